@@ -230,7 +230,7 @@ fn generate_derived_dialect(input: &DeriveDialectInput, methods: &[DialectMethod
             use ::core::iter::Peekable;
             use ::core::str::Chars;
             use sqlparser::ast::{ColumnOption, Expr, GranteesType, Ident, ObjectNamePart, Statement};
-            use sqlparser::dialect::{Dialect, Precedence};
+            use sqlparser::dialect::{Dialect, IdentCaseRule, Precedence};
             use sqlparser::keywords::Keyword;
             use sqlparser::parser::{Parser, ParserError};
 
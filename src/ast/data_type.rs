@@ -129,6 +129,11 @@ pub enum DataType {
     ///
     /// [1]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#exact-numeric-type
     Numeric(ExactNumberInfo),
+    /// [MySQL] unsigned numeric with optional precision and scale, e.g. NUMERIC UNSIGNED or NUMERIC(10,2) UNSIGNED.
+    /// Note: Using UNSIGNED with NUMERIC is deprecated in recent versions of MySQL.
+    ///
+    /// [MySQL]: https://dev.mysql.com/doc/refman/8.4/en/numeric-type-syntax.html
+    NumericUnsigned(ExactNumberInfo),
     /// Decimal type with optional precision and scale, e.g. DECIMAL(10,2), [SQL Standard][1].
     ///
     /// [1]: https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#exact-numeric-type
@@ -496,6 +501,11 @@ pub enum DataType {
     ///
     /// [PostgreSQL]: https://www.postgresql.org/docs/17/datatype-textsearch.html
     TsQuery,
+    /// [MySQL] numeric type with the `ZEROFILL` attribute, e.g. INT(5) ZEROFILL or DECIMAL(10,2) UNSIGNED ZEROFILL.
+    /// Note: ZEROFILL is deprecated in recent versions of MySQL.
+    ///
+    /// [MySQL]: https://dev.mysql.com/doc/refman/8.4/en/numeric-type-attributes.html
+    Zerofill(Box<DataType>),
 }
 
 impl fmt::Display for DataType {
@@ -527,6 +537,9 @@ impl fmt::Display for DataType {
             DataType::Numeric(info) => {
                 write!(f, "NUMERIC{info}")
             }
+            DataType::NumericUnsigned(info) => {
+                write!(f, "NUMERIC{info} UNSIGNED")
+            }
             DataType::Decimal(info) => {
                 write!(f, "DECIMAL{info}")
             }
@@ -811,6 +824,7 @@ impl fmt::Display for DataType {
             DataType::GeometricType(kind) => write!(f, "{kind}"),
             DataType::TsVector => write!(f, "TSVECTOR"),
             DataType::TsQuery => write!(f, "TSQUERY"),
+            DataType::Zerofill(data_type) => write!(f, "{data_type} ZEROFILL"),
         }
     }
 }
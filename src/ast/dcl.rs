@@ -444,12 +444,17 @@ pub struct Grant {
     pub grantees: Vec<Grantee>,
     /// Whether `WITH GRANT OPTION` is present.
     pub with_grant_option: bool,
+    /// Whether `WITH ADMIN OPTION` is present, when granting role membership.
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-grant.html)
+    pub with_admin_option: bool,
     /// Optional `AS GRANTOR` identifier.
     pub as_grantor: Option<Ident>,
-    /// Optional `GRANTED BY` identifier.
+    /// Optional `GRANTED BY` grantor.
     ///
     /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/dcl-statements)
-    pub granted_by: Option<Ident>,
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-grant.html)
+    pub granted_by: Option<Grantee>,
     /// Optional `CURRENT GRANTS` modifier.
     ///
     /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/grant-privilege)
@@ -469,6 +474,9 @@ impl fmt::Display for Grant {
         if self.with_grant_option {
             write!(f, " WITH GRANT OPTION")?;
         }
+        if self.with_admin_option {
+            write!(f, " WITH ADMIN OPTION")?;
+        }
         if let Some(ref as_grantor) = self.as_grantor {
             write!(f, " AS {as_grantor}")?;
         }
@@ -1297,6 +1297,36 @@ pub enum AlterColumnOperation {
         /// Optional sequence options for identity generation.
         sequence_options: Option<Vec<SequenceOptions>>,
     },
+    /// `SET STATISTICS <target>`
+    ///
+    /// Note: this is a PostgreSQL-specific operation.
+    SetStatistics {
+        /// The per-column statistics target.
+        target: Expr,
+    },
+    /// `SET ( attribute_option = value [, ... ] )`
+    ///
+    /// Note: this is a PostgreSQL-specific operation.
+    SetOptions {
+        /// The attribute options to set.
+        options: Vec<SqlOption>,
+    },
+    /// `RESET ( attribute_option [, ... ] )`
+    ///
+    /// Note: this is a PostgreSQL-specific operation.
+    ResetOptions {
+        /// The attribute options to reset.
+        options: Vec<Ident>,
+    },
+    /// `SET STORAGE { PLAIN | EXTERNAL | EXTENDED | MAIN | DEFAULT }`
+    ///
+    /// Note: this is a PostgreSQL-specific operation.
+    SetStorage {
+        /// The storage mode for the column.
+        storage: ColumnStorage,
+        /// The token holding the storage mode.
+        storage_token: AttachedToken,
+    },
 }
 
 impl fmt::Display for AlterColumnOperation {
@@ -1346,6 +1376,49 @@ impl fmt::Display for AlterColumnOperation {
                 }
                 Ok(())
             }
+            AlterColumnOperation::SetStatistics { target } => {
+                write!(f, "SET STATISTICS {target}")
+            }
+            AlterColumnOperation::SetOptions { options } => {
+                write!(f, "SET ({})", display_comma_separated(options))
+            }
+            AlterColumnOperation::ResetOptions { options } => {
+                write!(f, "RESET ({})", display_comma_separated(options))
+            }
+            AlterColumnOperation::SetStorage { storage, .. } => {
+                write!(f, "SET STORAGE {storage}")
+            }
+        }
+    }
+}
+
+/// The storage mode set by `ALTER COLUMN ... SET STORAGE`.
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-altertable.html)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ColumnStorage {
+    /// `PLAIN`
+    Plain,
+    /// `EXTERNAL`
+    External,
+    /// `EXTENDED`
+    Extended,
+    /// `MAIN`
+    Main,
+    /// `DEFAULT`
+    Default,
+}
+
+impl fmt::Display for ColumnStorage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColumnStorage::Plain => write!(f, "PLAIN"),
+            ColumnStorage::External => write!(f, "EXTERNAL"),
+            ColumnStorage::Extended => write!(f, "EXTENDED"),
+            ColumnStorage::Main => write!(f, "MAIN"),
+            ColumnStorage::Default => write!(f, "DEFAULT"),
         }
     }
 }
@@ -2962,6 +3035,13 @@ pub struct CreateTable {
     /// BigQuery: A partition expression for the table.
     /// <https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#partition_expression>
     pub partition_by: Option<Box<Expr>>,
+    /// PostgreSQL `PARTITION BY { RANGE | LIST | HASH } (...)` clause declaring a
+    /// partitioned table.
+    /// <https://www.postgresql.org/docs/current/ddl-partitioning.html>
+    pub partition_key: Option<PartitionKey>,
+    /// MySQL `PARTITION BY` clause with its partition definitions.
+    /// <https://dev.mysql.com/doc/refman/8.4/en/create-table.html#create-table-partitioning>
+    pub partition_options: Option<PartitionOptions>,
     /// BigQuery: Table clustering column list.
     /// <https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#table_option_list>
     /// Snowflake: Table clustering list which contains base column, expressions on base columns.
@@ -3076,6 +3156,11 @@ pub struct CreateTable {
     ///
     /// [Teradata](https://docs.teradata.com/r/Enterprise_IntelliFlex_VMware/SQL-Data-Definition-Language-Syntax-and-Examples/Table-Statements/CREATE-TABLE-and-CREATE-TABLE-AS/Syntax-Elements/AS_clause/WITH-Clause-Phrase)
     pub with_data: Option<WithData>,
+    /// `IGNORE | REPLACE` handling of duplicate unique key values in
+    /// `CREATE TABLE ... SELECT`.
+    ///
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/create-table-select.html)
+    pub duplicate_handling: Option<CreateTableDuplicateHandling>,
 }
 
 impl fmt::Display for CreateTable {
@@ -3269,6 +3354,12 @@ impl fmt::Display for CreateTable {
         if let Some(partition_by) = self.partition_by.as_ref() {
             write!(f, " PARTITION BY {partition_by}")?;
         }
+        if let Some(partition_key) = &self.partition_key {
+            write!(f, " {partition_key}")?;
+        }
+        if let Some(partition_options) = &self.partition_options {
+            write!(f, " {partition_options}")?;
+        }
         if let Some(cluster_by) = self.cluster_by.as_ref() {
             write!(f, " CLUSTER BY {cluster_by}")?;
         }
@@ -3401,6 +3492,9 @@ impl fmt::Display for CreateTable {
         if let Some(sortkey) = &self.sortkey {
             write!(f, " SORTKEY({})", display_comma_separated(sortkey))?;
         }
+        if let Some(duplicate_handling) = &self.duplicate_handling {
+            write!(f, " {duplicate_handling}")?;
+        }
         if let Some(query) = &self.query {
             write!(f, " AS {query}")?;
         }
@@ -3443,6 +3537,227 @@ impl fmt::Display for WithData {
     }
 }
 
+/// Handling of rows that duplicate a unique key value in a
+/// `CREATE TABLE ... { IGNORE | REPLACE } SELECT` statement.
+///
+/// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/create-table-select.html)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CreateTableDuplicateHandling {
+    /// `IGNORE`: keep the existing row and discard the duplicate.
+    Ignore,
+    /// `REPLACE`: replace the existing row with the duplicate.
+    Replace,
+}
+
+impl fmt::Display for CreateTableDuplicateHandling {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CreateTableDuplicateHandling::Ignore => f.write_str("IGNORE"),
+            CreateTableDuplicateHandling::Replace => f.write_str("REPLACE"),
+        }
+    }
+}
+
+/// PostgreSQL partition key of a partitioned table.
+///
+/// ```sql
+/// PARTITION BY { RANGE | LIST | HASH } ( { column_name | ( expression ) } [, ...] )
+/// ```
+///
+/// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createtable.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct PartitionKey {
+    /// The partitioning strategy.
+    pub strategy: PartitionStrategy,
+    /// Columns or parenthesized expressions making up the key.
+    pub columns: Vec<Expr>,
+}
+
+impl fmt::Display for PartitionKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PARTITION BY {} ({})",
+            self.strategy,
+            display_comma_separated(&self.columns)
+        )
+    }
+}
+
+/// Partitioning strategy of a PostgreSQL [PartitionKey].
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum PartitionStrategy {
+    /// `RANGE`
+    Range,
+    /// `LIST`
+    List,
+    /// `HASH`
+    Hash,
+}
+
+impl fmt::Display for PartitionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionStrategy::Range => write!(f, "RANGE"),
+            PartitionStrategy::List => write!(f, "LIST"),
+            PartitionStrategy::Hash => write!(f, "HASH"),
+        }
+    }
+}
+
+/// MySQL `PARTITION BY` clause of a `CREATE TABLE` statement.
+///
+/// ```sql
+/// PARTITION BY partition_function [PARTITIONS num]
+///     [(partition_definition [, partition_definition] ...)]
+/// ```
+///
+/// See [MySQL](https://dev.mysql.com/doc/refman/8.4/en/create-table.html#create-table-partitioning)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct PartitionOptions {
+    /// How rows are assigned to partitions.
+    pub function: PartitionFunction,
+    /// Number of partitions given by `PARTITIONS num`, if any.
+    pub partitions: Option<u64>,
+    /// Explicit partition definitions, in the order they were written.
+    pub definitions: Vec<PartitionDefinition>,
+}
+
+impl fmt::Display for PartitionOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PARTITION BY {}", self.function)?;
+        if let Some(partitions) = self.partitions {
+            write!(f, " PARTITIONS {partitions}")?;
+        }
+        if !self.definitions.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.definitions))?;
+        }
+        Ok(())
+    }
+}
+
+/// Partitioning function of a MySQL [PartitionOptions] clause.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum PartitionFunction {
+    /// `[LINEAR] HASH (expr)`
+    Hash {
+        /// Whether `LINEAR` was specified.
+        linear: bool,
+        /// The hashed expression.
+        expr: Box<Expr>,
+    },
+    /// `[LINEAR] KEY (column_list)`
+    Key {
+        /// Whether `LINEAR` was specified.
+        linear: bool,
+        /// The key columns; empty to use the primary key.
+        columns: Vec<Ident>,
+    },
+    /// `RANGE (expr)`
+    Range(Box<Expr>),
+    /// `RANGE COLUMNS (column_list)`
+    RangeColumns(Vec<Ident>),
+    /// `LIST (expr)`
+    List(Box<Expr>),
+    /// `LIST COLUMNS (column_list)`
+    ListColumns(Vec<Ident>),
+}
+
+impl fmt::Display for PartitionFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionFunction::Hash { linear, expr } => {
+                if *linear {
+                    write!(f, "LINEAR ")?;
+                }
+                write!(f, "HASH ({expr})")
+            }
+            PartitionFunction::Key { linear, columns } => {
+                if *linear {
+                    write!(f, "LINEAR ")?;
+                }
+                write!(f, "KEY ({})", display_comma_separated(columns))
+            }
+            PartitionFunction::Range(expr) => write!(f, "RANGE ({expr})"),
+            PartitionFunction::RangeColumns(columns) => {
+                write!(f, "RANGE COLUMNS ({})", display_comma_separated(columns))
+            }
+            PartitionFunction::List(expr) => write!(f, "LIST ({expr})"),
+            PartitionFunction::ListColumns(columns) => {
+                write!(f, "LIST COLUMNS ({})", display_comma_separated(columns))
+            }
+        }
+    }
+}
+
+/// A single partition of a MySQL [PartitionOptions] clause.
+///
+/// ```sql
+/// PARTITION partition_name [VALUES ...] [partition_option ...]
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct PartitionDefinition {
+    /// Name of the partition.
+    pub name: Ident,
+    /// The `VALUES` bound of the partition, if any.
+    pub values: Option<PartitionValues>,
+    /// Per-partition options such as `ENGINE`, `COMMENT`, `DATA DIRECTORY`,
+    /// `INDEX DIRECTORY`, `MAX_ROWS`, `MIN_ROWS` and `TABLESPACE`.
+    pub options: Vec<SqlOption>,
+}
+
+impl fmt::Display for PartitionDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PARTITION {}", self.name)?;
+        if let Some(values) = &self.values {
+            write!(f, " {values}")?;
+        }
+        if !self.options.is_empty() {
+            write!(f, " {}", display_separated(&self.options, " "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The `VALUES` bound of a MySQL [PartitionDefinition].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum PartitionValues {
+    /// `VALUES LESS THAN (expr [, expr] ...)`
+    LessThan(Vec<Expr>),
+    /// `VALUES LESS THAN MAXVALUE`
+    LessThanMaxValue,
+    /// `VALUES IN (value_list)`
+    In(Vec<Expr>),
+}
+
+impl fmt::Display for PartitionValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionValues::LessThan(values) => {
+                write!(f, "VALUES LESS THAN ({})", display_comma_separated(values))
+            }
+            PartitionValues::LessThanMaxValue => write!(f, "VALUES LESS THAN MAXVALUE"),
+            PartitionValues::In(values) => {
+                write!(f, "VALUES IN ({})", display_comma_separated(values))
+            }
+        }
+    }
+}
+
 /// PostgreSQL partition bound specification for `PARTITION OF`.
 ///
 /// Specifies partition bounds for a child partition table.
@@ -3569,6 +3884,8 @@ impl fmt::Display for DistStyle {
 pub struct CreateDomain {
     /// The name of the domain to be created.
     pub name: ObjectName,
+    /// Whether the `AS` keyword was present.
+    pub as_keyword: bool,
     /// The data type of the domain.
     pub data_type: DataType,
     /// The collation of the domain.
@@ -3576,17 +3893,16 @@ pub struct CreateDomain {
     /// The default value of the domain.
     pub default: Option<Expr>,
     /// The constraints of the domain.
-    pub constraints: Vec<TableConstraint>,
+    pub constraints: Vec<DomainConstraint>,
 }
 
 impl fmt::Display for CreateDomain {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "CREATE DOMAIN {name} AS {data_type}",
-            name = self.name,
-            data_type = self.data_type
-        )?;
+        write!(f, "CREATE DOMAIN {}", self.name)?;
+        if self.as_keyword {
+            write!(f, " AS")?;
+        }
+        write!(f, " {}", self.data_type)?;
         if let Some(collation) = &self.collation {
             write!(f, " COLLATE {collation}")?;
         }
@@ -3600,6 +3916,47 @@ impl fmt::Display for CreateDomain {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+/// A constraint of a [`CreateDomain`] statement.
+///
+/// ```sql
+/// [ CONSTRAINT constraint_name ] { NOT NULL | NULL | CHECK (expression) }
+/// ```
+pub enum DomainConstraint {
+    /// `[ CONSTRAINT <name> ] CHECK (<expr>)`
+    Check(CheckConstraint),
+    /// `[ CONSTRAINT <name> ] NOT NULL`
+    NotNull {
+        /// Optional constraint name.
+        name: Option<Ident>,
+    },
+    /// `[ CONSTRAINT <name> ] NULL`
+    Null {
+        /// Optional constraint name.
+        name: Option<Ident>,
+    },
+}
+
+impl From<CheckConstraint> for DomainConstraint {
+    fn from(constraint: CheckConstraint) -> Self {
+        DomainConstraint::Check(constraint)
+    }
+}
+
+impl fmt::Display for DomainConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DomainConstraint::Check(check) => write!(f, "{check}"),
+            DomainConstraint::NotNull { name } => {
+                write!(f, "{}NOT NULL", display_constraint_name(name))
+            }
+            DomainConstraint::Null { name } => write!(f, "{}NULL", display_constraint_name(name)),
+        }
+    }
+}
+
 /// The return type of a `CREATE FUNCTION` statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -4626,6 +4983,80 @@ impl Spanned for CreateCollation {
     }
 }
 
+/// CREATE STATISTICS statement.
+/// Note: this is a PostgreSQL-specific statement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateStatistics {
+    /// Whether `IF NOT EXISTS` was specified.
+    pub if_not_exists: bool,
+    /// Name of the statistics object, if given.
+    pub name: Option<ObjectName>,
+    /// Statistics kinds to compute, e.g. `(ndistinct, dependencies)`.
+    pub kinds: Vec<StatisticsKind>,
+    /// Columns or parenthesized expressions covered by the statistics.
+    pub on: Vec<Expr>,
+    /// Table the statistics are defined on.
+    pub from: ObjectName,
+}
+
+/// Kind of extended statistics listed in `CREATE STATISTICS`.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum StatisticsKind {
+    /// `ndistinct`
+    NDistinct,
+    /// `dependencies`
+    Dependencies,
+    /// `mcv`
+    Mcv,
+}
+
+impl fmt::Display for StatisticsKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StatisticsKind::NDistinct => write!(f, "ndistinct"),
+            StatisticsKind::Dependencies => write!(f, "dependencies"),
+            StatisticsKind::Mcv => write!(f, "mcv"),
+        }
+    }
+}
+
+impl fmt::Display for CreateStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CREATE STATISTICS")?;
+        if self.if_not_exists {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        if let Some(name) = &self.name {
+            write!(f, " {name}")?;
+        }
+        if !self.kinds.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.kinds))?;
+        }
+        write!(
+            f,
+            " ON {} FROM {}",
+            display_comma_separated(&self.on),
+            self.from
+        )
+    }
+}
+
+impl Spanned for CreateStatistics {
+    fn span(&self) -> Span {
+        Span::union_iter(
+            self.name
+                .iter()
+                .map(|n| n.span())
+                .chain(self.on.iter().map(|e| e.span()))
+                .chain(core::iter::once(self.from.span())),
+        )
+    }
+}
+
 /// ALTER COLLATION statement.
 /// Note: this is a PostgreSQL-specific statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -19,7 +19,11 @@
 //! See [this page](https://docs.snowflake.com/en/sql-reference/commands-data-loading) for more details.
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, string::String, vec::Vec};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::fmt;
 use core::fmt::Formatter;
 
@@ -29,7 +33,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "visitor")]
 use sqlparser_derive::{Visit, VisitMut};
 
-use crate::ast::{display_comma_separated, display_separated, ValueWithSpan};
+use crate::ast::{display_comma_separated, display_separated, Value, ValueWithSpan};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -82,6 +86,31 @@ pub enum KeyValueOptionKind {
     KeyValueOptions(Box<KeyValueOptions>),
 }
 
+impl KeyValueOptions {
+    /// Replaces the values of the options accepted by `filter`, including
+    /// every value nested below them, with `redacted`.
+    pub(crate) fn redact_values(&mut self, redacted: &str, filter: impl Fn(&str) -> bool) {
+        for option in &mut self.options {
+            if filter(&option.option_name) {
+                option.option_value.redact(redacted);
+            }
+        }
+    }
+}
+
+impl KeyValueOptionKind {
+    fn redact(&mut self, redacted: &str) {
+        let redact = |value: &mut ValueWithSpan| {
+            value.value = Value::SingleQuotedString(redacted.to_string())
+        };
+        match self {
+            KeyValueOptionKind::Single(value) => redact(value),
+            KeyValueOptionKind::Multi(values) => values.iter_mut().for_each(redact),
+            KeyValueOptionKind::KeyValueOptions(nested) => nested.redact_values(redacted, |_| true),
+        }
+    }
+}
+
 impl fmt::Display for KeyValueOptions {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let sep = match self.delimiter {
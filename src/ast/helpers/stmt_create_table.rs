@@ -25,11 +25,12 @@ use serde::{Deserialize, Serialize};
 use sqlparser_derive::{Visit, VisitMut};
 
 use crate::ast::{
-    ClusteredBy, ColumnDef, CommentDef, CreateTable, CreateTableLikeKind, CreateTableOptions,
-    DistStyle, Expr, FileFormat, ForValues, HiveDistributionStyle, HiveFormat, Ident,
-    InitializeKind, ObjectName, OnCommit, OneOrManyWithParens, Query, RefreshModeKind,
-    RowAccessPolicy, Statement, StorageLifecyclePolicy, StorageSerializationPolicy,
-    TableConstraint, TableVersion, Tag, WithData, WrappedCollection,
+    ClusteredBy, ColumnDef, CommentDef, CreateTable, CreateTableDuplicateHandling,
+    CreateTableLikeKind, CreateTableOptions, DistStyle, Expr, FileFormat, ForValues,
+    HiveDistributionStyle, HiveFormat, Ident, InitializeKind, ObjectName, OnCommit,
+    OneOrManyWithParens, PartitionKey, PartitionOptions, Query, RefreshModeKind, RowAccessPolicy,
+    Statement, StorageLifecyclePolicy, StorageSerializationPolicy, TableConstraint, TableVersion,
+    Tag, WithData, WrappedCollection,
 };
 
 use crate::parser::ParserError;
@@ -121,6 +122,10 @@ pub struct CreateTableBuilder {
     pub order_by: Option<OneOrManyWithParens<Expr>>,
     /// Optional `PARTITION BY` expression.
     pub partition_by: Option<Box<Expr>>,
+    /// Optional PostgreSQL `PARTITION BY { RANGE | LIST | HASH }` partition key.
+    pub partition_key: Option<PartitionKey>,
+    /// Optional MySQL `PARTITION BY` partitioning options.
+    pub partition_options: Option<PartitionOptions>,
     /// Optional `CLUSTER BY` expressions.
     pub cluster_by: Option<WrappedCollection<Vec<Expr>>>,
     /// Optional `CLUSTERED BY` clause.
@@ -192,6 +197,8 @@ pub struct CreateTableBuilder {
     pub fallback: Option<bool>,
     /// `WITH DATA` clause.
     pub with_data: Option<WithData>,
+    /// `IGNORE | REPLACE` duplicate handling for `CREATE TABLE ... SELECT`.
+    pub duplicate_handling: Option<CreateTableDuplicateHandling>,
 }
 
 impl CreateTableBuilder {
@@ -226,6 +233,8 @@ impl CreateTableBuilder {
             primary_key: None,
             order_by: None,
             partition_by: None,
+            partition_key: None,
+            partition_options: None,
             cluster_by: None,
             clustered_by: None,
             inherits: None,
@@ -261,6 +270,7 @@ impl CreateTableBuilder {
             multiset: None,
             fallback: None,
             with_data: None,
+            duplicate_handling: None,
         }
     }
     /// Set `OR REPLACE` for the CREATE TABLE statement.
@@ -399,6 +409,16 @@ impl CreateTableBuilder {
         self.partition_by = partition_by;
         self
     }
+    /// Set PostgreSQL `PARTITION BY { RANGE | LIST | HASH }` partition key.
+    pub fn partition_key(mut self, partition_key: Option<PartitionKey>) -> Self {
+        self.partition_key = partition_key;
+        self
+    }
+    /// Set MySQL `PARTITION BY` partitioning options.
+    pub fn partition_options(mut self, partition_options: Option<PartitionOptions>) -> Self {
+        self.partition_options = partition_options;
+        self
+    }
     /// Set `CLUSTER BY` expression(s).
     pub fn cluster_by(mut self, cluster_by: Option<WrappedCollection<Vec<Expr>>>) -> Self {
         self.cluster_by = cluster_by;
@@ -590,6 +610,14 @@ impl CreateTableBuilder {
         self.with_data = with_data;
         self
     }
+    /// Set `IGNORE | REPLACE` duplicate handling for `CREATE TABLE ... SELECT`.
+    pub fn duplicate_handling(
+        mut self,
+        duplicate_handling: Option<CreateTableDuplicateHandling>,
+    ) -> Self {
+        self.duplicate_handling = duplicate_handling;
+        self
+    }
     /// Consume the builder and produce a `CreateTable`.
     pub fn build(self) -> CreateTable {
         CreateTable {
@@ -621,6 +649,8 @@ impl CreateTableBuilder {
             primary_key: self.primary_key,
             order_by: self.order_by,
             partition_by: self.partition_by,
+            partition_key: self.partition_key,
+            partition_options: self.partition_options,
             cluster_by: self.cluster_by,
             clustered_by: self.clustered_by,
             inherits: self.inherits,
@@ -656,6 +686,7 @@ impl CreateTableBuilder {
             multiset: self.multiset,
             fallback: self.fallback,
             with_data: self.with_data,
+            duplicate_handling: self.duplicate_handling,
         }
     }
 }
@@ -706,6 +737,8 @@ impl From<CreateTable> for CreateTableBuilder {
             primary_key: table.primary_key,
             order_by: table.order_by,
             partition_by: table.partition_by,
+            partition_key: table.partition_key,
+            partition_options: table.partition_options,
             cluster_by: table.cluster_by,
             clustered_by: table.clustered_by,
             inherits: table.inherits,
@@ -741,6 +774,7 @@ impl From<CreateTable> for CreateTableBuilder {
             multiset: table.multiset,
             fallback: table.fallback,
             with_data: table.with_data,
+            duplicate_handling: table.duplicate_handling,
         }
     }
 }
@@ -749,6 +783,8 @@ impl From<CreateTable> for CreateTableBuilder {
 #[derive(Default)]
 pub(crate) struct CreateTableConfiguration {
     pub partition_by: Option<Box<Expr>>,
+    pub partition_key: Option<PartitionKey>,
+    pub partition_options: Option<PartitionOptions>,
     pub cluster_by: Option<WrappedCollection<Vec<Expr>>>,
     pub inherits: Option<Vec<ObjectName>>,
     pub table_options: CreateTableOptions,
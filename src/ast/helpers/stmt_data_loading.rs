@@ -84,6 +84,18 @@ pub struct StageLoadSelectItem {
     pub item_as: Option<Ident>,
 }
 
+impl StageParamsObject {
+    /// Replaces every `CREDENTIALS` value and the `ENCRYPTION` master key
+    /// with `redacted`. See [`Statement::redact_credentials`].
+    ///
+    /// [`Statement::redact_credentials`]: crate::ast::Statement::redact_credentials
+    pub(crate) fn redact_credentials(&mut self, redacted: &str) {
+        self.credentials.redact_values(redacted, |_| true);
+        self.encryption
+            .redact_values(redacted, |name| name.eq_ignore_ascii_case("MASTER_KEY"));
+    }
+}
+
 impl fmt::Display for StageParamsObject {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(ref url) = self.url {
@@ -43,12 +43,16 @@ use serde::{Deserialize, Serialize};
 use sqlparser_derive::{Visit, VisitMut};
 
 use crate::{
-    display_utils::SpaceOrNewline,
-    tokenizer::{Span, Token},
+    dialect::{Dialect, IdentCaseRule},
+    display_utils::{Indent, NewLine},
+    keywords::{
+        Keyword, ALL_KEYWORDS, ALL_KEYWORDS_INDEX, RESERVED_FOR_COLUMN_ALIAS,
+        RESERVED_FOR_TABLE_ALIAS,
+    },
 };
 use crate::{
-    display_utils::{Indent, NewLine},
-    keywords::Keyword,
+    display_utils::SpaceOrNewline,
+    tokenizer::{Span, Token},
 };
 
 pub use self::data_type::{
@@ -68,17 +72,20 @@ pub use self::ddl::{
     AlterTableLock, AlterTableOperation, AlterTableType, AlterType, AlterTypeAddValue,
     AlterTypeAddValuePosition, AlterTypeOperation, AlterTypeRename, AlterTypeRenameValue,
     ClusteredBy, ColumnDef, ColumnOption, ColumnOptionDef, ColumnOptions, ColumnPolicy,
-    ColumnPolicyProperty, ConstraintCharacteristics, CreateCollation, CreateCollationDefinition,
-    CreateConnector, CreateDomain, CreateExtension, CreateFunction, CreateIndex, CreateOperator,
-    CreateOperatorClass, CreateOperatorFamily, CreatePolicy, CreatePolicyCommand, CreatePolicyType,
-    CreateTable, CreateTrigger, CreateView, Deduplicate, DeferrableInitial, DistStyle,
-    DropBehavior, DropExtension, DropFunction, DropOperator, DropOperatorClass, DropOperatorFamily,
-    DropOperatorSignature, DropPolicy, DropTrigger, ForValues, FunctionReturnType, GeneratedAs,
-    GeneratedExpressionMode, IdentityParameters, IdentityProperty, IdentityPropertyFormatKind,
-    IdentityPropertyKind, IdentityPropertyOrder, IndexColumn, IndexOption, IndexType,
-    KeyOrIndexDisplay, Msck, NullsDistinctOption, OperatorArgTypes, OperatorClassItem,
-    OperatorFamilyDropItem, OperatorFamilyItem, OperatorOption, OperatorPurpose, Owner, Partition,
-    PartitionBoundValue, ProcedureParam, ReferentialAction, RenameTableNameKind, ReplicaIdentity,
+    ColumnPolicyProperty, ColumnStorage, ConstraintCharacteristics, CreateCollation,
+    CreateCollationDefinition, CreateConnector, CreateDomain, CreateExtension, CreateFunction,
+    CreateIndex, CreateOperator, CreateOperatorClass, CreateOperatorFamily, CreatePolicy,
+    CreatePolicyCommand, CreatePolicyType, CreateStatistics, CreateTable,
+    CreateTableDuplicateHandling, CreateTrigger, CreateView, Deduplicate, DeferrableInitial,
+    DistStyle, DomainConstraint, DropBehavior, DropExtension, DropFunction, DropOperator,
+    DropOperatorClass, DropOperatorFamily, DropOperatorSignature, DropPolicy, DropTrigger,
+    ForValues, FunctionReturnType, GeneratedAs, GeneratedExpressionMode, IdentityParameters,
+    IdentityProperty, IdentityPropertyFormatKind, IdentityPropertyKind, IdentityPropertyOrder,
+    IndexColumn, IndexOption, IndexType, KeyOrIndexDisplay, Msck, NullsDistinctOption,
+    OperatorArgTypes, OperatorClassItem, OperatorFamilyDropItem, OperatorFamilyItem,
+    OperatorOption, OperatorPurpose, Owner, Partition, PartitionBoundValue, PartitionDefinition,
+    PartitionFunction, PartitionKey, PartitionOptions, PartitionStrategy, PartitionValues,
+    ProcedureParam, ReferentialAction, RenameTableNameKind, ReplicaIdentity, StatisticsKind,
     TagsColumnOption, TriggerObjectKind, Truncate, UserDefinedTypeCompositeAttributeDef,
     UserDefinedTypeInternalLength, UserDefinedTypeRangeOption, UserDefinedTypeRepresentation,
     UserDefinedTypeSqlDefinitionOption, UserDefinedTypeStorage, ViewColumnDef, WithData,
@@ -99,11 +106,11 @@ pub use self::query::{
     JsonTableNestedColumn, LateralView, LimitClause, LockClause, LockType, MatchRecognizePattern,
     MatchRecognizeSymbol, Measure, NamedWindowDefinition, NamedWindowExpr, NonBlock, Offset,
     OffsetRows, OpenJsonTableColumn, OrderBy, OrderByExpr, OrderByKind, OrderByOptions,
-    OrderBySort, PipeOperator, PivotValueSource, ProjectionSelect, Query, RenameSelectItem,
-    RepetitionQuantifier, ReplaceSelectElement, ReplaceSelectItem, RowsPerMatch, Select,
-    SelectFlavor, SelectInto, SelectItem, SelectItemQualifiedWildcardKind, SelectModifiers,
-    SetExpr, SetOperator, SetQuantifier, Setting, SymbolDefinition, Table, TableAlias,
-    TableAliasColumnDef, TableFactor, TableFunctionArgs, TableIndexHintForClause,
+    OrderBySort, PipeOperator, PivotOnColumn, PivotStatement, PivotValueSource, ProjectionSelect,
+    Query, RenameSelectItem, RepetitionQuantifier, ReplaceSelectElement, ReplaceSelectItem,
+    RowsPerMatch, Select, SelectFlavor, SelectInto, SelectItem, SelectItemQualifiedWildcardKind,
+    SelectModifiers, SetExpr, SetOperator, SetQuantifier, Setting, SymbolDefinition, Table,
+    TableAlias, TableAliasColumnDef, TableFactor, TableFunctionArgs, TableIndexHintForClause,
     TableIndexHintType, TableIndexHints, TableIndexType, TableSample, TableSampleBucket,
     TableSampleKind, TableSampleMethod, TableSampleModifier, TableSampleQuantity, TableSampleSeed,
     TableSampleSeedModifier, TableSampleUnit, TableVersion, TableWithJoins, Top, TopQuantity,
@@ -362,6 +369,58 @@ impl Ident {
             span,
         }
     }
+
+    /// Returns the canonical name of this identifier under the case-folding
+    /// rules of `dialect`, so that two identifiers referring to the same
+    /// object normalize to the same string.
+    ///
+    /// Case-insensitive identifiers are normalized to lowercase.
+    pub fn normalize(&self, dialect: &dyn Dialect) -> String {
+        match (dialect.normalizes_idents_to(), self.quote_style) {
+            (IdentCaseRule::Lowercase, None) | (IdentCaseRule::CaseInsensitive, _) => {
+                self.value.to_lowercase()
+            }
+            (IdentCaseRule::Uppercase, None) => self.value.to_uppercase(),
+            _ => self.value.clone(),
+        }
+    }
+
+    /// Formats this identifier for `dialect`, quoting it with the dialect's
+    /// [`identifier_quote_style`](Dialect::identifier_quote_style) when it is
+    /// already quoted, is a reserved keyword, or contains characters that are
+    /// not valid in an unquoted identifier.
+    ///
+    /// Falls back to the identifier's own quote character, or `"`, when the
+    /// dialect has no preferred quote style.
+    pub fn to_string_with_dialect(&self, dialect: &dyn Dialect) -> String {
+        let mut chars = self.value.chars();
+        let is_plain = chars
+            .next()
+            .is_some_and(|ch| dialect.is_identifier_start(ch))
+            && chars.all(|ch| dialect.is_identifier_part(ch));
+        let is_reserved = ALL_KEYWORDS
+            .binary_search(&self.value.to_uppercase().as_str())
+            .is_ok_and(|i| {
+                let kw = ALL_KEYWORDS_INDEX[i];
+                RESERVED_FOR_COLUMN_ALIAS.contains(&kw)
+                    || RESERVED_FOR_TABLE_ALIAS.contains(&kw)
+                    || dialect.is_reserved_for_identifier(kw)
+            });
+        if self.quote_style.is_none() && is_plain && !is_reserved {
+            return self.value.clone();
+        }
+
+        let quote = dialect
+            .identifier_quote_style(&self.value)
+            .or(self.quote_style)
+            .unwrap_or('"');
+        Ident {
+            value: self.value.clone(),
+            quote_style: Some(quote),
+            span: self.span,
+        }
+        .to_string()
+    }
 }
 
 impl From<&str> for Ident {
@@ -377,13 +436,15 @@ impl From<&str> for Ident {
 impl fmt::Display for Ident {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.quote_style {
-            Some(q) if q == '"' || q == '\'' || q == '`' => {
+            Some('[') => {
+                let escaped = value::escape_quoted_string(&self.value, ']');
+                write!(f, "[{escaped}]")
+            }
+            Some(q) => {
                 let escaped = value::escape_quoted_string(&self.value, q);
                 write!(f, "{q}{escaped}{q}")
             }
-            Some('[') => write!(f, "[{}]", self.value),
             None => f.write_str(&self.value),
-            _ => panic!("unexpected quote style"),
         }
     }
 }
@@ -421,6 +482,8 @@ pub enum ObjectNamePart {
     Identifier(Ident),
     /// A function that returns an identifier (dialect-specific).
     Function(ObjectNamePartFunction),
+    /// A `*` wildcard part, e.g. in `GRANT ... ON db.*` or `*.*`.
+    Wildcard(AttachedToken),
 }
 
 impl ObjectNamePart {
@@ -428,7 +491,7 @@ impl ObjectNamePart {
     pub fn as_ident(&self) -> Option<&Ident> {
         match self {
             ObjectNamePart::Identifier(ident) => Some(ident),
-            ObjectNamePart::Function(_) => None,
+            ObjectNamePart::Function(_) | ObjectNamePart::Wildcard(_) => None,
         }
     }
 }
@@ -438,6 +501,7 @@ impl fmt::Display for ObjectNamePart {
         match self {
             ObjectNamePart::Identifier(ident) => write!(f, "{ident}"),
             ObjectNamePart::Function(func) => write!(f, "{func}"),
+            ObjectNamePart::Wildcard(_) => write!(f, "*"),
         }
     }
 }
@@ -531,7 +595,7 @@ impl fmt::Display for Interval {
                 assert!(self.last_field.is_none());
                 write!(
                     f,
-                    "INTERVAL {value} SECOND ({leading_precision}, {fractional_seconds_precision})"
+                    "INTERVAL {value} SECOND({leading_precision}, {fractional_seconds_precision})"
                 )
             }
             _ => {
@@ -540,13 +604,13 @@ impl fmt::Display for Interval {
                     write!(f, " {leading_field}")?;
                 }
                 if let Some(leading_precision) = self.leading_precision {
-                    write!(f, " ({leading_precision})")?;
+                    write!(f, "({leading_precision})")?;
                 }
                 if let Some(last_field) = &self.last_field {
                     write!(f, " TO {last_field}")?;
                 }
                 if let Some(fractional_seconds_precision) = self.fractional_seconds_precision {
-                    write!(f, " ({fractional_seconds_precision})")?;
+                    write!(f, "({fractional_seconds_precision})")?;
                 }
                 Ok(())
             }
@@ -1182,6 +1246,7 @@ pub enum Expr {
     },
     /// ```sql
     /// TRIM([BOTH | LEADING | TRAILING] [<expr> FROM] <expr>)
+    /// TRIM([BOTH | LEADING | TRAILING] FROM <expr>)
     /// TRIM(<expr>)
     /// TRIM(<expr>, [, characters]) -- PostgreSQL, DuckDB, Snowflake, BigQuery, Generic
     /// ```
@@ -1194,6 +1259,10 @@ pub enum Expr {
         expr: Box<Expr>,
         /// Optional list of characters to trim (dialect-specific).
         trim_characters: Option<Vec<Expr>>,
+        /// true if the `FROM` keyword was given without a `trim_what` expression,
+        /// e.g. `TRIM(LEADING FROM col)`.
+        /// This flag is used for formatting.
+        bare_from: bool,
     },
     /// ```sql
     /// OVERLAY(<expr> PLACING <expr> FROM <expr>[ FOR <expr> ]
@@ -2139,6 +2208,7 @@ impl fmt::Display for Expr {
                 trim_where,
                 trim_what,
                 trim_characters,
+                bare_from,
             } => {
                 write!(f, "TRIM(")?;
                 if let Some(ident) = trim_where {
@@ -2146,6 +2216,8 @@ impl fmt::Display for Expr {
                 }
                 if let Some(trim_char) = trim_what {
                     write!(f, "{trim_char} FROM {expr}")?;
+                } else if *bare_from {
+                    write!(f, "FROM {expr}")?;
                 } else {
                     write!(f, "{expr}")?;
                 }
@@ -3058,6 +3130,21 @@ impl fmt::Display for DeclareType {
     }
 }
 
+/// The separator between the declarations of a `DECLARE` statement.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum DeclareSeparator {
+    /// Declarations separated by `;`, as in a [Snowflake] declaration block.
+    ///
+    /// [Snowflake]: https://docs.snowflake.com/en/sql-reference/snowflake-scripting/declare
+    Semicolon,
+    /// Declarations separated by `,`, as in [MsSql] `DECLARE @a INT, @b INT`.
+    ///
+    /// [MsSql]: https://learn.microsoft.com/en-us/sql/t-sql/language-elements/declare-local-variable-transact-sql
+    Comma,
+}
+
 /// A `DECLARE` statement.
 /// [PostgreSQL] [Snowflake] [BigQuery]
 ///
@@ -3891,6 +3978,18 @@ pub enum Statement {
         /// Dialect-specific attach options (e.g., `READ_ONLY`).
         attach_options: Vec<AttachDuckDBDatabaseOption>,
     },
+    /// ```sql
+    /// DETACH DATABASE schema_name
+    /// ```
+    /// (SQLite-specific)
+    ///
+    /// See <https://sqlite.org/lang_detach.html>
+    DetachDatabase {
+        /// The name of the attached database to detach
+        schema_name: Ident,
+        /// true if the syntax is 'DETACH DATABASE', false if it's just 'DETACH'
+        database: bool,
+    },
     /// (DuckDB-specific)
     /// ```sql
     /// DETACH db_alias;
@@ -3990,6 +4089,8 @@ pub enum Statement {
     Declare {
         /// Cursor declaration statements collected by `DECLARE`.
         stmts: Vec<Declare>,
+        /// The separator written between `stmts`.
+        separator: DeclareSeparator,
     },
     /// ```sql
     /// CREATE EXTENSION [ IF NOT EXISTS ] extension_name
@@ -4007,6 +4108,12 @@ pub enum Statement {
     /// <https://www.postgresql.org/docs/current/sql-createcollation.html>
     CreateCollation(CreateCollation),
     /// ```sql
+    /// CREATE STATISTICS [ IF NOT EXISTS ] name [ ( kind [, ...] ) ] ON expr [, ...] FROM table
+    /// ```
+    /// Note: this is a PostgreSQL-specific statement.
+    /// <https://www.postgresql.org/docs/current/sql-createstatistics.html>
+    CreateStatistics(CreateStatistics),
+    /// ```sql
     /// DROP EXTENSION [ IF EXISTS ] name [, ...] [ CASCADE | RESTRICT ]
     /// ```
     /// Note: this is a PostgreSQL-specific statement.
@@ -4101,23 +4208,21 @@ pub enum Statement {
     ShowStatus {
         /// Optional filter for which status entries to display.
         filter: Option<ShowStatementFilter>,
-        /// `true` when `GLOBAL` scope was requested.
-        global: bool,
-        /// `true` when `SESSION` scope was requested.
-        session: bool,
+        /// Optional scope modifier (`GLOBAL` / `SESSION`), shared with `SET`.
+        scope: Option<ContextModifier>,
     },
     /// ```sql
-    /// SHOW VARIABLES
+    /// SHOW [GLOBAL | SESSION] VARIABLES [LIKE 'pattern' | WHERE expr]
     /// ```
     ///
     /// Note: this is a MySQL-specific statement.
     ShowVariables {
+        /// Token for the `SHOW` keyword.
+        show_token: AttachedToken,
         /// Optional filter for which variables to display.
         filter: Option<ShowStatementFilter>,
-        /// `true` when `GLOBAL` scope was requested.
-        global: bool,
-        /// `true` when `SESSION` scope was requested.
-        session: bool,
+        /// Optional scope modifier (`GLOBAL` / `SESSION`), shared with `SET`.
+        scope: Option<ContextModifier>,
     },
     /// ```sql
     /// SHOW CREATE TABLE
@@ -4131,6 +4236,60 @@ pub enum Statement {
         obj_name: ObjectName,
     },
     /// ```sql
+    /// SHOW CREATE USER user
+    /// ```
+    ///
+    /// Note: this is a MySQL-specific statement.
+    /// See <https://dev.mysql.com/doc/refman/8.4/en/show-create-user.html>
+    ShowCreateUser {
+        /// Token for the `SHOW` keyword.
+        show_token: AttachedToken,
+        /// The user whose `CREATE USER` statement is shown.
+        user: GranteeName,
+    },
+    /// ```sql
+    /// SHOW GRANTS [FOR user [USING role [, role] ...]]
+    /// ```
+    ///
+    /// Note: this is a MySQL-specific statement.
+    /// See <https://dev.mysql.com/doc/refman/8.4/en/show-grants.html>
+    ShowGrants {
+        /// Token for the `SHOW` keyword.
+        show_token: AttachedToken,
+        /// The user given in the `FOR` clause, if any.
+        for_user: Option<GranteeName>,
+        /// Roles given in the `USING` clause.
+        using: Vec<GranteeName>,
+    },
+    /// ```sql
+    /// SHOW TABLE STATUS [{FROM | IN} db_name] [LIKE 'pattern' | WHERE expr]
+    /// ```
+    ///
+    /// Note: this is a MySQL-specific statement.
+    /// See <https://dev.mysql.com/doc/refman/8.4/en/show-table-status.html>
+    ShowTableStatus {
+        /// Token for the `SHOW` keyword.
+        show_token: AttachedToken,
+        /// The database given in the `FROM`/`IN` clause, if any.
+        show_in: Option<ShowStatementIn>,
+        /// Optional `LIKE`/`WHERE` filter.
+        filter: Option<ShowStatementFilter>,
+    },
+    /// ```sql
+    /// SHOW OPEN TABLES [{FROM | IN} db_name] [LIKE 'pattern' | WHERE expr]
+    /// ```
+    ///
+    /// Note: this is a MySQL-specific statement.
+    /// See <https://dev.mysql.com/doc/refman/8.4/en/show-open-tables.html>
+    ShowOpenTables {
+        /// Token for the `SHOW` keyword.
+        show_token: AttachedToken,
+        /// The database given in the `FROM`/`IN` clause, if any.
+        show_in: Option<ShowStatementIn>,
+        /// Optional `LIKE`/`WHERE` filter.
+        filter: Option<ShowStatementFilter>,
+    },
+    /// ```sql
     /// SHOW COLUMNS
     /// ```
     ShowColumns {
@@ -4487,6 +4646,22 @@ pub enum Statement {
         comment: Option<String>,
     },
     /// ```sql
+    /// CREATE FILE FORMAT
+    /// ```
+    /// See <https://docs.snowflake.com/en/sql-reference/sql/create-file-format>
+    CreateFileFormat {
+        /// `OR REPLACE` flag for file format.
+        or_replace: bool,
+        /// Whether file format is temporary.
+        temporary: bool,
+        /// `IF NOT EXISTS` flag.
+        if_not_exists: bool,
+        /// File format name.
+        name: ObjectName,
+        /// File format options, including `TYPE` and `COMMENT`.
+        options: KeyValueOptions,
+    },
+    /// ```sql
     /// ASSERT <condition> [AS <message>]
     /// ```
     Assert {
@@ -4612,8 +4787,8 @@ pub enum Statement {
         statement: Box<Statement>,
         /// Optional output format of explain
         format: Option<AnalyzeFormatKind>,
-        /// Postgres style utility options, `(analyze, verbose true)`
-        options: Option<Vec<UtilityOption>>,
+        /// Postgres style option list, `(analyze, verbose true)`
+        options: Option<Vec<ExplainOption>>,
     },
     /// ```sql
     /// SAVEPOINT
@@ -4639,6 +4814,13 @@ pub enum Statement {
     /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/dml-syntax#merge_statement)
     /// [MSSQL](https://learn.microsoft.com/en-us/sql/t-sql/statements/merge-transact-sql?view=sql-server-ver16)
     Merge(Merge),
+    /// DuckDB's simplified `PIVOT` statement.
+    ///
+    /// ```sql
+    /// PIVOT t ON col USING SUM(x) AS s GROUP BY k
+    /// ```
+    /// [DuckDB](https://duckdb.org/docs/sql/statements/pivot#simplified-pivot-syntax)
+    Pivot(PivotStatement),
     /// ```sql
     /// CACHE [ FLAG ] TABLE <table_name> [ OPTIONS('K1' = 'V1', 'K2' = V2) ] [ AS ] [ <query> ]
     /// ```
@@ -4704,8 +4886,12 @@ pub enum Statement {
     Pragma {
         /// Pragma name (possibly qualified).
         name: ObjectName,
-        /// Optional pragma value.
-        value: Option<ValueWithSpan>,
+        /// Optional pragma value: a string, a (signed) number, a boolean, a
+        /// placeholder or a bare name such as `WAL`.
+        ///
+        /// Note: this was an `Option<ValueWithSpan>` before bare names were
+        /// supported; a bare name is an [`Expr::Identifier`].
+        value: Option<Expr>,
         /// Whether the pragma used `=`.
         is_eq: bool,
     },
@@ -4720,6 +4906,8 @@ pub enum Statement {
     /// ```
     /// Note: this is a MySQL-specific statement. See <https://dev.mysql.com/doc/refman/8.0/en/lock-tables.html>
     LockTables {
+        /// Token for the `LOCK` keyword.
+        lock_token: AttachedToken,
         /// List of tables to lock with modes.
         tables: Vec<LockTable>,
     },
@@ -4727,7 +4915,28 @@ pub enum Statement {
     /// UNLOCK TABLES
     /// ```
     /// Note: this is a MySQL-specific statement. See <https://dev.mysql.com/doc/refman/8.0/en/lock-tables.html>
-    UnlockTables,
+    UnlockTables {
+        /// Token for the `UNLOCK` keyword.
+        unlock_token: AttachedToken,
+        /// Token for the `TABLES` keyword.
+        tables_token: AttachedToken,
+    },
+    /// ```sql
+    /// HANDLER <table_name> OPEN [ [AS] alias ]
+    /// HANDLER <table_name> READ ...
+    /// HANDLER <table_name> CLOSE
+    /// ```
+    /// Note: this is a MySQL-specific statement. See <https://dev.mysql.com/doc/refman/8.4/en/handler.html>
+    Handler {
+        /// Token for the `HANDLER` keyword.
+        handler_token: AttachedToken,
+        /// The table the handler is opened on, or its alias.
+        table_name: ObjectName,
+        /// Optional alias given by `HANDLER ... OPEN AS <alias>`.
+        alias: Option<Ident>,
+        /// The handler operation.
+        op: HandlerOp,
+    },
     /// Unloads the result of a query to file
     ///
     /// [Athena](https://docs.aws.amazon.com/athena/latest/ug/unload.html):
@@ -4764,9 +4973,22 @@ pub enum Statement {
     /// OPTIMIZE table_name [WHERE predicate] [ZORDER BY (col_name1 [, ...])]
     /// ```
     /// See Databricks <https://docs.databricks.com/en/sql/language-manual/delta-optimize.html>
+    ///
+    /// MySQL:
+    /// ```sql
+    /// OPTIMIZE [NO_WRITE_TO_BINLOG | LOCAL] TABLE tbl_name [, tbl_name] ...
+    /// ```
+    /// See MySQL <https://dev.mysql.com/doc/refman/8.4/en/optimize-table.html>
     OptimizeTable {
-        /// Table name to optimize.
+        /// Token for the `OPTIMIZE` keyword.
+        optimize_token: AttachedToken,
+        /// Optional `NO_WRITE_TO_BINLOG` or `LOCAL` modifier.
+        /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/optimize-table.html)
+        location: Option<FlushLocation>,
+        /// Table to optimize.
         name: ObjectName,
+        /// Further tables after the first one; only MySQL accepts more than one.
+        additional_names: Vec<ObjectName>,
         /// Whether the `TABLE` keyword was present (ClickHouse uses `OPTIMIZE TABLE`, Databricks uses `OPTIMIZE`).
         has_table_keyword: bool,
         /// Optional cluster identifier.
@@ -4788,6 +5010,34 @@ pub enum Statement {
         /// [Databricks](https://docs.databricks.com/en/sql/language-manual/delta-optimize.html)
         zorder: Option<Vec<Expr>>,
     },
+    /// MySQL:
+    /// ```sql
+    /// CHECK TABLE tbl_name [, tbl_name] ... [option] ...
+    /// ```
+    /// See MySQL <https://dev.mysql.com/doc/refman/8.4/en/check-table.html>
+    CheckTable {
+        /// Token for the `CHECK` keyword.
+        check_token: AttachedToken,
+        /// Tables to check.
+        names: Vec<ObjectName>,
+        /// Check options, in the order they were written.
+        options: Vec<CheckTableOption>,
+    },
+    /// MySQL:
+    /// ```sql
+    /// REPAIR [NO_WRITE_TO_BINLOG | LOCAL] TABLE tbl_name [, tbl_name] ... [QUICK] [EXTENDED] [USE_FRM]
+    /// ```
+    /// See MySQL <https://dev.mysql.com/doc/refman/8.4/en/repair-table.html>
+    RepairTable {
+        /// Token for the `REPAIR` keyword.
+        repair_token: AttachedToken,
+        /// Optional `NO_WRITE_TO_BINLOG` or `LOCAL` modifier.
+        location: Option<FlushLocation>,
+        /// Tables to repair.
+        names: Vec<ObjectName>,
+        /// Repair options, in the order they were written.
+        options: Vec<RepairTableOption>,
+    },
     /// ```sql
     /// LISTEN
     /// ```
@@ -4925,6 +5175,13 @@ pub enum Statement {
     /// ```
     /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-reset.html)
     Reset(ResetStatement),
+    /// Rebuild indexes.
+    ///
+    /// ```sql
+    /// REINDEX [ ( option [, ...] ) ] { INDEX | TABLE | SCHEMA | DATABASE | SYSTEM } [ CONCURRENTLY ] name
+    /// ```
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-reindex.html)
+    Reindex(Reindex),
 }
 
 impl From<Analyze> for Statement {
@@ -4939,6 +5196,12 @@ impl From<ddl::Truncate> for Statement {
     }
 }
 
+impl From<Reindex> for Statement {
+    fn from(reindex: Reindex) -> Self {
+        Statement::Reindex(reindex)
+    }
+}
+
 impl From<Lock> for Statement {
     fn from(lock: Lock) -> Self {
         Statement::Lock(lock)
@@ -4999,6 +5262,49 @@ impl fmt::Display for RaisErrorOption {
     }
 }
 
+/// Replacement for credential values written by [`Statement::redact_credentials`].
+pub const REDACTED_CREDENTIAL: &str = "***";
+
+impl Statement {
+    /// Replaces credential values in this statement with
+    /// [`REDACTED_CREDENTIAL`], so that the re-serialized statement can be
+    /// logged safely while keeping its structure.
+    ///
+    /// This covers the Redshift `CREDENTIALS 'auth-args'` option of `COPY`
+    /// and `UNLOAD`, and the Snowflake `CREDENTIALS = (...)` values and
+    /// `ENCRYPTION = (MASTER_KEY = ...)` of stage parameters.
+    ///
+    /// ```
+    /// # use sqlparser::dialect::RedshiftSqlDialect;
+    /// # use sqlparser::parser::Parser;
+    /// let sql = "COPY t FROM 's3://bucket/file' CREDENTIALS 'aws_secret_access_key=SK'";
+    /// let mut statement = Parser::parse_sql(&RedshiftSqlDialect {}, sql).unwrap().remove(0);
+    /// statement.redact_credentials();
+    /// assert_eq!(
+    ///     statement.to_string(),
+    ///     "COPY t FROM 's3://bucket/file' CREDENTIALS '***'"
+    /// );
+    /// ```
+    pub fn redact_credentials(&mut self) {
+        let redact_legacy_options = |options: &mut Vec<CopyLegacyOption>| {
+            for option in options {
+                if let CopyLegacyOption::Credentials(credentials) = option {
+                    *credentials = REDACTED_CREDENTIAL.to_string();
+                }
+            }
+        };
+        match self {
+            Statement::Copy { legacy_options, .. } => redact_legacy_options(legacy_options),
+            Statement::Unload { options, .. } => redact_legacy_options(options),
+            Statement::CopyIntoSnowflake { stage_params, .. }
+            | Statement::CreateStage { stage_params, .. } => {
+                stage_params.redact_credentials(REDACTED_CREDENTIAL)
+            }
+            _ => {}
+        }
+    }
+}
+
 impl fmt::Display for Statement {
     /// Formats a SQL statement with support for pretty printing.
     ///
@@ -5121,9 +5427,13 @@ impl fmt::Display for Statement {
                 write!(f, "{statement}")
             }
             Statement::Query(s) => s.fmt(f),
-            Statement::Declare { stmts } => {
+            Statement::Declare { stmts, separator } => {
                 write!(f, "DECLARE ")?;
-                write!(f, "{}", display_separated(stmts, "; "))
+                let separator = match separator {
+                    DeclareSeparator::Semicolon => "; ",
+                    DeclareSeparator::Comma => ", ",
+                };
+                write!(f, "{}", display_separated(stmts, separator))
             }
             Statement::Fetch {
                 name,
@@ -5201,6 +5511,13 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::DetachDatabase {
+                schema_name,
+                database,
+            } => {
+                let keyword = if *database { "DATABASE " } else { "" };
+                write!(f, "DETACH {keyword}{schema_name}")
+            }
             Statement::DetachDuckDBDatabase {
                 if_exists,
                 database,
@@ -5489,6 +5806,7 @@ impl fmt::Display for Statement {
             Statement::CreateIndex(create_index) => create_index.fmt(f),
             Statement::CreateExtension(create_extension) => write!(f, "{create_extension}"),
             Statement::CreateCollation(create_collation) => write!(f, "{create_collation}"),
+            Statement::CreateStatistics(create_statistics) => write!(f, "{create_statistics}"),
             Statement::DropExtension(drop_extension) => write!(f, "{drop_extension}"),
             Statement::DropOperator(drop_operator) => write!(f, "{drop_operator}"),
             Statement::DropOperatorFamily(drop_operator_family) => {
@@ -5722,37 +6040,23 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
-            Statement::ShowStatus {
-                filter,
-                global,
-                session,
-            } => {
-                write!(f, "SHOW")?;
-                if *global {
-                    write!(f, " GLOBAL")?;
+            Statement::ShowStatus { filter, scope } => {
+                write!(f, "SHOW ")?;
+                if let Some(scope) = scope {
+                    write!(f, "{scope}")?;
                 }
-                if *session {
-                    write!(f, " SESSION")?;
-                }
-                write!(f, " STATUS")?;
+                write!(f, "STATUS")?;
                 if let Some(filter) = filter {
                     write!(f, " {}", filter)?;
                 }
                 Ok(())
             }
-            Statement::ShowVariables {
-                filter,
-                global,
-                session,
-            } => {
-                write!(f, "SHOW")?;
-                if *global {
-                    write!(f, " GLOBAL")?;
+            Statement::ShowVariables { filter, scope, .. } => {
+                write!(f, "SHOW ")?;
+                if let Some(scope) = scope {
+                    write!(f, "{scope}")?;
                 }
-                if *session {
-                    write!(f, " SESSION")?;
-                }
-                write!(f, " VARIABLES")?;
+                write!(f, "VARIABLES")?;
                 if let Some(filter) = filter {
                     write!(f, " {}", filter)?;
                 }
@@ -5762,6 +6066,43 @@ impl fmt::Display for Statement {
                 write!(f, "SHOW CREATE {obj_type} {obj_name}",)?;
                 Ok(())
             }
+            Statement::ShowCreateUser { user, .. } => write!(f, "SHOW CREATE USER {user}"),
+            Statement::ShowGrants {
+                for_user, using, ..
+            } => {
+                write!(f, "SHOW GRANTS")?;
+                if let Some(user) = for_user {
+                    write!(f, " FOR {user}")?;
+                }
+                if !using.is_empty() {
+                    write!(f, " USING {}", display_comma_separated(using))?;
+                }
+                Ok(())
+            }
+            Statement::ShowTableStatus {
+                show_in, filter, ..
+            } => {
+                write!(f, "SHOW TABLE STATUS")?;
+                if let Some(show_in) = show_in {
+                    write!(f, " {show_in}")?;
+                }
+                if let Some(filter) = filter {
+                    write!(f, " {filter}")?;
+                }
+                Ok(())
+            }
+            Statement::ShowOpenTables {
+                show_in, filter, ..
+            } => {
+                write!(f, "SHOW OPEN TABLES")?;
+                if let Some(show_in) = show_in {
+                    write!(f, " {show_in}")?;
+                }
+                if let Some(filter) = filter {
+                    write!(f, " {filter}")?;
+                }
+                Ok(())
+            }
             Statement::ShowColumns {
                 extended,
                 full,
@@ -6073,6 +6414,7 @@ impl fmt::Display for Statement {
                 write!(f, "RELEASE SAVEPOINT {name}")
             }
             Statement::Merge(merge) => merge.fmt(f),
+            Statement::Pivot(pivot) => pivot.fmt(f),
             Statement::Cache {
                 table_name,
                 table_flag,
@@ -6171,6 +6513,25 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::CreateFileFormat {
+                or_replace,
+                temporary,
+                if_not_exists,
+                name,
+                options,
+            } => {
+                write!(
+                    f,
+                    "CREATE {or_replace}{temp}FILE FORMAT {if_not_exists}{name}",
+                    temp = if *temporary { "TEMPORARY " } else { "" },
+                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                )?;
+                if !options.options.is_empty() {
+                    write!(f, " {options}")?;
+                }
+                Ok(())
+            }
             Statement::CopyIntoSnowflake {
                 kind,
                 into,
@@ -6264,12 +6625,24 @@ impl fmt::Display for Statement {
                 Ok(())
             }
             Statement::Lock(lock) => lock.fmt(f),
-            Statement::LockTables { tables } => {
+            Statement::LockTables { tables, .. } => {
                 write!(f, "LOCK TABLES {}", display_comma_separated(tables))
             }
-            Statement::UnlockTables => {
+            Statement::UnlockTables { .. } => {
                 write!(f, "UNLOCK TABLES")
             }
+            Statement::Handler {
+                table_name,
+                alias,
+                op,
+                ..
+            } => {
+                write!(f, "HANDLER {table_name} {op}")?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {alias}")?;
+                }
+                Ok(())
+            }
             Statement::Unload {
                 query,
                 query_text,
@@ -6298,7 +6671,9 @@ impl fmt::Display for Statement {
                 Ok(())
             }
             Statement::OptimizeTable {
+                location,
                 name,
+                additional_names,
                 has_table_keyword,
                 on_cluster,
                 partition,
@@ -6306,12 +6681,19 @@ impl fmt::Display for Statement {
                 deduplicate,
                 predicate,
                 zorder,
+                ..
             } => {
                 write!(f, "OPTIMIZE")?;
+                if let Some(location) = location {
+                    write!(f, " {location}")?;
+                }
                 if *has_table_keyword {
                     write!(f, " TABLE")?;
                 }
                 write!(f, " {name}")?;
+                for name in additional_names {
+                    write!(f, ", {name}")?;
+                }
                 if let Some(on_cluster) = on_cluster {
                     write!(f, " ON CLUSTER {on_cluster}")?;
                 }
@@ -6332,6 +6714,29 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::CheckTable { names, options, .. } => {
+                write!(f, "CHECK TABLE {}", display_comma_separated(names))?;
+                if !options.is_empty() {
+                    write!(f, " {}", display_separated(options, " "))?;
+                }
+                Ok(())
+            }
+            Statement::RepairTable {
+                location,
+                names,
+                options,
+                ..
+            } => {
+                write!(f, "REPAIR")?;
+                if let Some(location) = location {
+                    write!(f, " {location}")?;
+                }
+                write!(f, " TABLE {}", display_comma_separated(names))?;
+                if !options.is_empty() {
+                    write!(f, " {}", display_separated(options, " "))?;
+                }
+                Ok(())
+            }
             Statement::LISTEN { channel } => {
                 write!(f, "LISTEN {channel}")?;
                 Ok(())
@@ -6343,7 +6748,7 @@ impl fmt::Display for Statement {
             Statement::NOTIFY { channel, payload } => {
                 write!(f, "NOTIFY {channel}")?;
                 if let Some(payload) = payload {
-                    write!(f, ", '{payload}'")?;
+                    write!(f, ", '{}'", value::escape_single_quote_string(payload))?;
                 }
                 Ok(())
             }
@@ -6379,6 +6784,7 @@ impl fmt::Display for Statement {
             Statement::Vacuum(s) => write!(f, "{s}"),
             Statement::AlterUser(s) => write!(f, "{s}"),
             Statement::Reset(s) => write!(f, "{s}"),
+            Statement::Reindex(s) => write!(f, "{s}"),
         }
     }
 }
@@ -6608,6 +7014,70 @@ impl fmt::Display for LockTableMode {
     }
 }
 
+/// A `REINDEX` statement.
+///
+/// See <https://www.postgresql.org/docs/current/sql-reindex.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct Reindex {
+    /// Optional parenthesized options, e.g. `(VERBOSE)`.
+    pub options: Option<Vec<UtilityOption>>,
+    /// The kind of object being reindexed.
+    pub kind: ReindexKind,
+    /// Whether `CONCURRENTLY` was specified.
+    pub concurrently: bool,
+    /// Name of the object to reindex. Only optional for `DATABASE` and `SYSTEM`.
+    pub name: Option<ObjectName>,
+}
+
+impl fmt::Display for Reindex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "REINDEX")?;
+        if let Some(options) = &self.options {
+            write!(f, " ({})", display_comma_separated(options))?;
+        }
+        write!(f, " {}", self.kind)?;
+        if self.concurrently {
+            write!(f, " CONCURRENTLY")?;
+        }
+        if let Some(name) = &self.name {
+            write!(f, " {name}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The kind of object rebuilt by a [`Reindex`] statement.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ReindexKind {
+    /// `INDEX`
+    Index,
+    /// `TABLE`
+    Table,
+    /// `SCHEMA`
+    Schema,
+    /// `DATABASE`
+    Database,
+    /// `SYSTEM`
+    System,
+}
+
+impl fmt::Display for ReindexKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Self::Index => "INDEX",
+            Self::Table => "TABLE",
+            Self::Schema => "SCHEMA",
+            Self::Database => "DATABASE",
+            Self::System => "SYSTEM",
+        };
+        write!(f, "{text}")
+    }
+}
+
 /// PostgreSQL identity option for TRUNCATE table
 /// [ RESTART IDENTITY | CONTINUE IDENTITY ]
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -6818,6 +7288,10 @@ pub enum Privileges {
     },
     /// Specific privileges (e.g. `SELECT`, `INSERT`)
     Actions(Vec<Action>),
+    /// Membership in one or more roles, e.g. `GRANT admin TO alice`
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-grant.html)
+    Roles(Vec<Ident>),
 }
 
 impl fmt::Display for Privileges {
@@ -6839,6 +7313,9 @@ impl fmt::Display for Privileges {
             Privileges::Actions(actions) => {
                 write!(f, "{}", display_comma_separated(actions))
             }
+            Privileges::Roles(roles) => {
+                write!(f, "{}", display_comma_separated(roles))
+            }
         }
     }
 }
@@ -8864,17 +9341,34 @@ impl fmt::Display for SqlOption {
             SqlOption::TableSpace(tablespace_option) => {
                 write!(f, "TABLESPACE {}", tablespace_option.name)?;
                 match tablespace_option.storage {
-                    Some(StorageType::Disk) => write!(f, " STORAGE DISK"),
-                    Some(StorageType::Memory) => write!(f, " STORAGE MEMORY"),
-                    None => Ok(()),
+                    Some(StorageType::Disk) => write!(f, " STORAGE DISK")?,
+                    Some(StorageType::Memory) => write!(f, " STORAGE MEMORY")?,
+                    None => {}
                 }
+                if let Some(file_block_size) = &tablespace_option.file_block_size {
+                    let eq = if tablespace_option.file_block_size_eq {
+                        " ="
+                    } else {
+                        ""
+                    };
+                    write!(f, " FILE_BLOCK_SIZE{eq} {file_block_size}")?;
+                }
+                Ok(())
             }
             SqlOption::Comment(comment) => match comment {
                 CommentDef::WithEq(comment) => {
-                    write!(f, "COMMENT = '{comment}'")
+                    write!(
+                        f,
+                        "COMMENT = '{}'",
+                        value::escape_single_quote_string(comment)
+                    )
                 }
                 CommentDef::WithoutEq(comment) => {
-                    write!(f, "COMMENT '{comment}'")
+                    write!(
+                        f,
+                        "COMMENT '{}'",
+                        value::escape_single_quote_string(comment)
+                    )
                 }
             },
             SqlOption::NamedParenthesizedList(value) => {
@@ -8891,6 +9385,76 @@ impl fmt::Display for SqlOption {
     }
 }
 
+impl SqlOption {
+    /// Returns the storage engine of an `ENGINE = <engine>` option.
+    ///
+    /// Returns `None` for any other option.
+    pub fn table_engine(&self) -> Option<TableEngine> {
+        match self {
+            SqlOption::NamedParenthesizedList(NamedParenthesizedList {
+                key,
+                name: Some(name),
+                ..
+            }) if key.value.eq_ignore_ascii_case("ENGINE") => {
+                Some(TableEngine::from_name(&name.value))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+/// A table storage engine, as named by an `ENGINE = <engine>` table option.
+///
+/// <https://dev.mysql.com/doc/refman/8.4/en/storage-engines.html>
+pub enum TableEngine {
+    /// `InnoDB`
+    InnoDB,
+    /// `MyISAM`
+    MyISAM,
+    /// `MEMORY` (or its `HEAP` synonym)
+    Memory,
+    /// `ARCHIVE`
+    Archive,
+    /// `CSV`
+    CSV,
+    /// `NDB` (or its `NDBCLUSTER` synonym)
+    NDB,
+    /// Any other engine, with its name as written.
+    Other(String),
+}
+
+impl TableEngine {
+    /// Maps an engine name to a [`TableEngine`], ignoring case.
+    pub fn from_name(name: &str) -> Self {
+        match name.to_ascii_uppercase().as_str() {
+            "INNODB" => TableEngine::InnoDB,
+            "MYISAM" => TableEngine::MyISAM,
+            "MEMORY" | "HEAP" => TableEngine::Memory,
+            "ARCHIVE" => TableEngine::Archive,
+            "CSV" => TableEngine::CSV,
+            "NDB" | "NDBCLUSTER" => TableEngine::NDB,
+            _ => TableEngine::Other(name.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for TableEngine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TableEngine::InnoDB => write!(f, "InnoDB"),
+            TableEngine::MyISAM => write!(f, "MyISAM"),
+            TableEngine::Memory => write!(f, "MEMORY"),
+            TableEngine::Archive => write!(f, "ARCHIVE"),
+            TableEngine::CSV => write!(f, "CSV"),
+            TableEngine::NDB => write!(f, "NDB"),
+            TableEngine::Other(name) => write!(f, "{name}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -8909,9 +9473,13 @@ pub enum StorageType {
 /// <https://dev.mysql.com/doc/refman/8.4/en/create-table.html>
 pub struct TablespaceOption {
     /// Name of the tablespace.
-    pub name: String,
+    pub name: Ident,
     /// Optional storage type for the tablespace.
     pub storage: Option<StorageType>,
+    /// Optional `FILE_BLOCK_SIZE [=] <size>` of the tablespace.
+    pub file_block_size: Option<Expr>,
+    /// Whether `FILE_BLOCK_SIZE` was followed by `=`.
+    pub file_block_size_eq: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -9772,7 +10340,7 @@ impl fmt::Display for FlushType {
     }
 }
 
-/// Location modifier for flush commands.
+/// Location modifier for `FLUSH` and MySQL `OPTIMIZE TABLE` commands.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -9792,6 +10360,63 @@ impl fmt::Display for FlushLocation {
     }
 }
 
+/// Option of a MySQL `CHECK TABLE` statement.
+/// <https://dev.mysql.com/doc/refman/8.4/en/check-table.html>
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CheckTableOption {
+    /// `FOR UPGRADE`
+    ForUpgrade,
+    /// `QUICK`
+    Quick,
+    /// `FAST`
+    Fast,
+    /// `MEDIUM`
+    Medium,
+    /// `EXTENDED`
+    Extended,
+    /// `CHANGED`
+    Changed,
+}
+
+impl fmt::Display for CheckTableOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckTableOption::ForUpgrade => f.write_str("FOR UPGRADE"),
+            CheckTableOption::Quick => f.write_str("QUICK"),
+            CheckTableOption::Fast => f.write_str("FAST"),
+            CheckTableOption::Medium => f.write_str("MEDIUM"),
+            CheckTableOption::Extended => f.write_str("EXTENDED"),
+            CheckTableOption::Changed => f.write_str("CHANGED"),
+        }
+    }
+}
+
+/// Option of a MySQL `REPAIR TABLE` statement.
+/// <https://dev.mysql.com/doc/refman/8.4/en/repair-table.html>
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum RepairTableOption {
+    /// `QUICK`
+    Quick,
+    /// `EXTENDED`
+    Extended,
+    /// `USE_FRM`
+    UseFrm,
+}
+
+impl fmt::Display for RepairTableOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RepairTableOption::Quick => f.write_str("QUICK"),
+            RepairTableOption::Extended => f.write_str("EXTENDED"),
+            RepairTableOption::UseFrm => f.write_str("USE_FRM"),
+        }
+    }
+}
+
 /// Optional context modifier for statements that can be or `LOCAL`, `GLOBAL`, or `SESSION`.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -10414,6 +11039,94 @@ impl fmt::Display for LockTableType {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+/// The operation of a MySQL `HANDLER` statement.
+///
+/// See <https://dev.mysql.com/doc/refman/8.4/en/handler.html>
+pub enum HandlerOp {
+    /// `OPEN`
+    Open,
+    /// `READ [index_name] <read> [WHERE <condition>] [LIMIT ...]`
+    Read {
+        /// The index to read from, if any.
+        index: Option<Ident>,
+        /// Which rows to read.
+        read: HandlerRead,
+        /// Optional `WHERE` condition.
+        selection: Option<Expr>,
+        /// Optional `LIMIT` clause.
+        limit: Option<LimitClause>,
+    },
+    /// `CLOSE`
+    Close,
+}
+
+impl fmt::Display for HandlerOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandlerOp::Open => write!(f, "OPEN"),
+            HandlerOp::Read {
+                index,
+                read,
+                selection,
+                limit,
+            } => {
+                write!(f, "READ")?;
+                if let Some(index) = index {
+                    write!(f, " {index}")?;
+                }
+                write!(f, " {read}")?;
+                if let Some(selection) = selection {
+                    write!(f, " WHERE {selection}")?;
+                }
+                if let Some(limit) = limit {
+                    write!(f, "{limit}")?;
+                }
+                Ok(())
+            }
+            HandlerOp::Close => write!(f, "CLOSE"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+/// The rows read by a `HANDLER ... READ` statement.
+pub enum HandlerRead {
+    /// `{ = | <= | >= | < | > } (value, ...)`
+    Compare {
+        /// The comparison operator.
+        op: BinaryOperator,
+        /// The index key values to compare against.
+        values: Vec<Expr>,
+    },
+    /// `FIRST`
+    First,
+    /// `NEXT`
+    Next,
+    /// `PREV`
+    Prev,
+    /// `LAST`
+    Last,
+}
+
+impl fmt::Display for HandlerRead {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandlerRead::Compare { op, values } => {
+                write!(f, "{op} ({})", display_comma_separated(values))
+            }
+            HandlerRead::First => write!(f, "FIRST"),
+            HandlerRead::Next => write!(f, "NEXT"),
+            HandlerRead::Prev => write!(f, "PREV"),
+            HandlerRead::Last => write!(f, "LAST"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -10761,6 +11474,94 @@ impl Display for UtilityOption {
     }
 }
 
+/// An option of a parenthesized `EXPLAIN` option list, e.g.
+/// `EXPLAIN (ANALYZE true, VERBOSE, FORMAT JSON) ...`
+///
+/// Boolean options hold `None` when written without an argument. Options
+/// this enum does not model, and boolean options whose argument is not a
+/// `TRUE`/`FALSE` literal (e.g. `WAL OFF`), are kept as written in
+/// [`ExplainOption::Other`].
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-explain.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ExplainOption {
+    /// `ANALYZE [ boolean ]`
+    Analyze(Option<bool>),
+    /// `VERBOSE [ boolean ]`
+    Verbose(Option<bool>),
+    /// `COSTS [ boolean ]`
+    Costs(Option<bool>),
+    /// `SETTINGS [ boolean ]`
+    Settings(Option<bool>),
+    /// `GENERIC_PLAN [ boolean ]`
+    GenericPlan(Option<bool>),
+    /// `BUFFERS [ boolean ]`
+    Buffers(Option<bool>),
+    /// `WAL [ boolean ]`
+    Wal(Option<bool>),
+    /// `TIMING [ boolean ]`
+    Timing(Option<bool>),
+    /// `SUMMARY [ boolean ]`
+    Summary(Option<bool>),
+    /// `MEMORY [ boolean ]`
+    Memory(Option<bool>),
+    /// `FORMAT { TEXT | XML | JSON | YAML }`
+    Format(ExplainFormat),
+    /// Any other option, e.g. `WAL OFF` or DuckDB specific options.
+    Other(UtilityOption),
+}
+
+impl fmt::Display for ExplainOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, arg) = match self {
+            ExplainOption::Analyze(arg) => ("ANALYZE", arg),
+            ExplainOption::Verbose(arg) => ("VERBOSE", arg),
+            ExplainOption::Costs(arg) => ("COSTS", arg),
+            ExplainOption::Settings(arg) => ("SETTINGS", arg),
+            ExplainOption::GenericPlan(arg) => ("GENERIC_PLAN", arg),
+            ExplainOption::Buffers(arg) => ("BUFFERS", arg),
+            ExplainOption::Wal(arg) => ("WAL", arg),
+            ExplainOption::Timing(arg) => ("TIMING", arg),
+            ExplainOption::Summary(arg) => ("SUMMARY", arg),
+            ExplainOption::Memory(arg) => ("MEMORY", arg),
+            ExplainOption::Format(format) => return write!(f, "FORMAT {format}"),
+            ExplainOption::Other(option) => return write!(f, "{option}"),
+        };
+        match arg {
+            Some(arg) => write!(f, "{name} {arg}"),
+            None => f.write_str(name),
+        }
+    }
+}
+
+/// Output format of a PostgreSQL `EXPLAIN (FORMAT ...)` option.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ExplainFormat {
+    /// `TEXT`
+    Text,
+    /// `XML`
+    Xml,
+    /// `JSON`
+    Json,
+    /// `YAML`
+    Yaml,
+}
+
+impl fmt::Display for ExplainFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ExplainFormat::Text => "TEXT",
+            ExplainFormat::Xml => "XML",
+            ExplainFormat::Json => "JSON",
+            ExplainFormat::Yaml => "YAML",
+        })
+    }
+}
+
 /// Represents the different options available for `SHOW`
 /// statements to filter the results. Example from Snowflake:
 /// <https://docs.snowflake.com/en/sql-reference/sql/show-tables>
@@ -11893,12 +12694,16 @@ impl fmt::Display for InitializeKind {
 ///
 /// '''sql
 /// VACUUM [ FULL | SORT ONLY | DELETE ONLY | REINDEX | RECLUSTER ] [ \[ table_name \] [ TO threshold PERCENT ] \[ BOOST \] ]
+/// VACUUM [ ( option [, ...] ) ] [ table_name [ ( column_name [, ...] ) ] ]
 /// '''
 /// [Redshift](https://docs.aws.amazon.com/redshift/latest/dg/r_VACUUM_command.html)
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-vacuum.html)
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub struct VacuumStatement {
+    /// Optional parenthesized options, e.g. `(FULL, ANALYZE)`.
+    pub options: Option<Vec<UtilityOption>>,
     /// Whether `FULL` was specified.
     pub full: bool,
     /// Whether `SORT ONLY` was specified.
@@ -11911,6 +12716,8 @@ pub struct VacuumStatement {
     pub recluster: bool,
     /// Optional table to run `VACUUM` on.
     pub table_name: Option<ObjectName>,
+    /// Optional list of columns of `table_name`.
+    pub columns: Vec<Ident>,
     /// Optional threshold value (percent) for `TO threshold PERCENT`.
     pub threshold: Option<ValueWithSpan>,
     /// Whether `BOOST` was specified.
@@ -11919,9 +12726,13 @@ pub struct VacuumStatement {
 
 impl fmt::Display for VacuumStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VACUUM")?;
+        if let Some(options) = &self.options {
+            write!(f, " ({})", display_comma_separated(options))?;
+        }
         write!(
             f,
-            "VACUUM{}{}{}{}{}",
+            "{}{}{}{}{}",
             if self.full { " FULL" } else { "" },
             if self.sort_only { " SORT ONLY" } else { "" },
             if self.delete_only { " DELETE ONLY" } else { "" },
@@ -11931,6 +12742,9 @@ impl fmt::Display for VacuumStatement {
         if let Some(table_name) = &self.table_name {
             write!(f, " {table_name}")?;
         }
+        if !self.columns.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.columns))?;
+        }
         if let Some(threshold) = &self.threshold {
             write!(f, " TO {threshold} PERCENT")?;
         }
@@ -12101,6 +12915,12 @@ impl From<CreateCollation> for Statement {
     }
 }
 
+impl From<CreateStatistics> for Statement {
+    fn from(c: CreateStatistics) -> Self {
+        Self::CreateStatistics(c)
+    }
+}
+
 impl From<DropExtension> for Statement {
     fn from(de: DropExtension) -> Self {
         Self::DropExtension(de)
@@ -12481,7 +13301,7 @@ mod tests {
             fractional_seconds_precision: Some(9),
         });
         assert_eq!(
-            "INTERVAL '123:45.67' MINUTE (10) TO SECOND (9)",
+            "INTERVAL '123:45.67' MINUTE(10) TO SECOND(9)",
             format!("{interval}"),
         );
 
@@ -12494,7 +13314,7 @@ mod tests {
             last_field: None,
             fractional_seconds_precision: Some(3),
         });
-        assert_eq!("INTERVAL '5' SECOND (1, 3)", format!("{interval}"));
+        assert_eq!("INTERVAL '5' SECOND(1, 3)", format!("{interval}"));
     }
 
     #[test]
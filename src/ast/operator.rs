@@ -139,6 +139,9 @@ pub enum BinaryOperator {
     DuckIntegerDivide,
     /// MySQL [`DIV`](https://dev.mysql.com/doc/refman/8.0/en/arithmetic-functions.html) integer division
     MyIntegerDivide,
+    /// MySQL [`MOD`](https://dev.mysql.com/doc/refman/8.0/en/arithmetic-functions.html) modulo,
+    /// e.g. `a MOD b`; the same operation as [`BinaryOperator::Modulo`]
+    MyModulo,
     /// MATCH operator, e.g. `a MATCH b` (SQLite-specific)
     /// See <https://www.sqlite.org/lang_expr.html#the_like_glob_regexp_match_and_extract_operators>
     Match,
@@ -355,6 +358,7 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::BitwiseXor => f.write_str("^"),
             BinaryOperator::DuckIntegerDivide => f.write_str("//"),
             BinaryOperator::MyIntegerDivide => f.write_str("DIV"),
+            BinaryOperator::MyModulo => f.write_str("MOD"),
             BinaryOperator::Match => f.write_str("MATCH"),
             BinaryOperator::Regexp => f.write_str("REGEXP"),
             BinaryOperator::Custom(s) => f.write_str(s),
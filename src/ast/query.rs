@@ -1732,6 +1732,14 @@ pub enum TableFactor {
         /// The alias for the table
         alias: Option<TableAlias>,
     },
+    /// The comma-separated arrays of a ClickHouse `ARRAY JOIN`, e.g.
+    /// `ARRAY JOIN a AS x, b AS y`.
+    ///
+    /// Only used as the [`Join::relation`] of an `ARRAY JOIN` with more than
+    /// one array; a single array is the relation itself.
+    ///
+    /// See <https://clickhouse.com/docs/en/sql-reference/statements/select/array-join>.
+    ArrayJoinList(Vec<TableFactor>),
 }
 
 /// The table sample modifier options
@@ -1978,6 +1986,79 @@ impl fmt::Display for PivotValueSource {
     }
 }
 
+/// DuckDB's simplified `PIVOT` statement.
+///
+/// ```sql
+/// PIVOT <dataset> ON <columns> [USING <values>] [GROUP BY <rows>] [ORDER BY ...] [LIMIT ...]
+/// ```
+///
+/// See <https://duckdb.org/docs/sql/statements/pivot#simplified-pivot-syntax>.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct PivotStatement {
+    /// The `PIVOT` token.
+    pub pivot_token: AttachedToken,
+    /// The dataset to pivot, e.g. a table or a parenthesized query.
+    pub table: TableFactor,
+    /// The columns whose values become new columns.
+    pub on: Vec<PivotOnColumn>,
+    /// The aggregates computed for each new column, e.g. `SUM(x) AS s`.
+    pub using: Vec<ExprWithAlias>,
+    /// The columns kept as rows.
+    pub group_by: Vec<Expr>,
+    /// The `ORDER BY` applied to the result.
+    pub order_by: Vec<OrderByExpr>,
+    /// The `LIMIT` applied to the result.
+    pub limit: Option<Expr>,
+}
+
+impl fmt::Display for PivotStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PIVOT {} ON {}",
+            self.table,
+            display_comma_separated(&self.on)
+        )?;
+        if !self.using.is_empty() {
+            write!(f, " USING {}", display_comma_separated(&self.using))?;
+        }
+        if !self.group_by.is_empty() {
+            write!(f, " GROUP BY {}", display_comma_separated(&self.group_by))?;
+        }
+        if !self.order_by.is_empty() {
+            write!(f, " ORDER BY {}", display_comma_separated(&self.order_by))?;
+        }
+        if let Some(limit) = &self.limit {
+            write!(f, " LIMIT {limit}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A column of the `ON` clause of a [`PivotStatement`], optionally restricted
+/// to a list of values, e.g. `year IN (2000, 2010)`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct PivotOnColumn {
+    /// The pivoted column or expression.
+    pub expr: Expr,
+    /// The values to pivot on, if given with `IN (...)`.
+    pub values: Option<Vec<ExprWithAlias>>,
+}
+
+impl fmt::Display for PivotOnColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expr)?;
+        if let Some(values) = &self.values {
+            write!(f, " IN ({})", display_comma_separated(values))?;
+        }
+        Ok(())
+    }
+}
+
 /// An item in the `MEASURES` subclause of a `MATCH_RECOGNIZE` operation.
 ///
 /// See <https://docs.snowflake.com/en/sql-reference/constructs/match_recognize#measures-specifying-additional-output-columns>.
@@ -2515,6 +2596,9 @@ impl fmt::Display for TableFactor {
 
                 Ok(())
             }
+            TableFactor::ArrayJoinList(arrays) => {
+                write!(f, "{}", display_comma_separated(arrays))
+            }
         }
     }
 }
@@ -2847,6 +2931,9 @@ pub enum JoinOperator {
     StraightJoin(JoinConstraint),
     /// ClickHouse: `ARRAY JOIN` for unnesting arrays inline.
     ///
+    /// Several comma-separated arrays are held in the join's relation as a
+    /// [`TableFactor::ArrayJoinList`].
+    ///
     /// See <https://clickhouse.com/docs/en/sql-reference/statements/select/array-join>.
     ArrayJoin,
     /// ClickHouse: `LEFT ARRAY JOIN` for unnesting arrays inline (preserves rows with empty arrays).
@@ -3537,8 +3624,8 @@ impl fmt::Display for Fetch {
 pub struct LockClause {
     /// The kind of lock requested (e.g. `SHARE`, `UPDATE`).
     pub lock_type: LockType,
-    /// Optional object name after `OF` (e.g. `FOR UPDATE OF t1`).
-    pub of: Option<ObjectName>,
+    /// Tables listed after `OF` (e.g. `FOR UPDATE OF t1, t2`); empty if there is no `OF`.
+    pub of: Vec<ObjectName>,
     /// Optional non-blocking behavior (`NOWAIT` / `SKIP LOCKED`).
     pub nonblock: Option<NonBlock>,
 }
@@ -3546,8 +3633,8 @@ pub struct LockClause {
 impl fmt::Display for LockClause {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "FOR {}", &self.lock_type)?;
-        if let Some(ref of) = self.of {
-            write!(f, " OF {of}")?;
+        if !self.of.is_empty() {
+            write!(f, " OF {}", display_comma_separated(&self.of))?;
         }
         if let Some(ref nb) = self.nonblock {
             write!(f, " {nb}")?;
@@ -3565,6 +3652,14 @@ pub enum LockType {
     Share,
     /// `UPDATE` lock (exclusive/update lock).
     Update,
+    /// `KEY SHARE` lock, blocking only `FOR UPDATE`.
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-select.html#SQL-FOR-UPDATE-SHARE)
+    KeyShare,
+    /// `NO KEY UPDATE` lock, weaker than `UPDATE` as it allows `FOR KEY SHARE`.
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-select.html#SQL-FOR-UPDATE-SHARE)
+    NoKeyUpdate,
 }
 
 impl fmt::Display for LockType {
@@ -3572,6 +3667,8 @@ impl fmt::Display for LockType {
         let select_lock = match self {
             LockType::Share => "SHARE",
             LockType::Update => "UPDATE",
+            LockType::KeyShare => "KEY SHARE",
+            LockType::NoKeyUpdate => "NO KEY UPDATE",
         };
         write!(f, "{select_lock}")
     }
@@ -19,7 +19,9 @@ use crate::{
     ast::{
         ddl::AlterSchema, query::SelectItemQualifiedWildcardKind, AlterSchemaOperation, AlterTable,
         ColumnOptions, CreateOperator, CreateOperatorClass, CreateOperatorFamily, CreateView,
-        ExportData, Owner, TypedString,
+        Deduplicate, ExportData, GranteeName, HandlerOp, HandlerRead, LockTable, Owner,
+        PartitionDefinition, PartitionFunction, PartitionOptions, PartitionValues,
+        ShowStatementFilter, ShowStatementIn, TypedString,
     },
     tokenizer::TokenWithSpan,
 };
@@ -42,8 +44,8 @@ use super::{
     MergeInsertKind, MergeUpdateExpr, MergeUpdateKind, NamedParenthesizedList,
     NamedWindowDefinition, ObjectName, ObjectNamePart, Offset, OnConflict, OnConflictAction,
     OnInsert, OpenStatement, OrderBy, OrderByExpr, OrderByKind, OutputClause, Parens, Partition,
-    PartitionBoundValue, PivotValueSource, ProjectionSelect, Query, RaiseStatement,
-    RaiseStatementValue, ReferentialAction, RenameSelectItem, ReplaceSelectElement,
+    PartitionBoundValue, PivotOnColumn, PivotStatement, PivotValueSource, ProjectionSelect, Query,
+    RaiseStatement, RaiseStatementValue, ReferentialAction, RenameSelectItem, ReplaceSelectElement,
     ReplaceSelectItem, Select, SelectInto, SelectItem, SetExpr, SqlOption, Statement, Subscript,
     SymbolDefinition, TableAlias, TableAliasColumnDef, TableConstraint, TableFactor, TableObject,
     TableOptionsClustered, TableWithJoins, Update, UpdateTableFromKind, Use, Values, ViewColumnDef,
@@ -264,6 +266,7 @@ impl Spanned for Values {
 /// - [Statement::AlterRole]
 /// - [Statement::AttachDatabase]
 /// - [Statement::AttachDuckDBDatabase]
+/// - [Statement::DetachDatabase]
 /// - [Statement::DetachDuckDBDatabase]
 /// - [Statement::Drop]
 /// - [Statement::DropFunction]
@@ -280,7 +283,6 @@ impl Spanned for Values {
 /// - [Statement::ShowFunctions]
 /// - [Statement::ShowVariable]
 /// - [Statement::ShowStatus]
-/// - [Statement::ShowVariables]
 /// - [Statement::ShowCreate]
 /// - [Statement::ShowColumns]
 /// - [Statement::ShowTables]
@@ -297,6 +299,7 @@ impl Spanned for Values {
 /// - [Statement::CreateProcedure]
 /// - [Statement::CreateMacro]
 /// - [Statement::CreateStage]
+/// - [Statement::CreateFileFormat]
 /// - [Statement::Assert]
 /// - [Statement::Grant]
 /// - [Statement::Revoke]
@@ -314,10 +317,8 @@ impl Spanned for Values {
 /// - [Statement::CreateType]
 /// - [Statement::Pragma]
 /// - [Statement::Lock]
-/// - [Statement::LockTables]
-/// - [Statement::UnlockTables]
 /// - [Statement::Unload]
-/// - [Statement::OptimizeTable]
+/// - [Statement::Reindex]
 impl Spanned for Statement {
     fn span(&self) -> Span {
         match self {
@@ -387,6 +388,7 @@ impl Spanned for Statement {
             Statement::CreateRole(create_role) => create_role.span(),
             Statement::CreateExtension(create_extension) => create_extension.span(),
             Statement::CreateCollation(create_collation) => create_collation.span(),
+            Statement::CreateStatistics(create_statistics) => create_statistics.span(),
             Statement::DropExtension(drop_extension) => drop_extension.span(),
             Statement::DropOperator(drop_operator) => drop_operator.span(),
             Statement::DropOperatorFamily(drop_operator_family) => drop_operator_family.span(),
@@ -423,6 +425,7 @@ impl Spanned for Statement {
             Statement::AlterSession { .. } => Span::empty(),
             Statement::AttachDatabase { .. } => Span::empty(),
             Statement::AttachDuckDBDatabase { .. } => Span::empty(),
+            Statement::DetachDatabase { .. } => Span::empty(),
             Statement::DetachDuckDBDatabase { .. } => Span::empty(),
             Statement::Drop { .. } => Span::empty(),
             Statement::DropFunction(drop_function) => drop_function.span(),
@@ -437,8 +440,38 @@ impl Spanned for Statement {
             Statement::ShowFunctions { .. } => Span::empty(),
             Statement::ShowVariable { .. } => Span::empty(),
             Statement::ShowStatus { .. } => Span::empty(),
-            Statement::ShowVariables { .. } => Span::empty(),
+            Statement::ShowVariables {
+                show_token,
+                filter,
+                scope: _,
+            } => union_spans(
+                core::iter::once(show_token.0.span).chain(filter.iter().map(|f| f.span())),
+            ),
             Statement::ShowCreate { .. } => Span::empty(),
+            Statement::ShowCreateUser { show_token, user } => show_token.0.span.union(&user.span()),
+            Statement::ShowGrants {
+                show_token,
+                for_user,
+                using,
+            } => union_spans(
+                core::iter::once(show_token.0.span)
+                    .chain(for_user.iter().map(|u| u.span()))
+                    .chain(using.iter().map(|u| u.span())),
+            ),
+            Statement::ShowTableStatus {
+                show_token,
+                show_in,
+                filter,
+            }
+            | Statement::ShowOpenTables {
+                show_token,
+                show_in,
+                filter,
+            } => union_spans(
+                core::iter::once(show_token.0.span)
+                    .chain(show_in.iter().map(|i| i.span()))
+                    .chain(filter.iter().map(|f| f.span())),
+            ),
             Statement::ShowColumns { .. } => Span::empty(),
             Statement::ShowTables { .. } => Span::empty(),
             Statement::ShowCollation { .. } => Span::empty(),
@@ -457,6 +490,7 @@ impl Spanned for Statement {
             Statement::CreateProcedure { .. } => Span::empty(),
             Statement::CreateMacro { .. } => Span::empty(),
             Statement::CreateStage { .. } => Span::empty(),
+            Statement::CreateFileFormat { .. } => Span::empty(),
             Statement::Assert { .. } => Span::empty(),
             Statement::Grant { .. } => Span::empty(),
             Statement::Deny { .. } => Span::empty(),
@@ -470,16 +504,72 @@ impl Spanned for Statement {
             Statement::Savepoint { .. } => Span::empty(),
             Statement::ReleaseSavepoint { .. } => Span::empty(),
             Statement::Merge(merge) => merge.span(),
+            Statement::Pivot(pivot) => pivot.span(),
             Statement::Cache { .. } => Span::empty(),
             Statement::UNCache { .. } => Span::empty(),
             Statement::CreateSequence { .. } => Span::empty(),
             Statement::CreateType { .. } => Span::empty(),
             Statement::Pragma { .. } => Span::empty(),
             Statement::Lock(_) => Span::empty(),
-            Statement::LockTables { .. } => Span::empty(),
-            Statement::UnlockTables => Span::empty(),
+            Statement::LockTables { lock_token, tables } => union_spans(
+                core::iter::once(lock_token.0.span).chain(tables.iter().map(|t| t.span())),
+            ),
+            Statement::Handler {
+                handler_token,
+                table_name,
+                alias,
+                op,
+            } => union_spans(
+                core::iter::once(handler_token.0.span)
+                    .chain(core::iter::once(table_name.span()))
+                    .chain(alias.iter().map(|a| a.span))
+                    .chain(core::iter::once(op.span())),
+            ),
+            Statement::UnlockTables {
+                unlock_token,
+                tables_token,
+            } => unlock_token.0.span.union(&tables_token.0.span),
             Statement::Unload { .. } => Span::empty(),
-            Statement::OptimizeTable { .. } => Span::empty(),
+            Statement::OptimizeTable {
+                optimize_token,
+                location: _,
+                name,
+                additional_names,
+                has_table_keyword: _,
+                on_cluster,
+                partition,
+                include_final: _,
+                deduplicate,
+                predicate,
+                zorder,
+            } => union_spans(
+                core::iter::once(optimize_token.0.span)
+                    .chain(core::iter::once(name.span()))
+                    .chain(additional_names.iter().map(|n| n.span()))
+                    .chain(on_cluster.iter().map(|c| c.span))
+                    .chain(partition.iter().map(|p| p.span()))
+                    .chain(deduplicate.iter().filter_map(|d| match d {
+                        Deduplicate::All => None,
+                        Deduplicate::ByExpression(expr) => Some(expr.span()),
+                    }))
+                    .chain(predicate.iter().map(|p| p.span()))
+                    .chain(zorder.iter().flat_map(|z| z.iter().map(|e| e.span()))),
+            ),
+            Statement::CheckTable {
+                check_token,
+                names,
+                options: _,
+            } => union_spans(
+                core::iter::once(check_token.0.span).chain(names.iter().map(|n| n.span())),
+            ),
+            Statement::RepairTable {
+                repair_token,
+                location: _,
+                names,
+                options: _,
+            } => union_spans(
+                core::iter::once(repair_token.0.span).chain(names.iter().map(|n| n.span())),
+            ),
             Statement::CreatePolicy { .. } => Span::empty(),
             Statement::AlterPolicy { .. } => Span::empty(),
             Statement::AlterConnector { .. } => Span::empty(),
@@ -518,6 +608,85 @@ impl Spanned for Statement {
             Statement::Vacuum(..) => Span::empty(),
             Statement::AlterUser(..) => Span::empty(),
             Statement::Reset(..) => Span::empty(),
+            Statement::Reindex(..) => Span::empty(),
+        }
+    }
+}
+
+impl Spanned for GranteeName {
+    fn span(&self) -> Span {
+        match self {
+            GranteeName::ObjectName(object_name) => object_name.span(),
+            GranteeName::UserHost { user, host } => user.span.union(&host.span),
+        }
+    }
+}
+
+/// # partial span
+///
+/// Missing spans:
+/// - [ShowStatementIn::clause]
+/// - [ShowStatementIn::parent_type]
+impl Spanned for ShowStatementIn {
+    fn span(&self) -> Span {
+        self.parent_name
+            .as_ref()
+            .map(|n| n.span())
+            .unwrap_or(Span::empty())
+    }
+}
+
+/// # partial span
+///
+/// Missing spans:
+/// - [ShowStatementFilter::Like]
+/// - [ShowStatementFilter::ILike]
+/// - [ShowStatementFilter::NoKeyword]
+impl Spanned for ShowStatementFilter {
+    fn span(&self) -> Span {
+        match self {
+            ShowStatementFilter::Where(expr) => expr.span(),
+            ShowStatementFilter::Like(_)
+            | ShowStatementFilter::ILike(_)
+            | ShowStatementFilter::NoKeyword(_) => Span::empty(),
+        }
+    }
+}
+
+impl Spanned for LockTable {
+    fn span(&self) -> Span {
+        union_spans(core::iter::once(self.table.span).chain(self.alias.iter().map(|a| a.span)))
+    }
+}
+
+/// # partial span
+///
+/// Missing spans:
+/// - [HandlerOp::Open]
+/// - [HandlerOp::Close]
+impl Spanned for HandlerOp {
+    fn span(&self) -> Span {
+        match self {
+            HandlerOp::Open | HandlerOp::Close => Span::empty(),
+            HandlerOp::Read {
+                index,
+                read,
+                selection,
+                limit,
+            } => {
+                let values: &[Expr] = match read {
+                    HandlerRead::Compare { values, .. } => values,
+                    _ => &[],
+                };
+                union_spans(
+                    index
+                        .iter()
+                        .map(|i| i.span)
+                        .chain(values.iter().map(|v| v.span()))
+                        .chain(selection.iter().map(|s| s.span()))
+                        .chain(limit.iter().map(|l| l.span())),
+                )
+            }
         }
     }
 }
@@ -572,6 +741,8 @@ impl Spanned for CreateTable {
             primary_key: _,  // todo, clickhouse specific
             order_by: _,     // todo, clickhouse specific
             partition_by: _, // todo, BigQuery specific
+            partition_key,
+            partition_options,
             cluster_by: _,   // todo, BigQuery specific
             clustered_by: _, // todo, Hive specific
             inherits: _,     // todo, PostgreSQL specific
@@ -608,6 +779,7 @@ impl Spanned for CreateTable {
             multiset: _,
             fallback: _,
             with_data: _,
+            duplicate_handling: _,
         } = self;
 
         union_spans(
@@ -617,6 +789,12 @@ impl Spanned for CreateTable {
                 .chain(constraints.iter().map(|i| i.span()))
                 .chain(query.iter().map(|i| i.span()))
                 .chain(clone.iter().map(|i| i.span()))
+                .chain(
+                    partition_key
+                        .iter()
+                        .flat_map(|k| k.columns.iter().map(|i| i.span())),
+                )
+                .chain(partition_options.iter().map(|i| i.span()))
                 .chain(partition_of.iter().map(|i| i.span()))
                 .chain(for_values.iter().map(|i| i.span())),
         )
@@ -685,6 +863,54 @@ impl Spanned for ForValues {
     }
 }
 
+/// # partial span
+///
+/// Missing spans:
+/// - [PartitionOptions::partitions]
+impl Spanned for PartitionOptions {
+    fn span(&self) -> Span {
+        union_spans(
+            core::iter::once(self.function.span()).chain(self.definitions.iter().map(|d| d.span())),
+        )
+    }
+}
+
+impl Spanned for PartitionFunction {
+    fn span(&self) -> Span {
+        match self {
+            PartitionFunction::Hash { expr, .. }
+            | PartitionFunction::Range(expr)
+            | PartitionFunction::List(expr) => expr.span(),
+            PartitionFunction::Key { columns, .. }
+            | PartitionFunction::RangeColumns(columns)
+            | PartitionFunction::ListColumns(columns) => {
+                union_spans(columns.iter().map(|c| c.span))
+            }
+        }
+    }
+}
+
+impl Spanned for PartitionDefinition {
+    fn span(&self) -> Span {
+        union_spans(
+            core::iter::once(self.name.span)
+                .chain(self.values.iter().map(|v| v.span()))
+                .chain(self.options.iter().map(|o| o.span())),
+        )
+    }
+}
+
+impl Spanned for PartitionValues {
+    fn span(&self) -> Span {
+        match self {
+            PartitionValues::LessThan(exprs) | PartitionValues::In(exprs) => {
+                union_spans(exprs.iter().map(|e| e.span()))
+            }
+            PartitionValues::LessThanMaxValue => Span::empty(),
+        }
+    }
+}
+
 impl Spanned for CreateIndex {
     fn span(&self) -> Span {
         let CreateIndex {
@@ -904,6 +1130,14 @@ impl Spanned for AlterColumnOperation {
                 had_set: _,
             } => using.as_ref().map_or(Span::empty(), |u| u.span()),
             AlterColumnOperation::AddGenerated { .. } => Span::empty(),
+            AlterColumnOperation::SetStatistics { target } => target.span(),
+            AlterColumnOperation::SetOptions { options } => {
+                union_spans(options.iter().map(|o| o.span()))
+            }
+            AlterColumnOperation::ResetOptions { options } => {
+                union_spans(options.iter().map(|o| o.span))
+            }
+            AlterColumnOperation::SetStorage { storage_token, .. } => storage_token.0.span,
         }
     }
 }
@@ -999,6 +1233,28 @@ impl Spanned for Merge {
     }
 }
 
+impl Spanned for PivotStatement {
+    fn span(&self) -> Span {
+        union_spans(
+            [self.pivot_token.0.span, self.table.span()]
+                .into_iter()
+                .chain(self.on.iter().map(Spanned::span))
+                .chain(self.using.iter().map(Spanned::span))
+                .chain(self.group_by.iter().map(Spanned::span))
+                .chain(self.order_by.iter().map(Spanned::span))
+                .chain(self.limit.iter().map(Spanned::span)),
+        )
+    }
+}
+
+impl Spanned for PivotOnColumn {
+    fn span(&self) -> Span {
+        union_spans(
+            iter::once(self.expr.span()).chain(self.values.iter().flatten().map(Spanned::span)),
+        )
+    }
+}
+
 impl Spanned for FromTable {
     fn span(&self) -> Span {
         match self {
@@ -1618,6 +1874,7 @@ impl Spanned for Expr {
                 trim_where: _,
                 trim_what,
                 trim_characters,
+                bare_from: _,
             } => union_spans(
                 core::iter::once(expr.span())
                     .chain(trim_what.as_ref().map(|i| i.span()))
@@ -1717,6 +1974,7 @@ impl Spanned for ObjectNamePart {
                 .name
                 .span
                 .union(&union_spans(func.args.iter().map(|i| i.span()))),
+            ObjectNamePart::Wildcard(token) => token.0.span,
         }
     }
 }
@@ -2077,6 +2335,7 @@ impl Spanned for TableFactor {
                     .chain(where_clause.as_ref().map(|e| e.span()))
                     .chain(alias.as_ref().map(|a| a.span())),
             ),
+            TableFactor::ArrayJoinList(arrays) => union_spans(arrays.iter().map(|a| a.span())),
             TableFactor::OpenJsonTable { .. } => Span::empty(),
         }
     }
@@ -3101,4 +3360,77 @@ WHERE id = 1
             Span::new(Location::new(2, 8), Location::new(4, 52))
         );
     }
+
+    #[test]
+    fn test_statement_spans_cover_full_statement() {
+        let dialect = &crate::dialect::MySqlDialect {};
+        for sql in [
+            "SELECT a, b FROM t WHERE a = 1",
+            "INSERT INTO t (a, b) VALUES (1, 2)",
+            "UPDATE t SET a = 1 WHERE b = 2",
+            "DELETE FROM t WHERE a = 1",
+            "UNLOCK TABLES",
+            "HANDLER t OPEN AS h",
+            "HANDLER h READ idx FIRST WHERE a = 1 LIMIT 5",
+            "OPTIMIZE LOCAL TABLE t1, t2",
+            "SHOW GRANTS FOR 'u'@'h' USING r1, r2",
+            "SHOW OPEN TABLES IN db WHERE In_use > 0",
+            "SHOW VARIABLES WHERE a = 1",
+            "SHOW CREATE USER 'u'@'h'",
+        ] {
+            let stmt = Parser::parse_sql(dialect, sql).unwrap().pop().unwrap();
+            let test = SpanTest::new(dialect, sql);
+            assert_eq!(test.get_source(stmt.span()), sql);
+        }
+
+        // trailing keyword-only clauses carry no span of their own
+        for (sql, source) in [
+            (
+                "LOCK TABLES t1 READ LOCAL, t2 AS b LOW_PRIORITY WRITE",
+                "LOCK TABLES t1 READ LOCAL, t2 AS b",
+            ),
+            (
+                "HANDLER h READ idx >= (1, 2)",
+                "HANDLER h READ idx >= (1, 2",
+            ),
+            ("HANDLER h CLOSE", "HANDLER h"),
+            ("CHECK TABLE t1, t2 FOR UPGRADE", "CHECK TABLE t1, t2"),
+            (
+                "REPAIR NO_WRITE_TO_BINLOG TABLE t QUICK EXTENDED USE_FRM",
+                "REPAIR NO_WRITE_TO_BINLOG TABLE t",
+            ),
+            ("SHOW GRANTS", "SHOW"),
+            (
+                "SHOW TABLE STATUS FROM db LIKE 't%'",
+                "SHOW TABLE STATUS FROM db",
+            ),
+            ("SHOW GLOBAL VARIABLES", "SHOW"),
+        ] {
+            let stmt = Parser::parse_sql(dialect, sql).unwrap().pop().unwrap();
+            let test = SpanTest::new(dialect, sql);
+            assert_eq!(test.get_source(stmt.span()), source);
+        }
+
+        for (dialect, sql, source) in [
+            (
+                &crate::dialect::ClickHouseDialect {} as &dyn Dialect,
+                "OPTIMIZE TABLE t ON CLUSTER c PARTITION ID 'p' FINAL DEDUPLICATE",
+                "OPTIMIZE TABLE t ON CLUSTER c PARTITION ID 'p'",
+            ),
+            (
+                &crate::dialect::DatabricksDialect {},
+                "OPTIMIZE t WHERE a = 1 ZORDER BY (a, b)",
+                "OPTIMIZE t WHERE a = 1 ZORDER BY (a, b",
+            ),
+            (
+                &crate::dialect::PostgreSqlDialect {},
+                "CREATE STATISTICS IF NOT EXISTS s (ndistinct) ON a, b FROM t",
+                "s (ndistinct) ON a, b FROM t",
+            ),
+        ] {
+            let stmt = Parser::parse_sql(dialect, sql).unwrap().pop().unwrap();
+            let test = SpanTest::new(dialect, sql);
+            assert_eq!(test.get_source(stmt.span()), source);
+        }
+    }
 }
@@ -433,6 +433,30 @@ pub enum DateTimeField {
     TimezoneMinute,
     /// `TIMEZONE_REGION`
     TimezoneRegion,
+    /// `SECOND_MICROSECOND` (compound unit)
+    ///
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/expressions.html#temporal-intervals)
+    SecondMicrosecond,
+    /// `MINUTE_MICROSECOND` (compound unit)
+    MinuteMicrosecond,
+    /// `MINUTE_SECOND` (compound unit)
+    MinuteSecond,
+    /// `HOUR_MICROSECOND` (compound unit)
+    HourMicrosecond,
+    /// `HOUR_SECOND` (compound unit)
+    HourSecond,
+    /// `HOUR_MINUTE` (compound unit)
+    HourMinute,
+    /// `DAY_MICROSECOND` (compound unit)
+    DayMicrosecond,
+    /// `DAY_SECOND` (compound unit)
+    DaySecond,
+    /// `DAY_MINUTE` (compound unit)
+    DayMinute,
+    /// `DAY_HOUR` (compound unit)
+    DayHour,
+    /// `YEAR_MONTH` (compound unit)
+    YearMonth,
     /// `NODATETIME` indicates no date/time part
     NoDateTime,
     /// Arbitrary abbreviation or custom date-time part.
@@ -495,6 +519,17 @@ impl fmt::Display for DateTimeField {
             DateTimeField::TimezoneHour => write!(f, "TIMEZONE_HOUR"),
             DateTimeField::TimezoneMinute => write!(f, "TIMEZONE_MINUTE"),
             DateTimeField::TimezoneRegion => write!(f, "TIMEZONE_REGION"),
+            DateTimeField::SecondMicrosecond => write!(f, "SECOND_MICROSECOND"),
+            DateTimeField::MinuteMicrosecond => write!(f, "MINUTE_MICROSECOND"),
+            DateTimeField::MinuteSecond => write!(f, "MINUTE_SECOND"),
+            DateTimeField::HourMicrosecond => write!(f, "HOUR_MICROSECOND"),
+            DateTimeField::HourSecond => write!(f, "HOUR_SECOND"),
+            DateTimeField::HourMinute => write!(f, "HOUR_MINUTE"),
+            DateTimeField::DayMicrosecond => write!(f, "DAY_MICROSECOND"),
+            DateTimeField::DaySecond => write!(f, "DAY_SECOND"),
+            DateTimeField::DayMinute => write!(f, "DAY_MINUTE"),
+            DateTimeField::DayHour => write!(f, "DAY_HOUR"),
+            DateTimeField::YearMonth => write!(f, "YEAR_MONTH"),
             DateTimeField::NoDateTime => write!(f, "NODATETIME"),
             DateTimeField::Custom(custom) => write!(f, "{custom}"),
         }
@@ -146,6 +146,16 @@ impl Dialect for BigQueryDialect {
         true
     }
 
+    /// See <https://cloud.google.com/bigquery/docs/reference/standard-sql/query-syntax#group_by_all>
+    fn supports_group_by_all(&self) -> bool {
+        true
+    }
+
+    /// See <https://cloud.google.com/bigquery/docs/reference/standard-sql/query-syntax#qualify_clause>
+    fn supports_qualify_clause(&self) -> bool {
+        true
+    }
+
     fn is_column_alias(&self, kw: &Keyword, _parser: &mut Parser) -> bool {
         !RESERVED_FOR_COLUMN_ALIAS.contains(kw)
     }
@@ -166,4 +176,8 @@ impl Dialect for BigQueryDialect {
     fn supports_comma_separated_trim(&self) -> bool {
         true
     }
+
+    fn supports_merge_not_matched_by_source_or_target(&self) -> bool {
+        true
+    }
 }
@@ -104,6 +104,16 @@ impl Dialect for ClickHouseDialect {
         true
     }
 
+    /// See <https://clickhouse.com/docs/en/sql-reference/statements/select/group-by#group-by-all>
+    fn supports_group_by_all(&self) -> bool {
+        true
+    }
+
+    /// See <https://clickhouse.com/docs/en/sql-reference/statements/select/qualify>
+    fn supports_qualify_clause(&self) -> bool {
+        true
+    }
+
     /// Supported since 2020.
     /// See <https://clickhouse.com/docs/whats-new/changelog/2020#backward-incompatible-change-2>
     fn supports_nested_comments(&self) -> bool {
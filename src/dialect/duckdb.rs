@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::dialect::Dialect;
+use crate::dialect::{Dialect, IdentCaseRule};
 
 /// A [`Dialect`] for [DuckDB](https://duckdb.org/)
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -36,6 +36,11 @@ impl Dialect for DuckDbDialect {
         ch.is_alphabetic() || ch.is_ascii_digit() || ch == '$' || ch == '_'
     }
 
+    /// See <https://duckdb.org/docs/stable/sql/dialect/keywords_and_identifiers#case-sensitivity-of-identifiers>
+    fn normalizes_idents_to(&self) -> IdentCaseRule {
+        IdentCaseRule::CaseInsensitive
+    }
+
     fn supports_filter_during_aggregation(&self) -> bool {
         true
     }
@@ -44,6 +49,16 @@ impl Dialect for DuckDbDialect {
         true
     }
 
+    /// See DuckDB <https://duckdb.org/docs/sql/query_syntax/groupby.html#group-by-all>
+    fn supports_group_by_all(&self) -> bool {
+        true
+    }
+
+    /// See DuckDB <https://duckdb.org/docs/sql/query_syntax/qualify>
+    fn supports_qualify_clause(&self) -> bool {
+        true
+    }
+
     fn supports_bitwise_shift_operators(&self) -> bool {
         true
     }
@@ -125,6 +140,11 @@ impl Dialect for DuckDbDialect {
         true
     }
 
+    /// See <https://duckdb.org/docs/sql/statements/pivot#simplified-pivot-syntax>
+    fn supports_pivot_statement(&self) -> bool {
+        true
+    }
+
     /// See <https://duckdb.org/docs/sql/query_syntax/select#replace-clause>
     fn supports_select_wildcard_replace(&self) -> bool {
         true
@@ -133,4 +153,9 @@ impl Dialect for DuckDbDialect {
     fn supports_comma_separated_trim(&self) -> bool {
         true
     }
+
+    /// See <https://duckdb.org/docs/stable/sql/statements/merge_into>
+    fn supports_merge_not_matched_by_source_or_target(&self) -> bool {
+        true
+    }
 }
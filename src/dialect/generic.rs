@@ -229,6 +229,10 @@ impl Dialect for GenericDialect {
         true
     }
 
+    fn supports_data_type_zerofill_suffix(&self) -> bool {
+        true
+    }
+
     fn supports_interval_options(&self) -> bool {
         true
     }
@@ -261,6 +265,18 @@ impl Dialect for GenericDialect {
         true
     }
 
+    fn supports_pivot_statement(&self) -> bool {
+        true
+    }
+
+    fn supports_reindex(&self) -> bool {
+        true
+    }
+
+    fn supports_create_statistics(&self) -> bool {
+        true
+    }
+
     fn supports_prewhere(&self) -> bool {
         true
     }
@@ -305,6 +321,10 @@ impl Dialect for GenericDialect {
         true
     }
 
+    fn supports_cte_materialization_hint(&self) -> bool {
+        true
+    }
+
     fn supports_select_item_multi_column_alias(&self) -> bool {
         true
     }
@@ -312,4 +332,8 @@ impl Dialect for GenericDialect {
     fn supports_xml_expressions(&self) -> bool {
         true
     }
+
+    fn supports_merge_not_matched_by_source_or_target(&self) -> bool {
+        true
+    }
 }
@@ -214,7 +214,8 @@ pub trait Dialect: Debug + Any {
     /// implementation, accepting "double quoted" ids is both ANSI-compliant
     /// and appropriate for most dialects (with the notable exception of
     /// MySQL, MS SQL, and sqlite). You can accept one of characters listed
-    /// in `Word::matching_end_quote` here
+    /// in `Word::matching_end_quote` here; any other character is used as
+    /// its own closing delimiter.
     fn is_delimited_identifier_start(&self, ch: char) -> bool {
         ch == '"' || ch == '`'
     }
@@ -254,6 +255,12 @@ pub trait Dialect: Debug + Any {
         None
     }
 
+    /// Returns how the dialect folds the case of identifiers when resolving
+    /// names. See [`Ident::normalize`].
+    fn normalizes_idents_to(&self) -> IdentCaseRule {
+        IdentCaseRule::Preserve
+    }
+
     /// Determine if a character is a valid start character for an unquoted identifier
     fn is_identifier_start(&self, ch: char) -> bool;
 
@@ -358,6 +365,21 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports MySQL-style partitioning options
+    /// after the table options of a `CREATE TABLE` statement, e.g.
+    ///
+    /// ```sql
+    /// CREATE TABLE t (a INT) PARTITION BY RANGE (a) (
+    ///     PARTITION p0 VALUES LESS THAN (10) DATA DIRECTORY = '/data/p0',
+    ///     PARTITION p1 VALUES LESS THAN MAXVALUE
+    /// )
+    /// ```
+    ///
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/create-table.html#create-table-partitioning)
+    fn supports_create_table_partition_options(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports ClickHouse-style `ARRAY JOIN` / `LEFT ARRAY JOIN` /
     /// `INNER ARRAY JOIN` syntax for unnesting arrays inline.
     ///
@@ -694,6 +716,24 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Return true if the dialect supports the `BY SOURCE` and `BY TARGET`
+    /// qualifiers on `WHEN NOT MATCHED` clauses of a `MERGE` statement.
+    ///
+    /// Example:
+    /// ```sql
+    /// MERGE INTO t USING s ON t.id = s.id
+    /// WHEN NOT MATCHED BY TARGET THEN INSERT (id) VALUES (s.id)
+    /// WHEN NOT MATCHED BY SOURCE THEN DELETE
+    /// ```
+    ///
+    /// [SQL Server](https://learn.microsoft.com/en-us/sql/t-sql/statements/merge-transact-sql)
+    /// [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/dml-syntax#merge_statement)
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-merge.html)
+    /// [DuckDB](https://duckdb.org/docs/stable/sql/statements/merge_into)
+    fn supports_merge_not_matched_by_source_or_target(&self) -> bool {
+        false
+    }
+
     /// Return true if the dialect supports pipe operator.
     ///
     /// Example:
@@ -900,7 +940,8 @@ pub trait Dialect: Debug + Any {
             | Token::AtSign
             | Token::ShiftLeftVerticalBar
             | Token::VerticalBarShiftRight => Ok(p!(Pipe)),
-            Token::Caret | Token::Sharp | Token::ShiftRight | Token::ShiftLeft => Ok(p!(Caret)),
+            Token::Caret | Token::Sharp => Ok(p!(Caret)),
+            Token::ShiftRight | Token::ShiftLeft => Ok(p!(Shift)),
             Token::Ampersand => Ok(p!(Ampersand)),
             Token::Plus | Token::Minus => Ok(p!(PlusMinus)),
             Token::Mul | Token::Div | Token::DuckIntDiv | Token::Mod | Token::StringConcat => {
@@ -968,6 +1009,7 @@ pub trait Dialect: Debug + Any {
             Precedence::Xor => 24,
             Precedence::Ampersand => 23,
             Precedence::Caret => 22,
+            Precedence::Shift => 22,
             Precedence::Pipe => 21,
             Precedence::Colon => 21,
             Precedence::Between => 20,
@@ -976,6 +1018,8 @@ pub trait Dialect: Debug + Any {
             Precedence::Is => 17,
             Precedence::PgOther => 16,
             Precedence::UnaryNot => 15,
+            Precedence::UnaryPlusMinus => 40,
+            Precedence::UnaryBitwiseNot => 30,
             Precedence::And => 10,
             Precedence::Or => 5,
         }
@@ -1224,6 +1268,16 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect accepts an `IGNORE` or `REPLACE` duplicate
+    /// handling keyword before the query of a `CREATE TABLE ... SELECT`
+    ///
+    /// ```sql
+    /// CREATE TABLE t (id INT PRIMARY KEY) IGNORE SELECT id FROM s
+    /// ```
+    fn supports_create_table_select_duplicate_handling(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect accepts a comma-separated list of table-level
     /// options placed between the table name and the column-list parenthesis, e.g.
     ///
@@ -1406,6 +1460,22 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports `GROUP BY ALL`.
+    /// `ALL` which means all non-aggregate columns of the SELECT clause.
+    ///
+    /// For example: ```SELECT city, count(*) FROM addresses GROUP BY ALL;```.
+    fn supports_group_by_all(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the dialect supports a `QUALIFY` clause, which filters
+    /// rows on the results of window functions.
+    ///
+    /// For example: ```SELECT i FROM t QUALIFY ROW_NUMBER() OVER (PARTITION BY p ORDER BY o) = 1```.
+    fn supports_qualify_clause(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports `ORDER BY ALL`.
     /// `ALL` which means all columns of the SELECT clause.
     ///
@@ -1488,6 +1558,16 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if this dialect allows a `ZEROFILL` suffix after numeric data types.
+    ///
+    /// Example:
+    /// ```sql
+    /// CREATE TABLE t (d DECIMAL(10,2) UNSIGNED ZEROFILL);
+    /// ```
+    fn supports_data_type_zerofill_suffix(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports the `INTERVAL` data type with [Postgres]-style options.
     ///
     /// Examples:
@@ -1649,10 +1729,47 @@ pub trait Dialect: Debug + Any {
     /// ```
     ///
     /// [DuckDB](https://duckdb.org/docs/sql/statements/attach#detach-syntax)
+    /// [SQLite](https://sqlite.org/lang_detach.html)
     fn supports_detach(&self) -> bool {
         false
     }
 
+    /// Returns true if this dialect supports the simplified `PIVOT` statement.
+    ///
+    /// Example:
+    /// ```sql
+    /// PIVOT cities ON year USING SUM(population) AS total GROUP BY country;
+    /// ```
+    ///
+    /// [DuckDB](https://duckdb.org/docs/sql/statements/pivot#simplified-pivot-syntax)
+    fn supports_pivot_statement(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this dialect supports the `REINDEX` statement.
+    ///
+    /// Example:
+    /// ```sql
+    /// REINDEX (VERBOSE) TABLE CONCURRENTLY my_table;
+    /// ```
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-reindex.html)
+    fn supports_reindex(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this dialect supports the `CREATE STATISTICS` statement.
+    ///
+    /// Example:
+    /// ```sql
+    /// CREATE STATISTICS s (ndistinct) ON a, b FROM t;
+    /// ```
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createstatistics.html)
+    fn supports_create_statistics(&self) -> bool {
+        false
+    }
+
     /// Returns true if this dialect supports the `PREWHERE` clause
     /// in `SELECT` statements.
     ///
@@ -1745,6 +1862,17 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns true if the dialect supports a `[NOT] MATERIALIZED` hint
+    /// after `AS` in a CTE definition. For example:
+    /// ```sql
+    /// WITH cte_name AS MATERIALIZED (SELECT ...)
+    /// ```
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/queries-with.html#QUERIES-WITH-CTE-MATERIALIZATION)
+    fn supports_cte_materialization_hint(&self) -> bool {
+        false
+    }
+
     /// Returns true if the dialect supports parenthesized multi-column
     /// aliases in SELECT items. For example:
     /// ```sql
@@ -1804,6 +1932,23 @@ pub trait Dialect: Debug + Any {
     }
 }
 
+/// How a dialect folds the case of identifiers when resolving names.
+///
+/// See [`Dialect::normalizes_idents_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentCaseRule {
+    /// Identifiers are matched exactly as written.
+    Preserve,
+    /// Unquoted identifiers are folded to lowercase; quoted identifiers are
+    /// matched exactly.
+    Lowercase,
+    /// Unquoted identifiers are folded to uppercase; quoted identifiers are
+    /// matched exactly.
+    Uppercase,
+    /// All identifiers, quoted or not, are matched case-insensitively.
+    CaseInsensitive,
+}
+
 /// Operators for which precedence must be defined.
 ///
 /// Higher number -> higher precedence.
@@ -1826,6 +1971,8 @@ pub enum Precedence {
     Ampersand,
     /// Bitwise `CARET` (^) for some dialects.
     Caret,
+    /// Bitwise shift operators (`<<`, `>>`).
+    Shift,
     /// Bitwise `OR` / pipe operator (`|`).
     Pipe,
     /// `:` operator for json/variant access.
@@ -1842,6 +1989,10 @@ pub enum Precedence {
     PgOther,
     /// Unary `NOT`.
     UnaryNot,
+    /// Operand of a unary `+` or `-`.
+    UnaryPlusMinus,
+    /// Operand of a unary bitwise `~`.
+    UnaryBitwiseNot,
     /// Logical `AND`.
     And,
     /// Logical `OR` (lowest precedence).
@@ -20,7 +20,7 @@ use crate::ast::{
     BeginEndStatements, ConditionalStatementBlock, ConditionalStatements, CreateTrigger,
     GranteesType, IfStatement, Statement,
 };
-use crate::dialect::Dialect;
+use crate::dialect::{Dialect, IdentCaseRule};
 use crate::keywords::Keyword;
 use crate::parser::{Parser, ParserError};
 use crate::tokenizer::Token;
@@ -55,6 +55,12 @@ impl Dialect for MsSqlDialect {
         Some('[')
     }
 
+    /// Identifier matching follows the database collation, which is
+    /// case-insensitive by default.
+    fn normalizes_idents_to(&self) -> IdentCaseRule {
+        IdentCaseRule::CaseInsensitive
+    }
+
     /// SQL Server has `CONVERT(type, value)` instead of `CONVERT(value, type)`
     /// <https://learn.microsoft.com/en-us/sql/t-sql/functions/cast-and-convert-transact-sql?view=sql-server-ver16>
     fn convert_type_before_value(&self) -> bool {
@@ -248,6 +254,10 @@ impl Dialect for MsSqlDialect {
             _ => None,
         }
     }
+
+    fn supports_merge_not_matched_by_source_or_target(&self) -> bool {
+        true
+    }
 }
 
 impl MsSqlDialect {
@@ -17,12 +17,19 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
+#[cfg(not(feature = "std"))]
+use alloc::vec;
 
 use crate::{
-    ast::{BinaryOperator, Expr, LockTable, LockTableType, Statement},
-    dialect::Dialect,
+    ast::{
+        helpers::attached_token::AttachedToken, BinaryOperator, CheckTableOption, Expr,
+        FlushLocation, HandlerOp, HandlerRead, LockTable, LockTableType, RepairTableOption,
+        ShowStatementFilter, ShowStatementIn, ShowStatementInClause, Statement,
+    },
+    dialect::{Dialect, IdentCaseRule, Precedence},
     keywords::Keyword,
     parser::{Parser, ParserError},
+    tokenizer::Token,
 };
 
 use super::keywords;
@@ -34,6 +41,8 @@ const RESERVED_FOR_TABLE_ALIAS_MYSQL: &[Keyword] = &[
     Keyword::STRAIGHT_JOIN,
 ];
 
+const RESERVED_FOR_COLUMN_ALIAS_MYSQL: &[Keyword] = &[Keyword::FOR, Keyword::LOCK, Keyword::WINDOW];
+
 /// A [`Dialect`] for [MySQL](https://www.mysql.com/)
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -67,6 +76,15 @@ impl Dialect for MySqlDialect {
         Some('`')
     }
 
+    /// Column, index and alias names are case-insensitive whether quoted or
+    /// not. Database and table names follow the `lower_case_table_names`
+    /// server setting, which defaults to case-insensitive on Windows and macOS.
+    ///
+    /// See <https://dev.mysql.com/doc/refman/8.4/en/identifier-case-sensitivity.html>
+    fn normalizes_idents_to(&self) -> IdentCaseRule {
+        IdentCaseRule::CaseInsensitive
+    }
+
     // See https://dev.mysql.com/doc/refman/8.0/en/string-literals.html#character-escape-sequences
     fn supports_string_literal_backslash_escape(&self) -> bool {
         true
@@ -98,30 +116,45 @@ impl Dialect for MySqlDialect {
         &self,
         parser: &mut crate::parser::Parser,
         expr: &crate::ast::Expr,
-        _precedence: u8,
+        precedence: u8,
     ) -> Option<Result<crate::ast::Expr, ParserError>> {
-        // Parse DIV as an operator
-        if parser.parse_keyword(Keyword::DIV) {
-            let left = Box::new(expr.clone());
-            let right = Box::new(match parser.parse_expr() {
-                Ok(expr) => expr,
-                Err(e) => return Some(Err(e)),
-            });
-            Some(Ok(Expr::BinaryOp {
-                left,
-                op: BinaryOperator::MyIntegerDivide,
-                right,
-            }))
+        // Parse DIV and MOD as operators
+        let op = if parser.parse_keyword(Keyword::DIV) {
+            BinaryOperator::MyIntegerDivide
+        } else if parser.parse_keyword(Keyword::MOD) {
+            BinaryOperator::MyModulo
         } else {
-            None
-        }
+            return None;
+        };
+        let left = Box::new(expr.clone());
+        let right = Box::new(match parser.parse_subexpr(precedence) {
+            Ok(expr) => expr,
+            Err(e) => return Some(Err(e)),
+        });
+        Some(Ok(Expr::BinaryOp { left, op, right }))
     }
 
     fn parse_statement(&self, parser: &mut Parser) -> Option<Result<Statement, ParserError>> {
-        if parser.parse_keywords(&[Keyword::LOCK, Keyword::TABLES]) {
+        if parser.peek_keywords(&[Keyword::LOCK, Keyword::TABLES]) {
             Some(parse_lock_tables(parser))
-        } else if parser.parse_keywords(&[Keyword::UNLOCK, Keyword::TABLES]) {
+        } else if parser.peek_keywords(&[Keyword::UNLOCK, Keyword::TABLES]) {
             Some(parse_unlock_tables(parser))
+        } else if parser.peek_keyword(Keyword::HANDLER) {
+            Some(parse_handler(parser))
+        } else if parser.peek_keyword(Keyword::OPTIMIZE) {
+            Some(parse_optimize_table(parser))
+        } else if parser.peek_keywords(&[Keyword::CHECK, Keyword::TABLE]) {
+            Some(parse_check_table(parser))
+        } else if parser.peek_keyword(Keyword::REPAIR) {
+            Some(parse_repair_table(parser))
+        } else if parser.peek_keywords(&[Keyword::SHOW, Keyword::GRANTS]) {
+            Some(parse_show_grants(parser))
+        } else if parser.peek_keywords(&[Keyword::SHOW, Keyword::TABLE, Keyword::STATUS]) {
+            Some(parse_show_table_status(parser))
+        } else if parser.peek_keywords(&[Keyword::SHOW, Keyword::OPEN, Keyword::TABLES]) {
+            Some(parse_show_open_tables(parser))
+        } else if parser.peek_keywords(&[Keyword::SHOW, Keyword::CREATE, Keyword::USER]) {
+            Some(parse_show_create_user(parser))
         } else {
             None
         }
@@ -140,6 +173,11 @@ impl Dialect for MySqlDialect {
         true
     }
 
+    /// See: <https://dev.mysql.com/doc/refman/8.4/en/create-table-select.html>
+    fn supports_create_table_select_duplicate_handling(&self) -> bool {
+        true
+    }
+
     /// See: <https://dev.mysql.com/doc/refman/8.4/en/insert.html>
     fn supports_insert_set(&self) -> bool {
         true
@@ -155,6 +193,52 @@ impl Dialect for MySqlDialect {
                 && !RESERVED_FOR_TABLE_ALIAS_MYSQL.contains(kw))
     }
 
+    /// MySQL supports `MOD` as an infix operator.
+    fn get_next_precedence(&self, parser: &Parser) -> Option<Result<u8, ParserError>> {
+        match &parser.peek_token_ref().token {
+            Token::Word(w) if w.keyword == Keyword::MOD => {
+                Some(Ok(self.prec_value(Precedence::MulDivModOp)))
+            }
+            _ => None,
+        }
+    }
+
+    /// MySQL binds unary `-`, `+` and `~` tighter than `^`, `^` tighter than
+    /// `*`, and `<<`/`>>` tighter than `&`.
+    ///
+    /// See: <https://dev.mysql.com/doc/refman/8.4/en/operator-precedence.html>
+    fn prec_value(&self, prec: Precedence) -> u8 {
+        match prec {
+            Precedence::Period => 100,
+            Precedence::DoubleColon => 50,
+            Precedence::UnaryPlusMinus => 45,
+            Precedence::UnaryBitwiseNot => 45,
+            Precedence::Caret => 42,
+            Precedence::AtTz => 41,
+            Precedence::MulDivModOp => 40,
+            Precedence::PlusMinus => 30,
+            Precedence::Shift => 29,
+            Precedence::Xor => 24,
+            Precedence::Ampersand => 23,
+            Precedence::Pipe => 21,
+            Precedence::Colon => 21,
+            Precedence::Between => 20,
+            Precedence::Eq => 20,
+            Precedence::Like => 19,
+            Precedence::Is => 17,
+            Precedence::PgOther => 16,
+            Precedence::UnaryNot => 15,
+            Precedence::And => 10,
+            Precedence::Or => 5,
+        }
+    }
+
+    /// See: <https://dev.mysql.com/doc/refman/8.4/en/keywords.html>
+    fn is_column_alias(&self, kw: &Keyword, _parser: &mut Parser) -> bool {
+        !keywords::RESERVED_FOR_COLUMN_ALIAS.contains(kw)
+            && !RESERVED_FOR_COLUMN_ALIAS_MYSQL.contains(kw)
+    }
+
     fn supports_table_hints(&self) -> bool {
         true
     }
@@ -188,6 +272,10 @@ impl Dialect for MySqlDialect {
         true
     }
 
+    fn supports_data_type_zerofill_suffix(&self) -> bool {
+        true
+    }
+
     fn supports_cross_join_constraint(&self) -> bool {
         true
     }
@@ -216,13 +304,23 @@ impl Dialect for MySqlDialect {
     fn supports_key_column_option(&self) -> bool {
         true
     }
+
+    /// See: <https://dev.mysql.com/doc/refman/8.4/en/create-table.html#create-table-partitioning>
+    fn supports_create_table_partition_options(&self) -> bool {
+        true
+    }
 }
 
 /// `LOCK TABLES`
 /// <https://dev.mysql.com/doc/refman/8.0/en/lock-tables.html>
 fn parse_lock_tables(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let lock_token = parser.expect_keyword(Keyword::LOCK)?;
+    parser.expect_keyword_is(Keyword::TABLES)?;
     let tables = parser.parse_comma_separated(parse_lock_table)?;
-    Ok(Statement::LockTables { tables })
+    Ok(Statement::LockTables {
+        lock_token: AttachedToken(lock_token),
+        tables,
+    })
 }
 
 // tbl_name [[AS] alias] lock_type
@@ -251,15 +349,303 @@ fn parse_lock_tables_type(parser: &mut Parser) -> Result<LockTableType, ParserEr
         Ok(LockTableType::Write {
             low_priority: false,
         })
-    } else if parser.parse_keywords(&[Keyword::LOW_PRIORITY, Keyword::WRITE]) {
+    } else if parser.parse_keyword(Keyword::LOW_PRIORITY) {
+        parser.expect_keyword_is(Keyword::WRITE)?;
         Ok(LockTableType::Write { low_priority: true })
     } else {
-        parser.expected_ref("an lock type in LOCK TABLES", parser.peek_token_ref())
+        parser.expected_one_of_keywords(
+            &[Keyword::READ, Keyword::WRITE, Keyword::LOW_PRIORITY],
+            parser.peek_token_ref(),
+        )
     }
 }
 
 /// UNLOCK TABLES
 /// <https://dev.mysql.com/doc/refman/8.0/en/lock-tables.html>
-fn parse_unlock_tables(_parser: &mut Parser) -> Result<Statement, ParserError> {
-    Ok(Statement::UnlockTables)
+fn parse_unlock_tables(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let unlock_token = parser.expect_keyword(Keyword::UNLOCK)?;
+    let tables_token = parser.expect_keyword(Keyword::TABLES)?;
+    Ok(Statement::UnlockTables {
+        unlock_token: AttachedToken(unlock_token),
+        tables_token: AttachedToken(tables_token),
+    })
+}
+
+/// `OPTIMIZE [NO_WRITE_TO_BINLOG | LOCAL] TABLE tbl_name [, tbl_name] ...`
+/// <https://dev.mysql.com/doc/refman/8.4/en/optimize-table.html>
+fn parse_optimize_table(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let optimize_token = parser.expect_keyword(Keyword::OPTIMIZE)?;
+    let location = parse_binlog_location(parser);
+    parser.expect_keyword_is(Keyword::TABLE)?;
+    let name = parser.parse_object_name(false)?;
+    let additional_names = if parser.consume_token(&Token::Comma) {
+        parser.parse_comma_separated(|p| p.parse_object_name(false))?
+    } else {
+        vec![]
+    };
+
+    Ok(Statement::OptimizeTable {
+        optimize_token: AttachedToken(optimize_token),
+        location,
+        name,
+        additional_names,
+        has_table_keyword: true,
+        on_cluster: None,
+        partition: None,
+        include_final: false,
+        deduplicate: None,
+        predicate: None,
+        zorder: None,
+    })
+}
+
+/// `CHECK TABLE tbl_name [, tbl_name] ... [option] ...`
+/// <https://dev.mysql.com/doc/refman/8.4/en/check-table.html>
+fn parse_check_table(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let check_token = parser.expect_keyword(Keyword::CHECK)?;
+    parser.expect_keyword_is(Keyword::TABLE)?;
+    let names = parser.parse_comma_separated(|p| p.parse_object_name(false))?;
+    let mut options = vec![];
+    loop {
+        let option = if parser.parse_keywords(&[Keyword::FOR, Keyword::UPGRADE]) {
+            CheckTableOption::ForUpgrade
+        } else {
+            match parser.parse_one_of_keywords(&[
+                Keyword::QUICK,
+                Keyword::FAST,
+                Keyword::MEDIUM,
+                Keyword::EXTENDED,
+                Keyword::CHANGED,
+            ]) {
+                Some(Keyword::QUICK) => CheckTableOption::Quick,
+                Some(Keyword::FAST) => CheckTableOption::Fast,
+                Some(Keyword::MEDIUM) => CheckTableOption::Medium,
+                Some(Keyword::EXTENDED) => CheckTableOption::Extended,
+                Some(Keyword::CHANGED) => CheckTableOption::Changed,
+                _ => break,
+            }
+        };
+        options.push(option);
+    }
+
+    Ok(Statement::CheckTable {
+        check_token: AttachedToken(check_token),
+        names,
+        options,
+    })
+}
+
+/// `REPAIR [NO_WRITE_TO_BINLOG | LOCAL] TABLE tbl_name [, tbl_name] ... [QUICK] [EXTENDED] [USE_FRM]`
+/// <https://dev.mysql.com/doc/refman/8.4/en/repair-table.html>
+fn parse_repair_table(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let repair_token = parser.expect_keyword(Keyword::REPAIR)?;
+    let location = parse_binlog_location(parser);
+    parser.expect_keyword_is(Keyword::TABLE)?;
+    let names = parser.parse_comma_separated(|p| p.parse_object_name(false))?;
+    let mut options = vec![];
+    if parser.parse_keyword(Keyword::QUICK) {
+        options.push(RepairTableOption::Quick);
+    }
+    if parser.parse_keyword(Keyword::EXTENDED) {
+        options.push(RepairTableOption::Extended);
+    }
+    if parser.parse_keyword(Keyword::USE_FRM) {
+        options.push(RepairTableOption::UseFrm);
+    }
+
+    Ok(Statement::RepairTable {
+        repair_token: AttachedToken(repair_token),
+        location,
+        names,
+        options,
+    })
+}
+
+// [NO_WRITE_TO_BINLOG | LOCAL]
+fn parse_binlog_location(parser: &mut Parser) -> Option<FlushLocation> {
+    if parser.parse_keyword(Keyword::NO_WRITE_TO_BINLOG) {
+        Some(FlushLocation::NoWriteToBinlog)
+    } else if parser.parse_keyword(Keyword::LOCAL) {
+        Some(FlushLocation::Local)
+    } else {
+        None
+    }
+}
+
+/// `HANDLER tbl_name { OPEN [ [AS] alias ] | READ ... | CLOSE }`
+/// <https://dev.mysql.com/doc/refman/8.4/en/handler.html>
+fn parse_handler(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let handler_token = parser.expect_keyword(Keyword::HANDLER)?;
+    let table_name = parser.parse_object_name(false)?;
+    let mut alias = None;
+    let op = if parser.parse_keyword(Keyword::OPEN) {
+        alias = parser.parse_optional_alias(&[])?;
+        HandlerOp::Open
+    } else if parser.parse_keyword(Keyword::READ) {
+        parse_handler_read(parser)?
+    } else if parser.parse_keyword(Keyword::CLOSE) {
+        HandlerOp::Close
+    } else {
+        return parser.expected_one_of_keywords(
+            &[Keyword::OPEN, Keyword::READ, Keyword::CLOSE],
+            parser.peek_token_ref(),
+        );
+    };
+
+    Ok(Statement::Handler {
+        handler_token: AttachedToken(handler_token),
+        table_name,
+        alias,
+        op,
+    })
+}
+
+// READ [index_name] { { = | <= | >= | < | > } (value, ...) | FIRST | NEXT | PREV | LAST }
+//     [ WHERE where_condition ] [ LIMIT ... ]
+fn parse_handler_read(parser: &mut Parser) -> Result<HandlerOp, ParserError> {
+    fn is_read_start(token: &Token) -> bool {
+        match token {
+            Token::Eq | Token::LtEq | Token::GtEq | Token::Lt | Token::Gt => true,
+            Token::Word(w) => matches!(
+                w.keyword,
+                Keyword::FIRST | Keyword::NEXT | Keyword::PREV | Keyword::LAST
+            ),
+            _ => false,
+        }
+    }
+
+    // `READ FIRST` and `READ NEXT` may be used without an index
+    let index = match &parser.peek_token_ref().token {
+        Token::Word(w)
+            if matches!(w.keyword, Keyword::FIRST | Keyword::NEXT)
+                && !is_read_start(&parser.peek_nth_token_ref(1).token) =>
+        {
+            None
+        }
+        _ => Some(parser.parse_identifier()?),
+    };
+
+    let next_token = parser.next_token();
+    let op = match &next_token.token {
+        Token::Eq => Some(BinaryOperator::Eq),
+        Token::LtEq => Some(BinaryOperator::LtEq),
+        Token::GtEq => Some(BinaryOperator::GtEq),
+        Token::Lt => Some(BinaryOperator::Lt),
+        Token::Gt => Some(BinaryOperator::Gt),
+        _ => None,
+    };
+    let read = match (op, &next_token.token) {
+        (Some(op), _) => {
+            parser.expect_token(&Token::LParen)?;
+            let values = parser.parse_comma_separated(Parser::parse_expr)?;
+            parser.expect_token(&Token::RParen)?;
+            HandlerRead::Compare { op, values }
+        }
+        (None, Token::Word(w)) if w.keyword == Keyword::FIRST => HandlerRead::First,
+        (None, Token::Word(w)) if w.keyword == Keyword::NEXT => HandlerRead::Next,
+        (None, Token::Word(w)) if w.keyword == Keyword::PREV => HandlerRead::Prev,
+        (None, Token::Word(w)) if w.keyword == Keyword::LAST => HandlerRead::Last,
+        _ => {
+            return parser.expected(
+                "one of =, <=, >=, <, >, FIRST, NEXT, PREV, LAST",
+                next_token,
+            )
+        }
+    };
+
+    let selection = if parser.parse_keyword(Keyword::WHERE) {
+        Some(parser.parse_expr()?)
+    } else {
+        None
+    };
+    let limit = parser.parse_optional_limit_clause()?;
+
+    Ok(HandlerOp::Read {
+        index,
+        read,
+        selection,
+        limit,
+    })
+}
+
+/// `SHOW GRANTS [FOR user [USING role [, role] ...]]`
+/// <https://dev.mysql.com/doc/refman/8.4/en/show-grants.html>
+fn parse_show_grants(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let show_token = parser.expect_keyword(Keyword::SHOW)?;
+    parser.expect_keyword_is(Keyword::GRANTS)?;
+    let for_user = if parser.parse_keyword(Keyword::FOR) {
+        Some(parser.parse_grantee_name()?)
+    } else {
+        None
+    };
+    let using = if for_user.is_some() && parser.parse_keyword(Keyword::USING) {
+        parser.parse_comma_separated(Parser::parse_grantee_name)?
+    } else {
+        vec![]
+    };
+    Ok(Statement::ShowGrants {
+        show_token: AttachedToken(show_token),
+        for_user,
+        using,
+    })
+}
+
+/// `SHOW CREATE USER user`
+/// <https://dev.mysql.com/doc/refman/8.4/en/show-create-user.html>
+fn parse_show_create_user(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let show_token = parser.expect_keyword(Keyword::SHOW)?;
+    parser.expect_keywords(&[Keyword::CREATE, Keyword::USER])?;
+    let user = parser.parse_grantee_name()?;
+    Ok(Statement::ShowCreateUser {
+        show_token: AttachedToken(show_token),
+        user,
+    })
+}
+
+/// `SHOW TABLE STATUS [{FROM | IN} db_name] [LIKE 'pattern' | WHERE expr]`
+/// <https://dev.mysql.com/doc/refman/8.4/en/show-table-status.html>
+fn parse_show_table_status(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let show_token = parser.expect_keyword(Keyword::SHOW)?;
+    parser.expect_keywords(&[Keyword::TABLE, Keyword::STATUS])?;
+    let (show_in, filter) = parse_show_db_filter(parser)?;
+    Ok(Statement::ShowTableStatus {
+        show_token: AttachedToken(show_token),
+        show_in,
+        filter,
+    })
+}
+
+/// `SHOW OPEN TABLES [{FROM | IN} db_name] [LIKE 'pattern' | WHERE expr]`
+/// <https://dev.mysql.com/doc/refman/8.4/en/show-open-tables.html>
+fn parse_show_open_tables(parser: &mut Parser) -> Result<Statement, ParserError> {
+    let show_token = parser.expect_keyword(Keyword::SHOW)?;
+    parser.expect_keywords(&[Keyword::OPEN, Keyword::TABLES])?;
+    let (show_in, filter) = parse_show_db_filter(parser)?;
+    Ok(Statement::ShowOpenTables {
+        show_token: AttachedToken(show_token),
+        show_in,
+        filter,
+    })
+}
+
+/// Parse the `[{FROM | IN} db_name] [LIKE 'pattern' | WHERE expr]` tail shared by
+/// `SHOW TABLE STATUS` and `SHOW OPEN TABLES`.
+fn parse_show_db_filter(
+    parser: &mut Parser,
+) -> Result<(Option<ShowStatementIn>, Option<ShowStatementFilter>), ParserError> {
+    let clause = match parser.parse_one_of_keywords(&[Keyword::FROM, Keyword::IN]) {
+        Some(Keyword::FROM) => Some(ShowStatementInClause::FROM),
+        Some(Keyword::IN) => Some(ShowStatementInClause::IN),
+        _ => None,
+    };
+    let show_in = match clause {
+        Some(clause) => Some(ShowStatementIn {
+            clause,
+            parent_type: None,
+            parent_name: Some(parser.parse_object_name(false)?),
+        }),
+        None => None,
+    };
+    let filter = parser.parse_show_statement_filter()?;
+    Ok((show_in, filter))
 }
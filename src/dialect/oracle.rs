@@ -22,7 +22,7 @@ use crate::{
     tokenizer::Token,
 };
 
-use super::{keywords::Keyword, Dialect, Precedence};
+use super::{keywords::Keyword, Dialect, IdentCaseRule, Precedence};
 
 const RESERVED_KEYWORDS_FOR_SELECT_ITEM_OPERATOR: [Keyword; 1] = [Keyword::CONNECT_BY_ROOT];
 
@@ -37,6 +37,11 @@ impl Dialect for OracleDialect {
         Some('"')
     }
 
+    /// See <https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/Database-Object-Names-and-Qualifiers.html>
+    fn normalizes_idents_to(&self) -> IdentCaseRule {
+        IdentCaseRule::Uppercase
+    }
+
     fn is_delimited_identifier_start(&self, ch: char) -> bool {
         ch == '"'
     }
@@ -28,7 +28,7 @@
 // limitations under the License.
 use log::debug;
 
-use crate::dialect::{Dialect, Precedence};
+use crate::dialect::{Dialect, IdentCaseRule, Precedence};
 use crate::keywords::Keyword;
 use crate::parser::{Parser, ParserError};
 use crate::tokenizer::Token;
@@ -63,6 +63,11 @@ impl Dialect for PostgreSqlDialect {
         Some('"')
     }
 
+    /// See <https://www.postgresql.org/docs/current/sql-syntax-lexical.html#SQL-SYNTAX-IDENTIFIERS>
+    fn normalizes_idents_to(&self) -> IdentCaseRule {
+        IdentCaseRule::Lowercase
+    }
+
     fn is_delimited_identifier_start(&self, ch: char) -> bool {
         ch == '"' // Postgres does not support backticks to quote identifiers
     }
@@ -171,6 +176,7 @@ impl Dialect for PostgreSqlDialect {
             Precedence::Xor => XOR_PREC,
             Precedence::Ampersand => PG_OTHER_PREC,
             Precedence::Caret => CARET_PREC,
+            Precedence::Shift => PG_OTHER_PREC,
             Precedence::Pipe => PG_OTHER_PREC,
             Precedence::Colon => PG_OTHER_PREC,
             Precedence::Between => BETWEEN_LIKE_PREC,
@@ -179,6 +185,8 @@ impl Dialect for PostgreSqlDialect {
             Precedence::Is => IS_PREC,
             Precedence::PgOther => PG_OTHER_PREC,
             Precedence::UnaryNot => NOT_PREC,
+            Precedence::UnaryPlusMinus => MUL_DIV_MOD_OP_PREC,
+            Precedence::UnaryBitwiseNot => PLUS_MINUS_PREC,
             Precedence::And => AND_PREC,
             Precedence::Or => OR_PREC,
         }
@@ -330,4 +338,23 @@ impl Dialect for PostgreSqlDialect {
     fn supports_comment_optimizer_hint(&self) -> bool {
         true
     }
+
+    fn supports_merge_not_matched_by_source_or_target(&self) -> bool {
+        true
+    }
+
+    /// See <https://www.postgresql.org/docs/current/queries-with.html#QUERIES-WITH-CTE-MATERIALIZATION>
+    fn supports_cte_materialization_hint(&self) -> bool {
+        true
+    }
+
+    /// See <https://www.postgresql.org/docs/current/sql-reindex.html>
+    fn supports_reindex(&self) -> bool {
+        true
+    }
+
+    /// See <https://www.postgresql.org/docs/current/sql-createstatistics.html>
+    fn supports_create_statistics(&self) -> bool {
+        true
+    }
 }
@@ -36,7 +36,7 @@ use crate::ast::{
     RefreshModeKind, RowAccessPolicy, ShowObjects, SqlOption, Statement, StorageLifecyclePolicy,
     StorageSerializationPolicy, TableObject, TagsColumnOption, Value, WrappedCollection,
 };
-use crate::dialect::{Dialect, Precedence};
+use crate::dialect::{Dialect, IdentCaseRule, Precedence};
 use crate::keywords::Keyword;
 use crate::parser::{IsOptional, Parser, ParserError};
 use crate::tokenizer::TokenWithSpan;
@@ -144,6 +144,11 @@ impl Dialect for SnowflakeDialect {
         true
     }
 
+    // see https://docs.snowflake.com/en/sql-reference/identifiers-syntax#label-identifier-casing
+    fn normalizes_idents_to(&self) -> IdentCaseRule {
+        IdentCaseRule::Uppercase
+    }
+
     fn supports_from_trailing_commas(&self) -> bool {
         true
     }
@@ -316,6 +321,8 @@ impl Dialect for SnowflakeDialect {
             if parser.parse_keyword(Keyword::STAGE) {
                 // OK - this is CREATE STAGE statement
                 return Some(parse_create_stage(or_replace, temporary, parser));
+            } else if parser.parse_keywords(&[Keyword::FILE, Keyword::FORMAT]) {
+                return Some(parse_create_file_format(or_replace, temporary, parser));
             } else if parser.parse_keyword(Keyword::TABLE) {
                 return Some(
                     parse_create_table(
@@ -621,6 +628,21 @@ impl Dialect for SnowflakeDialect {
         true
     }
 
+    /// See <https://docs.snowflake.com/en/sql-reference/constructs/group-by>
+    fn supports_group_by_all(&self) -> bool {
+        true
+    }
+
+    /// See <https://docs.snowflake.com/en/sql-reference/constructs/qualify>
+    fn supports_qualify_clause(&self) -> bool {
+        true
+    }
+
+    /// See <https://docs.snowflake.com/en/sql-reference/constructs/order-by>
+    fn supports_order_by_all(&self) -> bool {
+        true
+    }
+
     /// See: <https://docs.snowflake.com/en/sql-reference/constructs/connect-by>
     fn get_reserved_keywords_for_select_item_operator(&self) -> &[Keyword] {
         &RESERVED_KEYWORDS_FOR_SELECT_ITEM_OPERATOR
@@ -1253,6 +1275,35 @@ pub fn parse_create_stage(
     })
 }
 
+/// Parses a `CREATE FILE FORMAT` statement, assuming the `CREATE [OR REPLACE] [TEMPORARY]
+/// FILE FORMAT` keywords have already been consumed.
+///
+/// See <https://docs.snowflake.com/en/sql-reference/sql/create-file-format>
+pub fn parse_create_file_format(
+    or_replace: bool,
+    temporary: bool,
+    parser: &mut Parser,
+) -> Result<Statement, ParserError> {
+    let if_not_exists = parser.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    let name = parser.parse_object_name(false)?;
+    let mut options = Vec::new();
+    while let Token::Word(key) = parser.peek_token().token {
+        parser.advance_token();
+        options.push(parser.parse_key_value_option(&key)?);
+    }
+
+    Ok(Statement::CreateFileFormat {
+        or_replace,
+        temporary,
+        if_not_exists,
+        name,
+        options: KeyValueOptions {
+            options,
+            delimiter: KeyValueOptionsDelimiter::Space,
+        },
+    })
+}
+
 pub fn parse_stage_name_identifier(parser: &mut Parser) -> Result<Ident, ParserError> {
     let mut ident = String::new();
     while let Some(next_token) = parser.next_token_no_skip() {
@@ -20,7 +20,7 @@ use alloc::boxed::Box;
 
 use crate::ast::BinaryOperator;
 use crate::ast::{Expr, Statement};
-use crate::dialect::Dialect;
+use crate::dialect::{Dialect, IdentCaseRule};
 use crate::keywords::Keyword;
 use crate::parser::{Parser, ParserError};
 
@@ -46,6 +46,10 @@ impl Dialect for SQLiteDialect {
         Some('`')
     }
 
+    fn normalizes_idents_to(&self) -> IdentCaseRule {
+        IdentCaseRule::CaseInsensitive
+    }
+
     fn is_identifier_start(&self, ch: char) -> bool {
         // See https://www.sqlite.org/draft/tokenreq.html
         ch.is_ascii_lowercase()
@@ -124,4 +128,14 @@ impl Dialect for SQLiteDialect {
     fn supports_comma_separated_trim(&self) -> bool {
         true
     }
+
+    /// See <https://sqlite.org/lang_detach.html>
+    fn supports_detach(&self) -> bool {
+        true
+    }
+
+    /// See <https://sqlite.org/lang_with.html#materialization_hints>
+    fn supports_cte_materialization_hint(&self) -> bool {
+        true
+    }
 }
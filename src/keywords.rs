@@ -204,6 +204,7 @@ define_keywords!(
     CENTURY,
     CHAIN,
     CHANGE,
+    CHANGED,
     CHANGES,
     CHANGE_TRACKING,
     CHANNEL,
@@ -299,6 +300,10 @@ define_keywords!(
     DAYOFWEEK,
     DAYOFYEAR,
     DAYS,
+    DAY_HOUR,
+    DAY_MICROSECOND,
+    DAY_MINUTE,
+    DAY_SECOND,
     DCPROPERTIES,
     DEALLOCATE,
     DEC,
@@ -326,6 +331,7 @@ define_keywords!(
     DELTA,
     DENSE_RANK,
     DENY,
+    DEPENDENCIES,
     DEPENDS,
     DEREF,
     DESC,
@@ -417,10 +423,12 @@ define_keywords!(
     FALLBACK,
     FALSE,
     FAMILY,
+    FAST,
     FETCH,
     FIELDS,
     FILE,
     FILES,
+    FILE_BLOCK_SIZE,
     FILE_FORMAT,
     FILL,
     FILTER,
@@ -477,6 +485,7 @@ define_keywords!(
     GROUPING,
     GROUPS,
     GZIP,
+    HANDLER,
     HASH,
     HASHES,
     HAVING,
@@ -489,6 +498,9 @@ define_keywords!(
     HOSTS,
     HOUR,
     HOURS,
+    HOUR_MICROSECOND,
+    HOUR_MINUTE,
+    HOUR_SECOND,
     HUGEINT,
     IAM_ROLE,
     ICEBERG,
@@ -578,12 +590,14 @@ define_keywords!(
     LEAST,
     LEFT,
     LEFTARG,
+    LESS,
     LEVEL,
     LIFECYCLE,
     LIKE,
     LIKE_REGEX,
     LIMIT,
     LINE,
+    LINEAR,
     LINES,
     LIST,
     LISTEN,
@@ -628,7 +642,9 @@ define_keywords!(
     MAX_DATA_EXTENSION_TIME_IN_DAYS,
     MAX_ROWS,
     MB,
+    MCV,
     MEASURES,
+    MEDIUM,
     MEDIUMBLOB,
     MEDIUMINT,
     MEDIUMTEXT,
@@ -651,6 +667,8 @@ define_keywords!(
     MINUS,
     MINUTE,
     MINUTES,
+    MINUTE_MICROSECOND,
+    MINUTE_SECOND,
     MINVALUE,
     MIN_ROWS,
     MOD,
@@ -674,6 +692,7 @@ define_keywords!(
     NATURAL,
     NCHAR,
     NCLOB,
+    NDISTINCT,
     NEGATOR,
     NEST,
     NESTED,
@@ -808,6 +827,7 @@ define_keywords!(
     PREPARE,
     PRESERVE,
     PRESET,
+    PREV,
     PREWHERE,
     PRIMARY,
     PRINT,
@@ -825,6 +845,7 @@ define_keywords!(
     QUARTER,
     QUERIES,
     QUERY,
+    QUICK,
     QUOTE,
     RAISE,
     RAISERROR,
@@ -922,6 +943,7 @@ define_keywords!(
     SECONDARY,
     SECONDARY_ENGINE_ATTRIBUTE,
     SECONDS,
+    SECOND_MICROSECOND,
     SECRET,
     SECURE,
     SECURITY,
@@ -1042,6 +1064,7 @@ define_keywords!(
     TERSE,
     TEXT,
     TEXTFILE,
+    THAN,
     THEN,
     THROW,
     TIES,
@@ -1117,12 +1140,14 @@ define_keywords!(
     UNSIGNED,
     UNTIL,
     UPDATE,
+    UPGRADE,
     UPPER,
     URL,
     USAGE,
     USE,
     USER,
     USER_RESOURCES,
+    USE_FRM,
     USING,
     USMALLINT,
     UTINYINT,
@@ -1178,7 +1203,9 @@ define_keywords!(
     XOR,
     YEAR,
     YEARS,
+    YEAR_MONTH,
     YES,
+    ZEROFILL,
     ZONE,
     ZORDER,
     ZSTD
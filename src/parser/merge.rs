@@ -86,13 +86,15 @@ impl Parser<'_> {
             self.expect_keyword_is(Keyword::MATCHED)?;
 
             if matches!(clause_kind, MergeClauseKind::NotMatched)
-                && self.parse_keywords(&[Keyword::BY, Keyword::SOURCE])
+                && self
+                    .dialect
+                    .supports_merge_not_matched_by_source_or_target()
             {
-                clause_kind = MergeClauseKind::NotMatchedBySource;
-            } else if matches!(clause_kind, MergeClauseKind::NotMatched)
-                && self.parse_keywords(&[Keyword::BY, Keyword::TARGET])
-            {
-                clause_kind = MergeClauseKind::NotMatchedByTarget;
+                if self.parse_keywords(&[Keyword::BY, Keyword::SOURCE]) {
+                    clause_kind = MergeClauseKind::NotMatchedBySource;
+                } else if self.parse_keywords(&[Keyword::BY, Keyword::TARGET]) {
+                    clause_kind = MergeClauseKind::NotMatchedByTarget;
+                }
             }
 
             let predicate = if self.parse_keyword(Keyword::AND) {
@@ -50,18 +50,50 @@ use sqlparser::parser::ParserState::ColumnDefinition;
 /// Errors produced by the SQL parser.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParserError {
-    /// Error originating from the tokenizer with a message.
-    TokenizerError(String),
+    /// Error originating from the tokenizer, with the span of the
+    /// offending input.
+    TokenizerError(TokenizerError),
     /// Generic parser error with a message.
     ParserError(String),
+    /// Parser error raised at a known position of the SQL text.
+    ///
+    /// Displayed like [`ParserError::ParserError`], with the location
+    /// appended to the message, e.g. `... at Line: 1, Column: 16`.
+    ParserErrorWithLocation {
+        /// The error message, without the location.
+        message: String,
+        /// Position of the offending token.
+        location: Location,
+    },
     /// Raised when a recursion depth limit is exceeded.
     RecursionLimitExceeded,
 }
 
+impl ParserError {
+    /// Create a parser error at `location`, falling back to
+    /// [`ParserError::ParserError`] if the location is unknown.
+    fn with_location(message: String, location: Location) -> Self {
+        if location == Location::empty() {
+            ParserError::ParserError(message)
+        } else {
+            ParserError::ParserErrorWithLocation { message, location }
+        }
+    }
+
+    /// Returns the position of the error in the SQL text, if known.
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            ParserError::ParserErrorWithLocation { location, .. } => Some(*location),
+            ParserError::TokenizerError(e) => Some(e.location),
+            _ => None,
+        }
+    }
+}
+
 // Use `Parser::expected` instead, if possible
 macro_rules! parser_err {
     ($MSG:expr, $loc:expr) => {
-        Err(ParserError::ParserError(format!("{}{}", $MSG, $loc)))
+        Err(ParserError::with_location($MSG.to_string(), $loc))
     };
 }
 
@@ -185,21 +217,24 @@ pub enum WildcardExpr {
 
 impl From<TokenizerError> for ParserError {
     fn from(e: TokenizerError) -> Self {
-        ParserError::TokenizerError(e.to_string())
+        ParserError::TokenizerError(e)
     }
 }
 
 impl fmt::Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "sql parser error: {}",
-            match self {
-                ParserError::TokenizerError(s) => s,
-                ParserError::ParserError(s) => s,
-                ParserError::RecursionLimitExceeded => "recursion limit exceeded",
+        match self {
+            ParserError::ParserError(s) => {
+                write!(f, "sql parser error: {s}")
             }
-        )
+            ParserError::ParserErrorWithLocation { message, location } => {
+                write!(f, "sql parser error: {message}{location}")
+            }
+            ParserError::TokenizerError(e) => write!(f, "sql parser error: {e}"),
+            ParserError::RecursionLimitExceeded => {
+                write!(f, "sql parser error: recursion limit exceeded")
+            }
+        }
     }
 }
 
@@ -248,6 +283,13 @@ pub struct ParserOptions {
     /// Controls if the parser expects a semi-colon token
     /// between statements. Default is `true`.
     pub require_semicolon_stmt_delimiter: bool,
+    /// Keywords that are not reserved for this parser, regardless of the
+    /// dialect, so they can be used as aliases and identifiers.
+    /// Default is empty.
+    pub unreserved_keywords: Vec<Keyword>,
+    /// Maximum length in bytes of the SQL accepted by [`Parser::try_with_sql`].
+    /// Default is `None` (no limit).
+    pub max_input_length: Option<usize>,
 }
 
 impl Default for ParserOptions {
@@ -256,6 +298,8 @@ impl Default for ParserOptions {
             trailing_commas: false,
             unescape: true,
             require_semicolon_stmt_delimiter: true,
+            unreserved_keywords: vec![],
+            max_input_length: None,
         }
     }
 }
@@ -288,6 +332,30 @@ impl ParserOptions {
         self.unescape = unescape;
         self
     }
+
+    /// Set keywords that should not be treated as reserved.
+    ///
+    /// This overrides the reserved keyword lists of the dialect, e.g.
+    /// `RESERVED_FOR_TABLE_ALIAS`, so that the following SQL parses `use`
+    /// as a table alias when `USE` is given:
+    ///
+    /// ```sql
+    /// SELECT * FROM t use
+    /// ```
+    pub fn with_unreserved_keywords(mut self, keywords: Vec<Keyword>) -> Self {
+        self.unreserved_keywords = keywords;
+        self
+    }
+
+    /// Set the maximum length in bytes of the SQL to parse.
+    ///
+    /// Longer input is rejected with a [`ParserError`] before it is
+    /// tokenized. This complements [`Parser::with_recursion_limit`] when
+    /// parsing untrusted SQL.
+    pub fn with_max_input_length(mut self, max_input_length: usize) -> Self {
+        self.max_input_length = Some(max_input_length);
+        self
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -465,10 +533,19 @@ impl<'a> Parser<'a> {
     /// Tokenize the sql string and sets this [`Parser`]'s state to
     /// parse the resulting tokens
     ///
-    /// Returns an error if there was an error tokenizing the SQL string.
+    /// Returns an error if there was an error tokenizing the SQL string, or
+    /// if it is longer than [`ParserOptions::max_input_length`].
     ///
     /// See example on [`Parser::new()`] for an example
     pub fn try_with_sql(self, sql: &str) -> Result<Self, ParserError> {
+        if let Some(max_input_length) = self.options.max_input_length {
+            if sql.len() > max_input_length {
+                return Err(ParserError::ParserError(format!(
+                    "SQL input of {} bytes exceeds the maximum length of {max_input_length} bytes",
+                    sql.len()
+                )));
+            }
+        }
         debug!("Parsing sql '{sql}'...");
         let tokens = Tokenizer::new(self.dialect, sql)
             .with_unescape(self.options.unescape)
@@ -632,7 +709,11 @@ impl<'a> Parser<'a> {
                     }
                 }
                 Keyword::DETACH if self.dialect.supports_detach() => {
-                    self.parse_detach_duckdb_database()
+                    if dialect_of!(self is SQLiteDialect) {
+                        self.parse_detach_database()
+                    } else {
+                        self.parse_detach_duckdb_database()
+                    }
                 }
                 Keyword::MSCK => self.parse_msck().map(Into::into),
                 Keyword::CREATE => self.parse_create(),
@@ -682,6 +763,9 @@ impl<'a> Parser<'a> {
                 Keyword::EXECUTE | Keyword::EXEC => self.parse_execute(),
                 Keyword::PREPARE => self.parse_prepare(),
                 Keyword::MERGE => self.parse_merge(next_token).map(Into::into),
+                Keyword::PIVOT if self.dialect.supports_pivot_statement() => {
+                    self.parse_pivot_statement(next_token).map(Statement::Pivot)
+                }
                 // `LISTEN`, `UNLISTEN` and `NOTIFY` are Postgres-specific
                 // syntaxes. They are used for Postgres statement.
                 Keyword::LISTEN if self.dialect.supports_listen_notify() => self.parse_listen(),
@@ -719,6 +803,9 @@ impl<'a> Parser<'a> {
                     self.parse_vacuum()
                 }
                 Keyword::RESET => self.parse_reset().map(Into::into),
+                Keyword::REINDEX if self.dialect.supports_reindex() => {
+                    self.parse_reindex().map(Into::into)
+                }
                 _ => self.expected("an SQL statement", next_token),
             },
             Token::LParen => {
@@ -1234,6 +1321,16 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse `DETACH DATABASE` statement.
+    pub fn parse_detach_database(&mut self) -> Result<Statement, ParserError> {
+        let database = self.parse_keyword(Keyword::DATABASE);
+        let schema_name = self.parse_identifier()?;
+        Ok(Statement::DetachDatabase {
+            database,
+            schema_name,
+        })
+    }
+
     /// Parse `ANALYZE` statement.
     pub fn parse_analyze(&mut self) -> Result<Analyze, ParserError> {
         let has_table_keyword = self.parse_keyword(Keyword::TABLE);
@@ -1814,7 +1911,9 @@ impl<'a> Parser<'a> {
                     // we rollback and return the parsing error we got from trying to parse a
                     // special expression (to maintain backwards compatibility of parsing errors).
                     Err(e) => {
-                        if !self.dialect.is_reserved_for_identifier(w.keyword) {
+                        if !self.dialect.is_reserved_for_identifier(w.keyword)
+                            || self.is_unreserved_keyword(&w.keyword)
+                        {
                             if let Ok(Some(expr)) = self.maybe_parse(|parser| {
                                 parser.parse_expr_prefix_by_unreserved_word(&w, span)
                             }) {
@@ -1836,7 +1935,7 @@ impl<'a> Parser<'a> {
                 Ok(Expr::UnaryOp {
                     op,
                     expr: Box::new(
-                        self.parse_subexpr(self.dialect.prec_value(Precedence::MulDivModOp))?,
+                        self.parse_subexpr(self.dialect.prec_value(Precedence::UnaryPlusMinus))?,
                     ),
                 })
             }
@@ -1870,7 +1969,9 @@ impl<'a> Parser<'a> {
             }
             Token::Tilde => Ok(Expr::UnaryOp {
                 op: UnaryOperator::BitwiseNot,
-                expr: Box::new(self.parse_subexpr(self.dialect.prec_value(Precedence::PlusMinus))?),
+                expr: Box::new(
+                    self.parse_subexpr(self.dialect.prec_value(Precedence::UnaryBitwiseNot))?,
+                ),
             }),
             tok @ Token::Sharp
             | tok @ Token::AtDashAt
@@ -2984,6 +3085,17 @@ impl<'a> Parser<'a> {
                 trim_where = Some(self.parse_trim_where()?);
             }
         }
+        if self.parse_keyword(Keyword::FROM) {
+            let expr = self.parse_expr()?;
+            self.expect_token(&Token::RParen)?;
+            return Ok(Expr::Trim {
+                expr: Box::new(expr),
+                trim_where,
+                trim_what: None,
+                trim_characters: None,
+                bare_from: true,
+            });
+        }
         let expr = self.parse_expr()?;
         if self.parse_keyword(Keyword::FROM) {
             let trim_what = Box::new(expr);
@@ -2994,6 +3106,7 @@ impl<'a> Parser<'a> {
                 trim_where,
                 trim_what: Some(trim_what),
                 trim_characters: None,
+                bare_from: false,
             })
         } else if self.dialect.supports_comma_separated_trim() && self.consume_token(&Token::Comma)
         {
@@ -3004,6 +3117,7 @@ impl<'a> Parser<'a> {
                 trim_where: None,
                 trim_what: None,
                 trim_characters: Some(characters),
+                bare_from: false,
             })
         } else {
             self.expect_token(&Token::RParen)?;
@@ -3012,6 +3126,7 @@ impl<'a> Parser<'a> {
                 trim_where,
                 trim_what: None,
                 trim_characters: None,
+                bare_from: false,
             })
         }
     }
@@ -3142,6 +3257,17 @@ impl<'a> Parser<'a> {
                 Keyword::TIMEZONE_HOUR => Ok(DateTimeField::TimezoneHour),
                 Keyword::TIMEZONE_MINUTE => Ok(DateTimeField::TimezoneMinute),
                 Keyword::TIMEZONE_REGION => Ok(DateTimeField::TimezoneRegion),
+                Keyword::SECOND_MICROSECOND => Ok(DateTimeField::SecondMicrosecond),
+                Keyword::MINUTE_MICROSECOND => Ok(DateTimeField::MinuteMicrosecond),
+                Keyword::MINUTE_SECOND => Ok(DateTimeField::MinuteSecond),
+                Keyword::HOUR_MICROSECOND => Ok(DateTimeField::HourMicrosecond),
+                Keyword::HOUR_SECOND => Ok(DateTimeField::HourSecond),
+                Keyword::HOUR_MINUTE => Ok(DateTimeField::HourMinute),
+                Keyword::DAY_MICROSECOND => Ok(DateTimeField::DayMicrosecond),
+                Keyword::DAY_SECOND => Ok(DateTimeField::DaySecond),
+                Keyword::DAY_MINUTE => Ok(DateTimeField::DayMinute),
+                Keyword::DAY_HOUR => Ok(DateTimeField::DayHour),
+                Keyword::YEAR_MONTH => Ok(DateTimeField::YearMonth),
                 _ if self.dialect.allow_extract_custom() => {
                     self.prev_token();
                     let custom = self.parse_identifier()?;
@@ -3380,6 +3506,17 @@ impl<'a> Parser<'a> {
                     | Keyword::TIMEZONE
                     | Keyword::TIMEZONE_HOUR
                     | Keyword::TIMEZONE_MINUTE
+                    | Keyword::SECOND_MICROSECOND
+                    | Keyword::MINUTE_MICROSECOND
+                    | Keyword::MINUTE_SECOND
+                    | Keyword::HOUR_MICROSECOND
+                    | Keyword::HOUR_SECOND
+                    | Keyword::HOUR_MINUTE
+                    | Keyword::DAY_MICROSECOND
+                    | Keyword::DAY_SECOND
+                    | Keyword::DAY_MINUTE
+                    | Keyword::DAY_HOUR
+                    | Keyword::YEAR_MONTH
             )
         } else {
             false
@@ -3990,7 +4127,7 @@ impl<'a> Parser<'a> {
                         Ok(is_normalized)
                     } else {
                         self.expected_ref(
-                            "[NOT] NULL | TRUE | FALSE | DISTINCT | [form] NORMALIZED FROM after IS",
+                            "[NOT] NULL | TRUE | FALSE | UNKNOWN | DISTINCT | [form] NORMALIZED FROM after IS",
                             self.peek_token_ref(),
                         )
                     }
@@ -4492,7 +4629,7 @@ impl<'a> Parser<'a> {
     /// Return true if the next tokens exactly `expected`
     ///
     /// Does not advance the current token.
-    fn peek_keywords(&mut self, expected: &[Keyword]) -> bool {
+    pub(crate) fn peek_keywords(&mut self, expected: &[Keyword]) -> bool {
         let index = self.index;
         let matched = self.parse_keywords(expected);
         self.index = index;
@@ -4596,6 +4733,17 @@ impl<'a> Parser<'a> {
         )
     }
 
+    /// Report `found` was encountered instead of any of the candidate `keywords`,
+    /// e.g. `Expected: one of READ, WRITE, LOW_PRIORITY, found: foo`.
+    pub fn expected_one_of_keywords<T>(
+        &self,
+        keywords: &[Keyword],
+        found: &TokenWithSpan,
+    ) -> Result<T, ParserError> {
+        let keywords: Vec<String> = keywords.iter().map(|x| format!("{x:?}")).collect();
+        self.expected_ref(&format!("one of {}", keywords.join(", ")), found)
+    }
+
     /// Report that the token at `index` was found instead of `expected`.
     pub fn expected_at<T>(&self, expected: &str, index: usize) -> Result<T, ParserError> {
         let found = self.tokens.get(index).unwrap_or(&EOF_TOKEN);
@@ -5197,6 +5345,10 @@ impl<'a> Parser<'a> {
             self.parse_create_sequence(temporary)
         } else if self.parse_keyword(Keyword::COLLATION) {
             self.parse_create_collation().map(Into::into)
+        } else if self.dialect.supports_create_statistics()
+            && self.parse_keyword(Keyword::STATISTICS)
+        {
+            self.parse_create_statistics().map(Into::into)
         } else if self.parse_keyword(Keyword::TYPE) {
             self.parse_create_type()
         } else if self.parse_keyword(Keyword::PROCEDURE) {
@@ -6930,7 +7082,7 @@ impl<'a> Parser<'a> {
     /// Parses a [Statement::CreateDomain] statement.
     fn parse_create_domain(&mut self) -> Result<CreateDomain, ParserError> {
         let name = self.parse_object_name(false)?;
-        self.expect_keyword_is(Keyword::AS)?;
+        let as_keyword = self.parse_keyword(Keyword::AS);
         let data_type = self.parse_data_type()?;
         let collation = if self.parse_keyword(Keyword::COLLATE) {
             Some(self.parse_identifier()?)
@@ -6938,17 +7090,20 @@ impl<'a> Parser<'a> {
             None
         };
         let default = if self.parse_keyword(Keyword::DEFAULT) {
-            Some(self.parse_expr()?)
+            // parse in column definition state so a trailing `NOT NULL`
+            // constraint is not consumed as part of the default expression
+            Some(self.with_state(ColumnDefinition, |parser| parser.parse_expr())?)
         } else {
             None
         };
         let mut constraints = Vec::new();
-        while let Some(constraint) = self.parse_optional_table_constraint()? {
+        while let Some(constraint) = self.parse_optional_domain_constraint()? {
             constraints.push(constraint);
         }
 
         Ok(CreateDomain {
             name,
+            as_keyword,
             data_type,
             collation,
             default,
@@ -6956,6 +7111,35 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a single `[ CONSTRAINT name ] { NOT NULL | NULL | CHECK (expr) }`
+    /// constraint of a `CREATE DOMAIN` statement, if present.
+    fn parse_optional_domain_constraint(
+        &mut self,
+    ) -> Result<Option<DomainConstraint>, ParserError> {
+        let index = self.index;
+        let name = if self.parse_keyword(Keyword::CONSTRAINT) {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
+        if self.parse_keywords(&[Keyword::NOT, Keyword::NULL]) {
+            return Ok(Some(DomainConstraint::NotNull { name }));
+        }
+        if self.parse_keyword(Keyword::NULL) {
+            return Ok(Some(DomainConstraint::Null { name }));
+        }
+
+        self.index = index;
+        let loc = self.peek_token_ref().span.start;
+        match self.parse_optional_table_constraint()? {
+            Some(TableConstraint::Check(check)) => Ok(Some(DomainConstraint::Check(check))),
+            Some(constraint) => {
+                parser_err!(format!("Unsupported domain constraint: {constraint}"), loc)
+            }
+            None => Ok(None),
+        }
+    }
+
     /// ```sql
     ///     CREATE POLICY name ON table_name [ AS { PERMISSIVE | RESTRICTIVE } ]
     ///     [ FOR { ALL | SELECT | INSERT | UPDATE | DELETE } ]
@@ -7663,6 +7847,7 @@ impl<'a> Parser<'a> {
                 hold,
                 for_query: query,
             }],
+            separator: DeclareSeparator::Semicolon,
         })
     }
 
@@ -7706,6 +7891,7 @@ impl<'a> Parser<'a> {
                 hold: None,
                 for_query: None,
             }],
+            separator: DeclareSeparator::Semicolon,
         })
     }
 
@@ -7822,7 +8008,10 @@ impl<'a> Parser<'a> {
             break;
         }
 
-        Ok(Statement::Declare { stmts })
+        Ok(Statement::Declare {
+            stmts,
+            separator: DeclareSeparator::Semicolon,
+        })
     }
 
     /// Parse a [MsSql] `DECLARE` statement.
@@ -7839,7 +8028,10 @@ impl<'a> Parser<'a> {
     pub fn parse_mssql_declare(&mut self) -> Result<Statement, ParserError> {
         let stmts = self.parse_comma_separated(Parser::parse_mssql_declare_stmt)?;
 
-        Ok(Statement::Declare { stmts })
+        Ok(Statement::Declare {
+            stmts,
+            separator: DeclareSeparator::Comma,
+        })
     }
 
     /// Parse the body of a [MsSql] `DECLARE`statement.
@@ -7861,7 +8053,7 @@ impl<'a> Parser<'a> {
                     Token::Word(w) if w.keyword == Keyword::CURSOR
                 )
             {
-                Err(ParserError::TokenizerError(
+                Err(ParserError::ParserError(
                     "Invalid MsSql variable declaration.".to_string(),
                 ))
             } else {
@@ -8191,6 +8383,52 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a PostgreSQL-specific [Statement::CreateStatistics] statement.
+    pub fn parse_create_statistics(&mut self) -> Result<CreateStatistics, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = if self.peek_keyword(Keyword::ON) || self.peek_token_ref().token == Token::LParen
+        {
+            None
+        } else {
+            Some(self.parse_object_name(false)?)
+        };
+
+        let kinds = if self.consume_token(&Token::LParen) {
+            let kinds = self.parse_comma_separated(|p| {
+                match p.expect_one_of_keywords(&[
+                    Keyword::NDISTINCT,
+                    Keyword::DEPENDENCIES,
+                    Keyword::MCV,
+                ])? {
+                    Keyword::NDISTINCT => Ok(StatisticsKind::NDistinct),
+                    Keyword::DEPENDENCIES => Ok(StatisticsKind::Dependencies),
+                    Keyword::MCV => Ok(StatisticsKind::Mcv),
+                    // unreachable because expect_one_of_keywords used above
+                    unexpected_keyword => Err(ParserError::ParserError(format!(
+                        "Internal parser error: expected any of {{NDISTINCT, DEPENDENCIES, MCV}}, got {unexpected_keyword:?}"
+                    ))),
+                }
+            })?;
+            self.expect_token(&Token::RParen)?;
+            kinds
+        } else {
+            vec![]
+        };
+
+        self.expect_keyword_is(Keyword::ON)?;
+        let on = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_keyword_is(Keyword::FROM)?;
+        let from = self.parse_object_name(false)?;
+
+        Ok(CreateStatistics {
+            if_not_exists,
+            name,
+            kinds,
+            on,
+            from,
+        })
+    }
+
     /// Parse a PostgreSQL-specific [Statement::DropExtension] statement.
     pub fn parse_drop_extension(&mut self) -> Result<Statement, ParserError> {
         let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
@@ -8643,6 +8881,19 @@ impl<'a> Parser<'a> {
             None
         };
 
+        let duplicate_handling = if self
+            .dialect
+            .supports_create_table_select_duplicate_handling()
+        {
+            match self.parse_one_of_keywords(&[Keyword::IGNORE, Keyword::REPLACE]) {
+                Some(Keyword::IGNORE) => Some(CreateTableDuplicateHandling::Ignore),
+                Some(Keyword::REPLACE) => Some(CreateTableDuplicateHandling::Replace),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
         // Parse optional `AS ( query )`
         let query = if self.parse_keyword(Keyword::AS) {
             Some(self.parse_query()?)
@@ -8655,6 +8906,22 @@ impl<'a> Parser<'a> {
             None
         };
 
+        if duplicate_handling.is_some() && query.is_none() {
+            return self.expected_ref("AS or SELECT", self.peek_token_ref());
+        }
+
+        // `START TRANSACTION` only applies to an atomic `CREATE TABLE ... SELECT`
+        if query.is_none() {
+            if let CreateTableOptions::Plain(options) = &create_table_config.table_options {
+                if options.contains(&SqlOption::Ident(Ident::new("START TRANSACTION"))) {
+                    return parser_err!(
+                        "START TRANSACTION is only allowed in CREATE TABLE ... SELECT",
+                        self.peek_token_ref().span.start
+                    );
+                }
+            }
+        }
+
         // `WITH DATA` clause only applies if there is a query body.
         let with_data = if query.is_some() {
             self.maybe_parse_with_data()?
@@ -8685,6 +8952,8 @@ impl<'a> Parser<'a> {
             .on_cluster(on_cluster)
             .clustered_by(clustered_by)
             .partition_by(partition_by)
+            .partition_key(create_table_config.partition_key)
+            .partition_options(create_table_config.partition_options)
             .cluster_by(create_table_config.cluster_by)
             .inherits(create_table_config.inherits)
             .partition_of(partition_of)
@@ -8692,6 +8961,7 @@ impl<'a> Parser<'a> {
             .table_options(create_table_config.table_options)
             .primary_key(primary_key)
             .with_data(with_data)
+            .duplicate_handling(duplicate_handling)
             .strict(strict)
             .backup(backup)
             .diststyle(diststyle)
@@ -8789,7 +9059,7 @@ impl<'a> Parser<'a> {
         } else {
             parser_err!(
                 "Expecting DELETE ROWS, PRESERVE ROWS or DROP",
-                self.peek_token_ref()
+                self.peek_token_ref().span.start
             )
         }
     }
@@ -8883,10 +9153,16 @@ impl<'a> Parser<'a> {
         if !table_properties.is_empty() {
             table_options = CreateTableOptions::TableProperties(table_properties);
         }
+        let mut partition_key = None;
         let partition_by = if dialect_of!(self is BigQueryDialect | PostgreSqlDialect | GenericDialect)
             && self.parse_keywords(&[Keyword::PARTITION, Keyword::BY])
         {
-            Some(Box::new(self.parse_expr()?))
+            partition_key = self.maybe_parse_partition_key()?;
+            if partition_key.is_none() {
+                Some(Box::new(self.parse_expr()?))
+            } else {
+                None
+            }
         } else {
             None
         };
@@ -8914,14 +9190,151 @@ impl<'a> Parser<'a> {
             }
         };
 
+        let partition_options = if self.dialect.supports_create_table_partition_options()
+            && self.parse_keywords(&[Keyword::PARTITION, Keyword::BY])
+        {
+            Some(self.parse_partition_options()?)
+        } else {
+            None
+        };
+
         Ok(CreateTableConfiguration {
             partition_by,
+            partition_key,
+            partition_options,
             cluster_by,
             inherits,
             table_options,
         })
     }
 
+    /// Parse the strategy and column list of a PostgreSQL partition key, following
+    /// `PARTITION BY`. Returns `None` if the clause is not a `RANGE`, `LIST` or `HASH`
+    /// strategy followed by a parenthesized list.
+    fn maybe_parse_partition_key(&mut self) -> Result<Option<PartitionKey>, ParserError> {
+        let strategy = match self.peek_tokens() {
+            [Token::Word(w), Token::LParen] => match w.keyword {
+                Keyword::RANGE => PartitionStrategy::Range,
+                Keyword::LIST => PartitionStrategy::List,
+                Keyword::HASH => PartitionStrategy::Hash,
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        self.next_token();
+        self.expect_token(&Token::LParen)?;
+        let columns = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Some(PartitionKey { strategy, columns }))
+    }
+
+    /// Parse the MySQL partitioning options following `PARTITION BY` in a
+    /// `CREATE TABLE` statement.
+    ///
+    /// See <https://dev.mysql.com/doc/refman/8.4/en/create-table.html#create-table-partitioning>
+    fn parse_partition_options(&mut self) -> Result<PartitionOptions, ParserError> {
+        let linear = self.parse_keyword(Keyword::LINEAR);
+        let keyword = if linear {
+            self.expect_one_of_keywords(&[Keyword::HASH, Keyword::KEY])?
+        } else {
+            self.expect_one_of_keywords(&[
+                Keyword::HASH,
+                Keyword::KEY,
+                Keyword::RANGE,
+                Keyword::LIST,
+            ])?
+        };
+        let function = match keyword {
+            Keyword::HASH => PartitionFunction::Hash {
+                linear,
+                expr: Box::new(self.parse_parenthesized_partition_expr()?),
+            },
+            Keyword::KEY => PartitionFunction::Key {
+                linear,
+                columns: self.parse_parenthesized_column_list(Mandatory, true)?,
+            },
+            Keyword::RANGE if self.parse_keyword(Keyword::COLUMNS) => {
+                PartitionFunction::RangeColumns(
+                    self.parse_parenthesized_column_list(Mandatory, false)?,
+                )
+            }
+            Keyword::RANGE => {
+                PartitionFunction::Range(Box::new(self.parse_parenthesized_partition_expr()?))
+            }
+            Keyword::LIST if self.parse_keyword(Keyword::COLUMNS) => {
+                PartitionFunction::ListColumns(
+                    self.parse_parenthesized_column_list(Mandatory, false)?,
+                )
+            }
+            _ => PartitionFunction::List(Box::new(self.parse_parenthesized_partition_expr()?)),
+        };
+
+        let partitions = if self.parse_keyword(Keyword::PARTITIONS) {
+            Some(self.parse_literal_uint()?)
+        } else {
+            None
+        };
+
+        let definitions = if self.consume_token(&Token::LParen) {
+            let definitions = self.parse_comma_separated(Parser::parse_partition_definition)?;
+            self.expect_token(&Token::RParen)?;
+            definitions
+        } else {
+            vec![]
+        };
+
+        Ok(PartitionOptions {
+            function,
+            partitions,
+            definitions,
+        })
+    }
+
+    fn parse_parenthesized_partition_expr(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let expr = self.parse_expr()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(expr)
+    }
+
+    /// Parse a single MySQL partition definition:
+    /// `PARTITION name [VALUES ...] [partition_option ...]`
+    fn parse_partition_definition(&mut self) -> Result<PartitionDefinition, ParserError> {
+        self.expect_keyword_is(Keyword::PARTITION)?;
+        let name = self.parse_identifier()?;
+
+        let values = if self.parse_keywords(&[Keyword::VALUES, Keyword::LESS, Keyword::THAN]) {
+            if self.parse_keyword(Keyword::MAXVALUE) {
+                Some(PartitionValues::LessThanMaxValue)
+            } else {
+                self.expect_token(&Token::LParen)?;
+                let values = self.parse_comma_separated(Parser::parse_expr)?;
+                self.expect_token(&Token::RParen)?;
+                Some(PartitionValues::LessThan(values))
+            }
+        } else if self.parse_keywords(&[Keyword::VALUES, Keyword::IN]) {
+            self.expect_token(&Token::LParen)?;
+            let values = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            Some(PartitionValues::In(values))
+        } else {
+            None
+        };
+
+        // Partition options share their syntax with the table options, but unlike
+        // those they cannot be separated by commas.
+        let mut options = vec![];
+        while let Some(option) = self.parse_plain_option()? {
+            options.push(option);
+        }
+
+        Ok(PartitionDefinition {
+            name,
+            values,
+            options,
+        })
+    }
+
     fn parse_plain_option(&mut self) -> Result<Option<SqlOption>, ParserError> {
         // Single parameter option
         // <https://dev.mysql.com/doc/refman/8.4/en/create-table.html>
@@ -8955,28 +9368,26 @@ impl<'a> Parser<'a> {
             let _ = self.consume_token(&Token::Eq);
             let value = self.next_token();
 
-            let engine = match value.token {
-                Token::Word(w) => {
-                    let parameters = if self.peek_token_ref().token == Token::LParen {
-                        self.parse_parenthesized_identifiers()?
-                    } else {
-                        vec![]
-                    };
-
-                    Ok(Some(SqlOption::NamedParenthesizedList(
-                        NamedParenthesizedList {
-                            key: Ident::new("ENGINE"),
-                            name: Some(Ident::new(w.value)),
-                            values: parameters,
-                        },
-                    )))
-                }
+            let name = match &value.token {
+                Token::Word(w) => w.to_ident(value.span),
+                Token::SingleQuotedString(s) => Ident::with_quote_and_span('\'', value.span, s),
                 _ => {
                     return self.expected("Token::Word", value)?;
                 }
             };
+            let parameters = if self.peek_token_ref().token == Token::LParen {
+                self.parse_parenthesized_identifiers()?
+            } else {
+                vec![]
+            };
 
-            return engine;
+            return Ok(Some(SqlOption::NamedParenthesizedList(
+                NamedParenthesizedList {
+                    key: Ident::new("ENGINE"),
+                    name: Some(name),
+                    values: parameters,
+                },
+            )));
         }
 
         // <https://dev.mysql.com/doc/refman/8.4/en/create-table.html>
@@ -8984,36 +9395,44 @@ impl<'a> Parser<'a> {
             let _ = self.consume_token(&Token::Eq);
             let value = self.next_token();
 
-            let tablespace = match value.token {
-                Token::Word(Word { value: name, .. }) | Token::SingleQuotedString(name) => {
-                    let storage = match self.parse_keyword(Keyword::STORAGE) {
-                        true => {
-                            let _ = self.consume_token(&Token::Eq);
-                            let storage_token = self.next_token();
-                            match &storage_token.token {
-                                Token::Word(w) => match w.value.to_uppercase().as_str() {
-                                    "DISK" => Some(StorageType::Disk),
-                                    "MEMORY" => Some(StorageType::Memory),
-                                    _ => self
-                                        .expected("Storage type (DISK or MEMORY)", storage_token)?,
-                                },
-                                _ => self.expected("Token::Word", storage_token)?,
-                            }
-                        }
-                        false => None,
-                    };
-
-                    Ok(Some(SqlOption::TableSpace(TablespaceOption {
-                        name,
-                        storage,
-                    })))
-                }
+            let name = match &value.token {
+                Token::Word(w) => w.to_ident(value.span),
+                Token::SingleQuotedString(s) => Ident::with_quote_and_span('\'', value.span, s),
                 _ => {
                     return self.expected("Token::Word", value)?;
                 }
             };
+            let storage = match self.parse_keyword(Keyword::STORAGE) {
+                true => {
+                    let _ = self.consume_token(&Token::Eq);
+                    let storage_token = self.next_token();
+                    match &storage_token.token {
+                        Token::Word(w) => match w.value.to_uppercase().as_str() {
+                            "DISK" => Some(StorageType::Disk),
+                            "MEMORY" => Some(StorageType::Memory),
+                            _ => self.expected("Storage type (DISK or MEMORY)", storage_token)?,
+                        },
+                        _ => self.expected("Token::Word", storage_token)?,
+                    }
+                }
+                false => None,
+            };
+            let mut file_block_size_eq = false;
+            let file_block_size = if self.parse_keyword(Keyword::FILE_BLOCK_SIZE) {
+                file_block_size_eq = self.consume_token(&Token::Eq);
+                Some(self.parse_expr()?)
+            } else {
+                None
+            };
 
-            return tablespace;
+            // A trailing `ENGINE` is the table's own option, so it is left to
+            // the next iteration of `parse_plain_options`.
+            return Ok(Some(SqlOption::TableSpace(TablespaceOption {
+                name,
+                storage,
+                file_block_size,
+                file_block_size_eq,
+            })));
         }
 
         // <https://dev.mysql.com/doc/refman/8.4/en/create-table.html>
@@ -9104,6 +9523,7 @@ impl<'a> Parser<'a> {
 
         let _ = self.consume_token(&Token::Eq);
 
+        let value_loc = self.peek_token_ref().span.start;
         let value = match self
             .maybe_parse(|parser| parser.parse_value())?
             .map(Expr::Value)
@@ -9112,6 +9532,34 @@ impl<'a> Parser<'a> {
             None => Expr::Identifier(self.parse_identifier()?),
         };
 
+        let allowed_values: &[&str] = match key.value.as_str() {
+            "COMPRESSION" => &["ZLIB", "LZ4", "NONE"],
+            "ENCRYPTION" => &["Y", "N"],
+            _ => &[],
+        };
+        if !allowed_values.is_empty() {
+            let text = match &value {
+                Expr::Value(ValueWithSpan {
+                    value: Value::SingleQuotedString(s) | Value::DoubleQuotedString(s),
+                    ..
+                }) => s.clone(),
+                Expr::Identifier(ident) => ident.value.clone(),
+                other => other.to_string(),
+            };
+            if !allowed_values
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(&text))
+            {
+                return parser_err!(
+                    format!(
+                        "invalid {key} value '{text}', expected one of {}",
+                        allowed_values.join(", ")
+                    ),
+                    value_loc
+                );
+            }
+        }
+
         Ok(Some(SqlOption::KeyValue { key, value }))
     }
 
@@ -10594,6 +11042,41 @@ impl<'a> Parser<'a> {
                 self.parse_set_data_type(true)?
             } else if self.parse_keyword(Keyword::TYPE) {
                 self.parse_set_data_type(false)?
+            } else if is_postgresql && self.parse_keywords(&[Keyword::SET, Keyword::STATISTICS]) {
+                AlterColumnOperation::SetStatistics {
+                    target: self.parse_expr()?,
+                }
+            } else if is_postgresql && self.parse_keywords(&[Keyword::SET, Keyword::STORAGE]) {
+                let storage = match self.expect_one_of_keywords(&[
+                    Keyword::PLAIN,
+                    Keyword::EXTERNAL,
+                    Keyword::EXTENDED,
+                    Keyword::MAIN,
+                    Keyword::DEFAULT,
+                ])? {
+                    Keyword::PLAIN => ColumnStorage::Plain,
+                    Keyword::EXTERNAL => ColumnStorage::External,
+                    Keyword::EXTENDED => ColumnStorage::Extended,
+                    Keyword::MAIN => ColumnStorage::Main,
+                    Keyword::DEFAULT => ColumnStorage::Default,
+                    // unreachable because expect_one_of_keywords used above
+                    unexpected_keyword => return Err(ParserError::ParserError(
+                        format!("Internal parser error: expected any of {{PLAIN, EXTERNAL, EXTENDED, MAIN, DEFAULT}}, got {unexpected_keyword:?}"),
+                    )),
+                };
+                AlterColumnOperation::SetStorage {
+                    storage,
+                    storage_token: self.get_current_token().clone().into(),
+                }
+            } else if is_postgresql
+                && self.peek_keyword(Keyword::SET)
+                && self.peek_nth_token_ref(1).token == Token::LParen
+            {
+                let options = self.parse_options(Keyword::SET)?;
+                AlterColumnOperation::SetOptions { options }
+            } else if is_postgresql && self.parse_keyword(Keyword::RESET) {
+                let options = self.parse_parenthesized_column_list(Mandatory, false)?;
+                AlterColumnOperation::ResetOptions { options }
             } else if self.parse_keywords(&[Keyword::ADD, Keyword::GENERATED]) {
                 let generated_as = if self.parse_keyword(Keyword::ALWAYS) {
                     Some(GeneratedAs::Always)
@@ -10619,7 +11102,7 @@ impl<'a> Parser<'a> {
                 }
             } else {
                 let message = if is_postgresql {
-                    "SET/DROP NOT NULL, SET DEFAULT, SET DATA TYPE, or ADD GENERATED after ALTER COLUMN"
+                    "SET/DROP NOT NULL, SET DEFAULT, SET DATA TYPE, SET STATISTICS, SET STORAGE, SET/RESET options, or ADD GENERATED after ALTER COLUMN"
                 } else {
                     "SET/DROP NOT NULL, SET DEFAULT, or SET DATA TYPE after ALTER COLUMN"
                 };
@@ -12369,7 +12852,7 @@ impl<'a> Parser<'a> {
         if trailing_bracket.0 {
             return parser_err!(
                 format!("unmatched > after parsing data type {ty}"),
-                self.peek_token_ref()
+                self.peek_token_ref().span.start
             );
         }
 
@@ -12661,9 +13144,15 @@ impl<'a> Parser<'a> {
                 Keyword::MEDIUMTEXT => Ok(DataType::MediumText),
                 Keyword::LONGTEXT => Ok(DataType::LongText),
                 Keyword::BYTEA => Ok(DataType::Bytea),
-                Keyword::NUMERIC => Ok(DataType::Numeric(
-                    self.parse_exact_number_optional_precision_scale()?,
-                )),
+                Keyword::NUMERIC => {
+                    let precision = self.parse_exact_number_optional_precision_scale()?;
+
+                    if self.parse_keyword(Keyword::UNSIGNED) {
+                        Ok(DataType::NumericUnsigned(precision))
+                    } else {
+                        Ok(DataType::Numeric(precision))
+                    }
+                }
                 Keyword::DECIMAL => {
                     let precision = self.parse_exact_number_optional_precision_scale()?;
 
@@ -12812,6 +13301,42 @@ impl<'a> Parser<'a> {
             _ => self.expected_at("a data type name", next_token_index),
         }?;
 
+        // `ZEROFILL` only applies to numeric types
+        if self.dialect.supports_data_type_zerofill_suffix()
+            && matches!(
+                data,
+                DataType::TinyInt(_)
+                    | DataType::TinyIntUnsigned(_)
+                    | DataType::SmallInt(_)
+                    | DataType::SmallIntUnsigned(_)
+                    | DataType::MediumInt(_)
+                    | DataType::MediumIntUnsigned(_)
+                    | DataType::Int(_)
+                    | DataType::IntUnsigned(_)
+                    | DataType::Integer(_)
+                    | DataType::IntegerUnsigned(_)
+                    | DataType::BigInt(_)
+                    | DataType::BigIntUnsigned(_)
+                    | DataType::Numeric(_)
+                    | DataType::NumericUnsigned(_)
+                    | DataType::Decimal(_)
+                    | DataType::DecimalUnsigned(_)
+                    | DataType::Dec(_)
+                    | DataType::DecUnsigned(_)
+                    | DataType::Float(_)
+                    | DataType::FloatUnsigned(_)
+                    | DataType::Double(_)
+                    | DataType::DoubleUnsigned(_)
+                    | DataType::DoublePrecision
+                    | DataType::DoublePrecisionUnsigned
+                    | DataType::Real
+                    | DataType::RealUnsigned
+            )
+            && self.parse_keyword(Keyword::ZEROFILL)
+        {
+            data = DataType::Zerofill(Box::new(data));
+        }
+
         if self.dialect.supports_array_typedef_with_brackets() {
             while self.consume_token(&Token::LBracket) {
                 // Parse optional array data type size
@@ -12907,7 +13432,8 @@ impl<'a> Parser<'a> {
     /// Optionally parses an alias for a select list item
     fn maybe_parse_select_item_alias(&mut self) -> Result<Option<Ident>, ParserError> {
         fn validator(explicit: bool, kw: &Keyword, parser: &mut Parser) -> bool {
-            parser.dialect.is_select_item_alias(explicit, kw, parser)
+            parser.is_unreserved_keyword(kw)
+                || parser.dialect.is_select_item_alias(explicit, kw, parser)
         }
         self.parse_optional_alias_inner(None, validator)
     }
@@ -12917,7 +13443,8 @@ impl<'a> Parser<'a> {
     /// addition to the table itself.
     pub fn maybe_parse_table_alias(&mut self) -> Result<Option<TableAlias>, ParserError> {
         fn validator(explicit: bool, kw: &Keyword, parser: &mut Parser) -> bool {
-            parser.dialect.is_table_factor_alias(explicit, kw, parser)
+            parser.is_unreserved_keyword(kw)
+                || parser.dialect.is_table_factor_alias(explicit, kw, parser)
         }
         let explicit = self.peek_keyword(Keyword::AS);
         match self.parse_optional_alias_inner(None, validator)? {
@@ -13059,6 +13586,9 @@ impl<'a> Parser<'a> {
     /// Parse an optional `GROUP BY` clause, returning `Some(GroupByExpr)` when present.
     pub fn parse_optional_group_by(&mut self) -> Result<Option<GroupByExpr>, ParserError> {
         if self.parse_keywords(&[Keyword::GROUP, Keyword::BY]) {
+            if self.peek_keyword(Keyword::ALL) && !self.dialect.supports_group_by_all() {
+                return self.expected_ref("an expression after GROUP BY", self.peek_token_ref());
+            }
             let expressions = if self.parse_keyword(Keyword::ALL) {
                 None
             } else {
@@ -13116,6 +13646,9 @@ impl<'a> Parser<'a> {
     /// Parse an optional `ORDER BY` clause, returning `Some(OrderBy)` when present.
     pub fn parse_optional_order_by(&mut self) -> Result<Option<OrderBy>, ParserError> {
         if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
+            if self.peek_keyword(Keyword::ALL) && !self.dialect.supports_order_by_all() {
+                return self.expected_ref("an expression after ORDER BY", self.peek_token_ref());
+            }
             let order_by =
                 if self.dialect.supports_order_by_all() && self.parse_keyword(Keyword::ALL) {
                     let order_by_options = self.parse_order_by_options()?;
@@ -13141,7 +13674,9 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_optional_limit_clause(&mut self) -> Result<Option<LimitClause>, ParserError> {
+    pub(crate) fn parse_optional_limit_clause(
+        &mut self,
+    ) -> Result<Option<LimitClause>, ParserError> {
         let mut offset = if self.parse_keyword(Keyword::OFFSET) {
             Some(self.parse_offset()?)
         } else {
@@ -13218,6 +13753,12 @@ impl<'a> Parser<'a> {
         self.parse_object_name_inner(in_table_clause, false)
     }
 
+    /// Parse a possibly qualified object name that may contain `*` wildcard
+    /// parts, e.g. `db.*` or `*.*` in `GRANT ... ON` clauses.
+    pub fn parse_object_name_with_wildcards(&mut self) -> Result<ObjectName, ParserError> {
+        self.parse_object_name_inner(false, true)
+    }
+
     /// Parse a possibly qualified, possibly quoted identifier, e.g.
     /// `foo` or `myschema."table"
     ///
@@ -13244,12 +13785,8 @@ impl<'a> Parser<'a> {
         } else {
             loop {
                 if allow_wildcards && self.peek_token_ref().token == Token::Mul {
-                    let span = self.next_token().span;
-                    parts.push(ObjectNamePart::Identifier(Ident {
-                        value: Token::Mul.to_string(),
-                        quote_style: None,
-                        span,
-                    }));
+                    let token = self.next_token();
+                    parts.push(ObjectNamePart::Wildcard(AttachedToken(token)));
                 } else if dialect_of!(self is BigQueryDialect) && in_table_clause {
                     let (ident, end_with_period) = self.parse_unquoted_hyphenated_identifier()?;
                     parts.push(ObjectNamePart::Identifier(ident));
@@ -13429,8 +13966,12 @@ impl<'a> Parser<'a> {
         let next_token = self.next_token();
         match next_token.token {
             Token::Word(w) => Ok(w.into_ident(next_token.span)),
-            Token::SingleQuotedString(s) => Ok(Ident::with_quote('\'', s)),
-            Token::DoubleQuotedString(s) => Ok(Ident::with_quote('\"', s)),
+            Token::SingleQuotedString(s) => {
+                Ok(Ident::with_quote_and_span('\'', next_token.span, s))
+            }
+            Token::DoubleQuotedString(s) => {
+                Ok(Ident::with_quote_and_span('\"', next_token.span, s))
+            }
             _ => self.expected("identifier", next_token),
         }
     }
@@ -14040,7 +14581,7 @@ impl<'a> Parser<'a> {
             && self.dialect.supports_explain_with_utility_options()
             && self.peek_token_ref().token == Token::LParen
         {
-            options = Some(self.parse_utility_options()?)
+            options = Some(self.parse_explain_options()?)
         } else if self.parse_keywords(&[Keyword::QUERY, Keyword::PLAN]) {
             query_plan = true;
         } else if self.parse_keyword(Keyword::ESTIMATE) {
@@ -14093,6 +14634,88 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse a parenthesized `EXPLAIN` option list, e.g. `(ANALYZE, FORMAT JSON)`.
+    pub fn parse_explain_options(&mut self) -> Result<Vec<ExplainOption>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let options = self.parse_comma_separated(Self::parse_explain_option)?;
+        self.expect_token(&Token::RParen)?;
+
+        Ok(options)
+    }
+
+    fn parse_explain_option(&mut self) -> Result<ExplainOption, ParserError> {
+        let name = self.parse_identifier()?;
+        let option_name = match name.quote_style {
+            None => name.value.to_uppercase(),
+            Some(_) => String::new(),
+        };
+        if option_name == "FORMAT" {
+            return Ok(ExplainOption::Format(self.parse_explain_format()?));
+        }
+
+        let boolean_option: Option<fn(Option<bool>) -> ExplainOption> = match option_name.as_str() {
+            "ANALYZE" => Some(ExplainOption::Analyze),
+            "VERBOSE" => Some(ExplainOption::Verbose),
+            "COSTS" => Some(ExplainOption::Costs),
+            "SETTINGS" => Some(ExplainOption::Settings),
+            "GENERIC_PLAN" => Some(ExplainOption::GenericPlan),
+            "BUFFERS" => Some(ExplainOption::Buffers),
+            "WAL" => Some(ExplainOption::Wal),
+            "TIMING" => Some(ExplainOption::Timing),
+            "SUMMARY" => Some(ExplainOption::Summary),
+            "MEMORY" => Some(ExplainOption::Memory),
+            _ => None,
+        };
+        let has_arg = !matches!(self.peek_token_ref().token, Token::Comma | Token::RParen);
+
+        if let Some(option) = boolean_option {
+            if !has_arg {
+                return Ok(option(None));
+            }
+            let value = match self.peek_token_ref().token {
+                Token::Word(ref w) if w.keyword == Keyword::TRUE => Some(true),
+                Token::Word(ref w) if w.keyword == Keyword::FALSE => Some(false),
+                _ => None,
+            };
+            if let Some(value) = value {
+                if matches!(
+                    self.peek_nth_token_ref(1).token,
+                    Token::Comma | Token::RParen
+                ) {
+                    self.advance_token();
+                    return Ok(option(Some(value)));
+                }
+            }
+        }
+
+        let arg = if has_arg {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        Ok(ExplainOption::Other(UtilityOption { name, arg }))
+    }
+
+    fn parse_explain_format(&mut self) -> Result<ExplainFormat, ParserError> {
+        let format = match &self.peek_token_ref().token {
+            Token::Word(w) if w.quote_style.is_none() => match w.value.to_uppercase().as_str() {
+                "TEXT" => Some(ExplainFormat::Text),
+                "XML" => Some(ExplainFormat::Xml),
+                "JSON" => Some(ExplainFormat::Json),
+                "YAML" => Some(ExplainFormat::Yaml),
+                _ => None,
+            },
+            _ => None,
+        };
+        match format {
+            Some(format) => {
+                self.advance_token();
+                Ok(format)
+            }
+            None => self.expected_ref("TEXT, XML, JSON or YAML", self.peek_token_ref()),
+        }
+    }
+
     /// Parse a query expression, i.e. a `SELECT` statement optionally
     /// preceded with some `WITH` CTE declarations and optionally followed
     /// by `ORDER BY`. Unlike some other parse_... methods, this one doesn't
@@ -14609,7 +15232,7 @@ impl<'a> Parser<'a> {
         };
 
         let mut is_materialized = None;
-        if dialect_of!(self is PostgreSqlDialect) {
+        if self.dialect.supports_cte_materialization_hint() {
             if self.parse_keyword(Keyword::MATERIALIZED) {
                 is_materialized = Some(CteAsMaterialized::Materialized);
             } else if self.parse_keywords(&[Keyword::NOT, Keyword::MATERIALIZED]) {
@@ -14762,6 +15385,12 @@ impl<'a> Parser<'a> {
             let from_token = self.expect_keyword(Keyword::FROM)?;
             let from = self.parse_table_with_joins()?;
             if !self.peek_keyword(Keyword::SELECT) {
+                // e.g. `FROM t WHERE x > 1`: an implicit `SELECT *` that may still be filtered
+                let selection = if self.parse_keyword(Keyword::WHERE) {
+                    Some(self.parse_expr()?)
+                } else {
+                    None
+                };
                 return Ok(Select {
                     select_token: AttachedToken(from_token),
                     optimizer_hints: vec![],
@@ -14775,7 +15404,7 @@ impl<'a> Parser<'a> {
                     from,
                     lateral_views: vec![],
                     prewhere: None,
-                    selection: None,
+                    selection,
                     group_by: GroupByExpr::Expressions(vec![], vec![]),
                     cluster_by: vec![],
                     distribute_by: vec![],
@@ -14930,12 +15559,12 @@ impl<'a> Parser<'a> {
         let (named_windows, qualify, window_before_qualify) = if self.parse_keyword(Keyword::WINDOW)
         {
             let named_windows = self.parse_comma_separated(Parser::parse_named_window)?;
-            if self.parse_keyword(Keyword::QUALIFY) {
+            if self.dialect.supports_qualify_clause() && self.parse_keyword(Keyword::QUALIFY) {
                 (named_windows, Some(self.parse_expr()?), true)
             } else {
                 (named_windows, None, true)
             }
-        } else if self.parse_keyword(Keyword::QUALIFY) {
+        } else if self.dialect.supports_qualify_clause() && self.parse_keyword(Keyword::QUALIFY) {
             let qualify = Some(self.parse_expr()?);
             if self.parse_keyword(Keyword::WINDOW) {
                 (
@@ -15542,11 +16171,13 @@ impl<'a> Parser<'a> {
 
     /// Parse a `SHOW` statement and dispatch to specific SHOW handlers.
     pub fn parse_show(&mut self) -> Result<Statement, ParserError> {
+        let show_token = self.get_current_token().clone();
         let terse = self.parse_keyword(Keyword::TERSE);
         let extended = self.parse_keyword(Keyword::EXTENDED);
         let full = self.parse_keyword(Keyword::FULL);
-        let session = self.parse_keyword(Keyword::SESSION);
-        let global = self.parse_keyword(Keyword::GLOBAL);
+        let scope = self
+            .parse_one_of_keywords(&[Keyword::SESSION, Keyword::GLOBAL])
+            .and_then(Self::keyword_to_modifier);
         let external = self.parse_keyword(Keyword::EXTERNAL);
         if self
             .parse_one_of_keywords(&[Keyword::COLUMNS, Keyword::FIELDS])
@@ -15575,17 +16206,16 @@ impl<'a> Parser<'a> {
             && dialect_of!(self is MySqlDialect | GenericDialect)
         {
             Ok(Statement::ShowVariables {
+                show_token: AttachedToken(show_token),
                 filter: self.parse_show_statement_filter()?,
-                session,
-                global,
+                scope,
             })
         } else if self.parse_keyword(Keyword::STATUS)
             && dialect_of!(self is MySqlDialect | GenericDialect)
         {
             Ok(Statement::ShowStatus {
                 filter: self.parse_show_statement_filter()?,
-                session,
-                global,
+                scope,
             })
         } else if self.parse_keyword(Keyword::CATALOGS) {
             self.parse_show_catalogs(terse)
@@ -15865,7 +16495,7 @@ impl<'a> Parser<'a> {
             {
                 // ClickHouse: INNER ARRAY JOIN
                 Join {
-                    relation: self.parse_table_factor()?,
+                    relation: self.parse_array_join_arrays()?,
                     global,
                     join_operator: JoinOperator::InnerArrayJoin,
                 }
@@ -15874,7 +16504,7 @@ impl<'a> Parser<'a> {
             {
                 // ClickHouse: LEFT ARRAY JOIN
                 Join {
-                    relation: self.parse_table_factor()?,
+                    relation: self.parse_array_join_arrays()?,
                     global,
                     join_operator: JoinOperator::LeftArrayJoin,
                 }
@@ -15883,7 +16513,7 @@ impl<'a> Parser<'a> {
             {
                 // ClickHouse: ARRAY JOIN
                 Join {
-                    relation: self.parse_table_factor()?,
+                    relation: self.parse_array_join_arrays()?,
                     global,
                     join_operator: JoinOperator::ArrayJoin,
                 }
@@ -16022,6 +16652,17 @@ impl<'a> Parser<'a> {
         )
     }
 
+    /// Parses the comma-separated arrays of a ClickHouse `ARRAY JOIN`,
+    /// returning a [`TableFactor::ArrayJoinList`] if there is more than one.
+    fn parse_array_join_arrays(&mut self) -> Result<TableFactor, ParserError> {
+        let mut arrays = self.parse_comma_separated(Parser::parse_table_factor)?;
+        if arrays.len() == 1 {
+            Ok(arrays.remove(0))
+        } else {
+            Ok(TableFactor::ArrayJoinList(arrays))
+        }
+    }
+
     /// A table name or a parenthesized subquery, followed by optional `[AS] alias`
     #[cfg_attr(feature = "recursive-protection", recursive::recursive)]
     pub fn parse_table_factor(&mut self) -> Result<TableFactor, ParserError> {
@@ -16155,6 +16796,10 @@ impl<'a> Parser<'a> {
                             // `(mytable AS alias)`
                             alias.replace(outer_alias);
                         }
+                        // only the relation of an `ARRAY JOIN`, never found here
+                        TableFactor::ArrayJoinList(_) => {
+                            return self.expected_ref("joined table", self.peek_token_ref());
+                        }
                     };
                 }
                 // Do not store the extra set of parens in the AST
@@ -17200,6 +17845,58 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse DuckDB's simplified `PIVOT` statement, the `PIVOT` keyword
+    /// having been consumed.
+    pub fn parse_pivot_statement(
+        &mut self,
+        pivot_token: TokenWithSpan,
+    ) -> Result<PivotStatement, ParserError> {
+        let table = self.parse_table_factor()?;
+        self.expect_keyword_is(Keyword::ON)?;
+        let on = self.parse_comma_separated(|p| {
+            let expr = p.parse_subexpr(p.dialect.prec_value(Precedence::Between))?;
+            let values = if p.parse_keyword(Keyword::IN) {
+                p.expect_token(&Token::LParen)?;
+                let values =
+                    p.parse_comma_separated(Self::parse_expr_with_alias_optional_as_keyword)?;
+                p.expect_token(&Token::RParen)?;
+                Some(values)
+            } else {
+                None
+            };
+            Ok(PivotOnColumn { expr, values })
+        })?;
+        let using = if self.parse_keyword(Keyword::USING) {
+            self.parse_comma_separated(Self::parse_pivot_aggregate_function)?
+        } else {
+            vec![]
+        };
+        let group_by = if self.parse_keywords(&[Keyword::GROUP, Keyword::BY]) {
+            self.parse_comma_separated(Parser::parse_expr)?
+        } else {
+            vec![]
+        };
+        let order_by = if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
+            self.parse_comma_separated(Parser::parse_order_by_expr)?
+        } else {
+            vec![]
+        };
+        let limit = if self.parse_keyword(Keyword::LIMIT) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        Ok(PivotStatement {
+            pivot_token: pivot_token.into(),
+            table,
+            on,
+            using,
+            group_by,
+            order_by,
+            limit,
+        })
+    }
+
     /// Parse an UNPIVOT table factor, returning a TableFactor.
     pub fn parse_unpivot_table_factor(
         &mut self,
@@ -17259,6 +17956,9 @@ impl<'a> Parser<'a> {
 
         let with_grant_option =
             self.parse_keywords(&[Keyword::WITH, Keyword::GRANT, Keyword::OPTION]);
+        // `WITH ADMIN OPTION` only applies to role membership grants
+        let with_admin_option = matches!(privileges, Privileges::Roles(_))
+            && self.parse_keywords(&[Keyword::WITH, Keyword::ADMIN, Keyword::OPTION]);
 
         let current_grants =
             if self.parse_keywords(&[Keyword::COPY, Keyword::CURRENT, Keyword::GRANTS]) {
@@ -17276,7 +17976,10 @@ impl<'a> Parser<'a> {
         };
 
         let granted_by = if self.parse_keywords(&[Keyword::GRANTED, Keyword::BY]) {
-            Some(self.parse_identifier()?)
+            Some(Grantee {
+                grantee_type: GranteesType::None,
+                name: Some(self.parse_grantee_name()?),
+            })
         } else {
             None
         };
@@ -17286,6 +17989,7 @@ impl<'a> Parser<'a> {
             objects,
             grantees,
             with_grant_option,
+            with_admin_option,
             as_grantor,
             granted_by,
             current_grants,
@@ -17368,7 +18072,19 @@ impl<'a> Parser<'a> {
             Privileges::All {
                 with_privileges_keyword: self.parse_keyword(Keyword::PRIVILEGES),
             }
+        } else if let Some(actions) = self.maybe_parse(|p| p.parse_actions_list())? {
+            Privileges::Actions(actions)
+        } else if let Some(roles) = self.maybe_parse(|p| {
+            // Role membership: `GRANT role [, ...] TO ...` / `REVOKE role [, ...] FROM ...`
+            let roles = p.parse_comma_separated(|p| p.parse_identifier())?;
+            match p.peek_token_ref().token {
+                Token::Word(ref w) if matches!(w.keyword, Keyword::TO | Keyword::FROM) => Ok(roles),
+                _ => p.expected_ref("TO or FROM", p.peek_token_ref()),
+            }
+        })? {
+            Privileges::Roles(roles)
         } else {
+            // Re-parse to surface the error for the privilege list
             let actions = self.parse_actions_list()?;
             Privileges::Actions(actions)
         };
@@ -17518,8 +18234,7 @@ impl<'a> Parser<'a> {
                     Keyword::PROCEDURE,
                     Keyword::FUNCTION,
                 ]);
-                let objects =
-                    self.parse_comma_separated(|p| p.parse_object_name_inner(false, true));
+                let objects = self.parse_comma_separated(Parser::parse_object_name_with_wildcards);
                 match object_type {
                     Some(Keyword::DATABASE) => Some(GrantObjects::Databases(objects?)),
                     Some(Keyword::SCHEMA) => Some(GrantObjects::Schemas(objects?)),
@@ -19101,17 +19816,23 @@ impl<'a> Parser<'a> {
 
     /// Parse a FOR UPDATE/FOR SHARE clause
     pub fn parse_lock(&mut self) -> Result<LockClause, ParserError> {
-        let lock_type = match self.expect_one_of_keywords(&[Keyword::UPDATE, Keyword::SHARE])? {
-            Keyword::UPDATE => LockType::Update,
-            Keyword::SHARE => LockType::Share,
-            unexpected_keyword => return Err(ParserError::ParserError(
-                format!("Internal parser error: expected any of {{UPDATE, SHARE}}, got {unexpected_keyword:?}"),
-            )),
+        let lock_type = if self.parse_keywords(&[Keyword::NO, Keyword::KEY, Keyword::UPDATE]) {
+            LockType::NoKeyUpdate
+        } else if self.parse_keywords(&[Keyword::KEY, Keyword::SHARE]) {
+            LockType::KeyShare
+        } else {
+            match self.expect_one_of_keywords(&[Keyword::UPDATE, Keyword::SHARE])? {
+                Keyword::UPDATE => LockType::Update,
+                Keyword::SHARE => LockType::Share,
+                unexpected_keyword => return Err(ParserError::ParserError(
+                    format!("Internal parser error: expected any of {{UPDATE, SHARE}}, got {unexpected_keyword:?}"),
+                )),
+            }
         };
         let of = if self.parse_keyword(Keyword::OF) {
-            Some(self.parse_object_name(false)?)
+            self.parse_comma_separated(|p| p.parse_object_name(false))?
         } else {
-            None
+            vec![]
         };
         let nonblock = if self.parse_keyword(Keyword::NOWAIT) {
             Some(NonBlock::Nowait)
@@ -19187,6 +19908,50 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse a PostgreSQL `REINDEX` statement, assuming the `REINDEX` keyword
+    /// has already been consumed.
+    ///
+    /// See [Reindex]
+    pub fn parse_reindex(&mut self) -> Result<Reindex, ParserError> {
+        let options = if self.peek_token_ref().token == Token::LParen {
+            Some(self.parse_utility_options()?)
+        } else {
+            None
+        };
+        let kind = match self.expect_one_of_keywords(&[
+            Keyword::INDEX,
+            Keyword::TABLE,
+            Keyword::SCHEMA,
+            Keyword::DATABASE,
+            Keyword::SYSTEM,
+        ])? {
+            Keyword::INDEX => ReindexKind::Index,
+            Keyword::TABLE => ReindexKind::Table,
+            Keyword::SCHEMA => ReindexKind::Schema,
+            Keyword::DATABASE => ReindexKind::Database,
+            Keyword::SYSTEM => ReindexKind::System,
+            unexpected_keyword => {
+                return Err(ParserError::ParserError(format!(
+                    "Internal parser error: unexpected keyword `{unexpected_keyword}` in REINDEX"
+                )))
+            }
+        };
+        let concurrently = self.parse_keyword(Keyword::CONCURRENTLY);
+        let name = match kind {
+            ReindexKind::Database | ReindexKind::System => {
+                self.maybe_parse(|parser| parser.parse_object_name(false))?
+            }
+            _ => Some(self.parse_object_name(false)?),
+        };
+
+        Ok(Reindex {
+            options,
+            kind,
+            concurrently,
+            name,
+        })
+    }
+
     /// Parse a VALUES clause
     pub fn parse_values(
         &mut self,
@@ -19616,16 +20381,34 @@ impl<'a> Parser<'a> {
         })
     }
 
-    fn parse_pragma_value(&mut self) -> Result<ValueWithSpan, ParserError> {
+    fn parse_pragma_value(&mut self) -> Result<Expr, ParserError> {
+        match &self.peek_token_ref().token {
+            // A bare name, e.g. `PRAGMA table_info(t)` or `PRAGMA journal_mode = WAL`
+            Token::Word(w)
+                if w.quote_style.is_none()
+                    && !matches!(w.keyword, Keyword::TRUE | Keyword::FALSE | Keyword::NULL)
+                    && !keywords::RESERVED_FOR_COLUMN_ALIAS.contains(&w.keyword)
+                    && !self.dialect.is_reserved_for_identifier(w.keyword) =>
+            {
+                return Ok(Expr::Identifier(self.parse_identifier()?));
+            }
+            // A signed number, e.g. `PRAGMA cache_size = -2000`
+            Token::Plus | Token::Minus => return self.parse_number(),
+            _ => {}
+        }
         let v = self.parse_value()?;
         match &v.value {
-            Value::SingleQuotedString(_) => Ok(v),
-            Value::DoubleQuotedString(_) => Ok(v),
-            Value::Number(_, _) => Ok(v),
-            Value::Placeholder(_) => Ok(v),
+            Value::SingleQuotedString(_)
+            | Value::DoubleQuotedString(_)
+            | Value::Number(_, _)
+            | Value::Boolean(_)
+            | Value::Placeholder(_) => Ok(Expr::Value(v)),
             _ => {
                 self.prev_token();
-                self.expected_ref("number or string or ? placeholder", self.peek_token_ref())
+                self.expected_ref(
+                    "number, string, boolean, name or ? placeholder",
+                    self.peek_token_ref(),
+                )
             }
         }
     }
@@ -19706,6 +20489,7 @@ impl<'a> Parser<'a> {
     /// ```
     /// [Databricks](https://docs.databricks.com/en/sql/language-manual/delta-optimize.html)
     pub fn parse_optimize_table(&mut self) -> Result<Statement, ParserError> {
+        let optimize_token = self.get_current_token().clone();
         let has_table_keyword = self.parse_keyword(Keyword::TABLE);
 
         let name = self.parse_object_name(false)?;
@@ -19752,7 +20536,10 @@ impl<'a> Parser<'a> {
         };
 
         Ok(Statement::OptimizeTable {
+            optimize_token: AttachedToken(optimize_token),
+            location: None,
             name,
+            additional_names: vec![],
             has_table_keyword,
             on_cluster,
             partition,
@@ -20391,14 +21178,20 @@ impl<'a> Parser<'a> {
 
     fn parse_vacuum(&mut self) -> Result<Statement, ParserError> {
         self.expect_keyword(Keyword::VACUUM)?;
+        let options = if self.peek_token_ref().token == Token::LParen {
+            Some(self.parse_utility_options()?)
+        } else {
+            None
+        };
         let full = self.parse_keyword(Keyword::FULL);
         let sort_only = self.parse_keywords(&[Keyword::SORT, Keyword::ONLY]);
         let delete_only = self.parse_keywords(&[Keyword::DELETE, Keyword::ONLY]);
         let reindex = self.parse_keyword(Keyword::REINDEX);
         let recluster = self.parse_keyword(Keyword::RECLUSTER);
-        let (table_name, threshold, boost) =
+        let (table_name, columns, threshold, boost) =
             match self.maybe_parse(|p| p.parse_object_name(false))? {
                 Some(table_name) => {
+                    let columns = self.parse_parenthesized_column_list(Optional, false)?;
                     let threshold = if self.parse_keyword(Keyword::TO) {
                         let value = self.parse_value()?;
                         self.expect_keyword(Keyword::PERCENT)?;
@@ -20407,17 +21200,19 @@ impl<'a> Parser<'a> {
                         None
                     };
                     let boost = self.parse_keyword(Keyword::BOOST);
-                    (Some(table_name), threshold, boost)
+                    (Some(table_name), columns, threshold, boost)
                 }
-                _ => (None, None, false),
+                _ => (None, vec![], None, false),
             };
         Ok(Statement::Vacuum(VacuumStatement {
+            options,
             full,
             sort_only,
             delete_only,
             reindex,
             recluster,
             table_name,
+            columns,
             threshold,
             boost,
         }))
@@ -20554,6 +21349,11 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Returns true if `kw` is not reserved as per [`ParserOptions::unreserved_keywords`].
+    fn is_unreserved_keyword(&self, kw: &Keyword) -> bool {
+        self.options.unreserved_keywords.contains(kw)
+    }
+
     pub(crate) fn in_column_definition_state(&self) -> bool {
         matches!(self.state, ColumnDefinition)
     }
@@ -21318,25 +22118,39 @@ mod tests {
     #[test]
     fn test_tokenizer_error_loc() {
         let sql = "foo '";
-        let ast = Parser::parse_sql(&GenericDialect, sql);
+        let err = Parser::parse_sql(&GenericDialect, sql).unwrap_err();
         assert_eq!(
-            ast,
-            Err(ParserError::TokenizerError(
-                "Unterminated string literal at Line: 1, Column: 5".to_string()
-            ))
+            err,
+            ParserError::TokenizerError(TokenizerError {
+                message: "Unterminated string literal".to_string(),
+                location: Location::new(1, 5),
+                end_location: Location::new(1, 6),
+            })
+        );
+        // the error points at the opening quote
+        assert_eq!(err.location(), Some(Location::new(1, 5)));
+        assert_eq!(
+            err.to_string(),
+            "sql parser error: Unterminated string literal at Line: 1, Column: 5"
         );
     }
 
     #[test]
     fn test_parser_error_loc() {
         let sql = "SELECT this is a syntax error";
-        let ast = Parser::parse_sql(&GenericDialect, sql);
+        let err = Parser::parse_sql(&GenericDialect, sql).unwrap_err();
         assert_eq!(
-            ast,
-            Err(ParserError::ParserError(
-                "Expected: [NOT] NULL | TRUE | FALSE | DISTINCT | [form] NORMALIZED FROM after IS, found: a at Line: 1, Column: 16"
-                    .to_string()
-            ))
+            err,
+            ParserError::ParserErrorWithLocation {
+                message: "Expected: [NOT] NULL | TRUE | FALSE | UNKNOWN | DISTINCT | [form] NORMALIZED FROM after IS, found: a"
+                    .to_string(),
+                location: Location::new(1, 16),
+            }
+        );
+        assert_eq!(err.location(), Some(Location::new(1, 16)));
+        assert_eq!(
+            err.to_string(),
+            "sql parser error: Expected: [NOT] NULL | TRUE | FALSE | UNKNOWN | DISTINCT | [form] NORMALIZED FROM after IS, found: a at Line: 1, Column: 16"
         );
     }
 
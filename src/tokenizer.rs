@@ -471,11 +471,8 @@ pub struct Word {
 impl fmt::Display for Word {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.quote_style {
-            Some(s) if s == '"' || s == '[' || s == '`' => {
-                write!(f, "{}{}{}", s, self.value, Word::matching_end_quote(s))
-            }
+            Some(s) => write!(f, "{}{}{}", s, self.value, Word::matching_end_quote(s)),
             None => f.write_str(&self.value),
-            _ => panic!("Unexpected quote_style!"),
         }
     }
 }
@@ -486,7 +483,7 @@ impl Word {
             '"' => '"', // ANSI and most dialects
             '[' => ']', // MS SQL
             '`' => '`', // MySQL
-            _ => panic!("unexpected quoting style!"),
+            _ => ch,    // Any other delimiter closes itself
         }
     }
 }
@@ -786,12 +783,23 @@ impl fmt::Display for TokenWithSpan {
 }
 
 /// An error reported by the tokenizer, with a human-readable `message` and a `location`.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TokenizerError {
     /// A descriptive error message.
     pub message: String,
-    /// The `Location` where the error was detected.
+    /// The `Location` where the error was detected, e.g. the opening
+    /// quote of an unterminated string literal.
     pub location: Location,
+    /// The `Location` where the tokenizer stopped, e.g. the end of input
+    /// for an unterminated string literal.
+    pub end_location: Location,
+}
+
+impl TokenizerError {
+    /// The span of the SQL text the error refers to.
+    pub fn span(&self) -> Span {
+        Span::new(self.location, self.end_location)
+    }
 }
 
 impl fmt::Display for TokenizerError {
@@ -1302,6 +1310,7 @@ impl<'a> Tokenizer<'a> {
                         .peek_nested_delimited_identifier_quotes(chars.peekable.clone())
                     else {
                         return self.tokenizer_error(
+                            chars.location(),
                             chars.location(),
                             format!("Expected nested delimiter '{quote_start}' before EOF."),
                         );
@@ -1322,16 +1331,23 @@ impl<'a> Tokenizer<'a> {
                     if chars.peek() != Some(&nested_quote_start) {
                         return self.tokenizer_error(
                             error_loc,
+                            chars.location(),
                             format!("Expected nested delimiter '{nested_quote_start}' before EOF."),
                         );
                     }
+                    let mut nested = self.tokenize_quoted_identifier(nested_quote_end, chars)?;
+                    if quote_start == '[' && self.unescape {
+                        // `]` is written as `]]` inside `[...]`, as in the non-nested form
+                        nested = nested.replace("]]", "]");
+                    }
                     word.push(nested_quote_start.into());
-                    word.push(self.tokenize_quoted_identifier(nested_quote_end, chars)?);
+                    word.push(nested);
                     word.push(nested_quote_end.into());
                     peeking_take_while(chars, |ch| ch.is_whitespace());
                     if chars.peek() != Some(&quote_end) {
                         return self.tokenizer_error(
                             error_loc,
+                            chars.location(),
                             format!("Expected close delimiter '{quote_end}' before EOF."),
                         );
                     }
@@ -1355,6 +1371,7 @@ impl<'a> Tokenizer<'a> {
                         }
 
                         return self.tokenizer_error(
+                            chars.location(),
                             chars.location(),
                             "Unexpected character '_'".to_string(),
                         );
@@ -1378,6 +1395,18 @@ impl<'a> Tokenizer<'a> {
                         let s2 = peeking_next_take_while(chars, |ch, next_ch| {
                             ch.is_ascii_hexdigit() || is_number_separator(ch, next_ch)
                         });
+                        // `0x` without hex digits, or followed by other identifier
+                        // characters, is an identifier starting with digits, e.g. `0x1G`
+                        if self.dialect.supports_numeric_prefix() {
+                            let word =
+                                peeking_take_while(chars, |ch| self.dialect.is_identifier_part(ch));
+                            if s2.is_empty() || !word.is_empty() {
+                                return Ok(Some(Token::make_word_owned(
+                                    format!("0x{s2}{word}"),
+                                    None,
+                                )));
+                            }
+                        }
                         return Ok(Some(Token::HexStringLiteral(s2)));
                     }
 
@@ -1434,7 +1463,7 @@ impl<'a> Tokenizer<'a> {
                                 s += exponent_part.as_str();
                             }
                             // Not an exponent, discard the work done
-                            _ => (),
+                            _ => exponent_part.clear(),
                         }
                     }
 
@@ -1912,6 +1941,7 @@ impl<'a> Tokenizer<'a> {
             (Some(custom), _) => Ok(Token::CustomBinaryOperator(custom).into()),
             (None, Some(tok)) => Ok(Some(tok)),
             (None, None) => self.tokenizer_error(
+                chars.location(),
                 chars.location(),
                 format!("Expected a valid binary operator after '{prefix}'"),
             ),
@@ -1923,6 +1953,8 @@ impl<'a> Tokenizer<'a> {
         let mut s = String::new();
         let mut value = String::new();
 
+        // location of the opening `$`, reported for unterminated strings
+        let start_loc = chars.location();
         chars.next();
 
         // If the dialect does not support dollar-quoted strings, then `$$` is rather a placeholder.
@@ -1951,7 +1983,11 @@ impl<'a> Tokenizer<'a> {
             }
 
             return if chars.peek().is_none() && !is_terminated {
-                self.tokenizer_error(chars.location(), "Unterminated dollar-quoted string")
+                self.tokenizer_error(
+                    start_loc,
+                    chars.location(),
+                    "Unterminated dollar-quoted string",
+                )
             } else {
                 Ok(Token::DollarQuotedString(DollarQuotedString {
                     value: s,
@@ -2007,6 +2043,7 @@ impl<'a> Tokenizer<'a> {
                             }
 
                             return self.tokenizer_error(
+                                start_loc,
                                 chars.location(),
                                 "Unterminated dollar-quoted, expected $",
                             );
@@ -2027,11 +2064,13 @@ impl<'a> Tokenizer<'a> {
     fn tokenizer_error<R>(
         &self,
         loc: Location,
+        end_loc: Location,
         message: impl Into<String>,
     ) -> Result<R, TokenizerError> {
         Err(TokenizerError {
             message: message.into(),
             location: loc,
+            end_location: end_loc,
         })
     }
 
@@ -2076,6 +2115,7 @@ impl<'a> Tokenizer<'a> {
         } else {
             self.tokenizer_error(
                 error_loc,
+                chars.location(),
                 format!("Expected close delimiter '{quote_end}' before EOF."),
             )
         }
@@ -2091,7 +2131,11 @@ impl<'a> Tokenizer<'a> {
             return Ok(s);
         }
 
-        self.tokenizer_error(starting_loc, "Unterminated encoded string literal")
+        self.tokenizer_error(
+            starting_loc,
+            chars.location(),
+            "Unterminated encoded string literal",
+        )
     }
 
     /// Reads a string literal quoted by a single or triple quote characters.
@@ -2127,7 +2171,11 @@ impl<'a> Tokenizer<'a> {
             }
             3 => {
                 let Some(num_quote_chars) = NonZeroU8::new(3) else {
-                    return self.tokenizer_error(error_loc, "invalid number of opening quotes");
+                    return self.tokenizer_error(
+                        error_loc,
+                        chars.location(),
+                        "invalid number of opening quotes",
+                    );
                 };
                 (
                     triple_quote_token,
@@ -2135,7 +2183,11 @@ impl<'a> Tokenizer<'a> {
                 )
             }
             _ => {
-                return self.tokenizer_error(error_loc, "invalid string literal opening");
+                return self.tokenizer_error(
+                    error_loc,
+                    chars.location(),
+                    "invalid string literal opening",
+                );
             }
         };
 
@@ -2187,6 +2239,7 @@ impl<'a> Tokenizer<'a> {
             None | Some(' ') | Some('\t') | Some('\r') | Some('\n') => {
                 return self.tokenizer_error(
                     start_quote_loc,
+                    chars.location(),
                     format!(
                         "Invalid space, tab, newline, or EOF after '{}''",
                         String::from_iter(literal_prefix)
@@ -2221,7 +2274,11 @@ impl<'a> Tokenizer<'a> {
             value.push(ch);
         }
 
-        self.tokenizer_error(literal_start_loc, "Unterminated string literal")
+        self.tokenizer_error(
+            literal_start_loc,
+            chars.location(),
+            "Unterminated string literal",
+        )
     }
 
     /// Read a quoted string.
@@ -2236,7 +2293,11 @@ impl<'a> Tokenizer<'a> {
         // Consume any opening quotes.
         for _ in 0..settings.num_opening_quotes_to_consume {
             if Some(settings.quote_style) != chars.next() {
-                return self.tokenizer_error(error_loc, "invalid string literal opening");
+                return self.tokenizer_error(
+                    error_loc,
+                    chars.location(),
+                    "invalid string literal opening",
+                );
             }
         }
 
@@ -2328,7 +2389,7 @@ impl<'a> Tokenizer<'a> {
                 }
             }
         }
-        self.tokenizer_error(error_loc, "Unterminated string literal")
+        self.tokenizer_error(error_loc, chars.location(), "Unterminated string literal")
     }
 
     fn tokenize_multiline_comment(
@@ -2360,6 +2421,7 @@ impl<'a> Tokenizer<'a> {
                 }
                 None => {
                     break self.tokenizer_error(
+                        chars.location(),
                         chars.location(),
                         "Unexpected EOF while in a multi-line comment",
                     );
@@ -2616,6 +2678,7 @@ fn unescape_unicode_single_quoted_string(chars: &mut State<'_>) -> Result<String
     Err(TokenizerError {
         message: "Unterminated unicode encoded string literal".to_string(),
         location: chars.location(),
+        end_location: chars.location(),
     })
 }
 
@@ -2629,16 +2692,19 @@ fn take_char_from_hex_digits(
             message: "Unexpected EOF while parsing hex digit in escaped unicode string."
                 .to_string(),
             location: chars.location(),
+            end_location: chars.location(),
         })?;
         let digit = next_char.to_digit(16).ok_or_else(|| TokenizerError {
             message: format!("Invalid hex digit in escaped unicode string: {next_char}"),
             location: chars.location(),
+            end_location: chars.location(),
         })?;
         result = result * 16 + digit;
     }
     char::from_u32(result).ok_or_else(|| TokenizerError {
         message: format!("Invalid unicode character: {result:x}"),
         location: chars.location(),
+        end_location: chars.location(),
     })
 }
 
@@ -2657,6 +2723,7 @@ mod tests {
         let err = TokenizerError {
             message: "test".into(),
             location: Location { line: 1, column: 1 },
+            end_location: Location { line: 1, column: 1 },
         };
         {
             use core::error::Error;
@@ -3070,6 +3137,10 @@ mod tests {
             Err(TokenizerError {
                 message: "Unterminated string literal".to_string(),
                 location: Location { line: 1, column: 8 },
+                end_location: Location {
+                    line: 1,
+                    column: 12
+                },
             })
         );
     }
@@ -3087,7 +3158,11 @@ mod tests {
                 location: Location {
                     line: 1,
                     column: 35
-                }
+                },
+                end_location: Location {
+                    line: 1,
+                    column: 41
+                },
             })
         );
     }
@@ -3189,10 +3264,11 @@ mod tests {
             Tokenizer::new(&dialect, &sql).tokenize(),
             Err(TokenizerError {
                 message: "Unterminated dollar-quoted, expected $".into(),
-                location: Location {
+                location: Location { line: 1, column: 8 },
+                end_location: Location {
                     line: 1,
                     column: 91
-                }
+                },
             })
         );
     }
@@ -3205,10 +3281,11 @@ mod tests {
             Tokenizer::new(&dialect, &sql).tokenize(),
             Err(TokenizerError {
                 message: "Unterminated dollar-quoted, expected $".into(),
-                location: Location {
+                location: Location { line: 1, column: 8 },
+                end_location: Location {
                     line: 1,
                     column: 17
-                }
+                },
             })
         );
     }
@@ -3296,10 +3373,11 @@ mod tests {
             Tokenizer::new(&dialect, &sql).tokenize(),
             Err(TokenizerError {
                 message: "Unterminated dollar-quoted string".into(),
-                location: Location {
+                location: Location { line: 1, column: 8 },
+                end_location: Location {
                     line: 1,
                     column: 86
-                }
+                },
             })
         );
     }
@@ -3544,6 +3622,7 @@ mod tests {
             Err(TokenizerError {
                 message: "Expected close delimiter '\"' before EOF.".to_string(),
                 location: Location { line: 1, column: 1 },
+                end_location: Location { line: 1, column: 5 },
             })
         );
     }
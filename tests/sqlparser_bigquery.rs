@@ -2043,7 +2043,7 @@ fn parse_merge() {
 
 #[test]
 fn parse_merge_invalid_statements() {
-    let dialects = all_dialects_except(|d| d.is::<BigQueryDialect>() || d.is::<GenericDialect>());
+    let dialects = all_dialects_where(|d| d.supports_merge_not_matched_by_source_or_target());
     for (sql, err_msg) in [
         (
             "MERGE T USING U ON TRUE WHEN MATCHED BY TARGET AND 1 THEN DELETE",
@@ -2174,7 +2174,7 @@ fn parse_big_query_declare() {
         ),
     ] {
         match bigquery().verified_stmt(sql) {
-            Statement::Declare { mut stmts } => {
+            Statement::Declare { mut stmts, .. } => {
                 assert_eq!(1, stmts.len());
                 let Declare {
                     names,
@@ -2452,6 +2452,7 @@ fn test_bigquery_trim() {
             trim_characters: Some(vec![Expr::Value(
                 Value::SingleQuotedString("a".to_owned()).with_empty_span()
             )]),
+            bare_from: false,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -2631,7 +2632,7 @@ fn test_struct_trailing_and_nested_bracket() {
     // Bad case with redundant closing bracket
     assert_eq!(
         ParserError::ParserError(
-            "unmatched > after parsing data type STRUCT<a STRING, b INT64>)".to_owned()
+            "unmatched > after parsing data type STRUCT<a STRING, b INT64>".to_owned()
         ),
         bigquery()
             .parse_sql_statements("CREATE TABLE my_table(f1 STRUCT<a STRING, b INT64>>)")
@@ -2950,3 +2951,94 @@ fn test_create_snapshot_table() {
         "CREATE SNAPSHOT TABLE IF NOT EXISTS dataset_id.table1 CLONE dataset_id.table2 FOR SYSTEM_TIME AS OF TIMESTAMP_SUB(CURRENT_TIMESTAMP(), INTERVAL 1 HOUR) OPTIONS(expiration_timestamp = TIMESTAMP '2025-01-01 00:00:00 UTC')",
     );
 }
+
+#[test]
+fn parse_create_table_partition_by_expr_cluster_by_options() {
+    let sql = concat!(
+        "CREATE TABLE mydataset.events (id INT64, ts TIMESTAMP, country STRING) ",
+        "PARTITION BY DATE(ts) ",
+        "CLUSTER BY country, id ",
+        "OPTIONS(description = 'x', require_partition_filter = true)"
+    );
+    match bigquery().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            partition_by,
+            cluster_by,
+            table_options,
+            ..
+        }) => {
+            assert_eq!(
+                "DATE(ts)",
+                partition_by.expect("partition expression").to_string()
+            );
+            assert_eq!(
+                Some(WrappedCollection::NoWrapping(vec![
+                    Expr::Identifier(Ident::new("country")),
+                    Expr::Identifier(Ident::new("id")),
+                ])),
+                cluster_by
+            );
+            let CreateTableOptions::Options(options) = table_options else {
+                panic!("expected OPTIONS(...), got {table_options:?}");
+            };
+            assert_eq!(
+                vec!["description = 'x'", "require_partition_filter = true"],
+                options.iter().map(ToString::to_string).collect::<Vec<_>>()
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_backtick_dotted_table_name() {
+    // A single backtick-quoted `project.dataset.table` name is split into
+    // its parts, just like the individually quoted form.
+    let expected = ObjectName::from(vec![
+        Ident::with_quote('`', "project"),
+        Ident::with_quote('`', "dataset"),
+        Ident::with_quote('`', "table"),
+    ]);
+    for sql in [
+        "SELECT * FROM `project.dataset.table`",
+        "SELECT * FROM `project`.`dataset`.`table`",
+    ] {
+        let select = bigquery()
+            .verified_only_select_with_canonical(sql, "SELECT * FROM `project`.`dataset`.`table`");
+        assert_eq!(table_from_name(expected.clone()), select.from[0].relation);
+    }
+
+    // Outside of a table name the dotted identifier is kept as a single part
+    let select = bigquery().verified_only_select("SELECT `a.b.c`.x FROM t");
+    assert_eq!(
+        &Expr::CompoundIdentifier(vec![Ident::with_quote('`', "a.b.c"), Ident::new("x")]),
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_nested_array_of_named_struct_type() {
+    let select = bigquery().verified_only_select("SELECT CAST(x AS ARRAY<STRUCT<a INT64>>)");
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::Cast,
+            expr: Box::new(Expr::Identifier(Ident::new("x"))),
+            data_type: DataType::Array(ArrayElemTypeDef::AngleBracket(Box::new(DataType::Struct(
+                vec![StructField {
+                    field_name: Some(Ident::new("a")),
+                    field_type: DataType::Int64,
+                    options: None,
+                }],
+                StructBracketKind::AngleBrackets
+            )))),
+            array: false,
+            format: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    // closing brackets of deeper nesting are tokenized as `>>` / `>>>`
+    bigquery().verified_stmt("SELECT CAST(x AS ARRAY<STRUCT<a INT64, b ARRAY<STRING>>>)");
+    bigquery()
+        .verified_stmt("CREATE TABLE t (x ARRAY<STRUCT<a INT64>>, y STRUCT<a STRUCT<b INT64>>)");
+}
@@ -1845,6 +1845,77 @@ fn parse_inner_array_join() {
     }
 }
 
+#[test]
+fn parse_table_sample_with_offset() {
+    let select =
+        clickhouse().verified_only_select("SELECT * FROM tbl AS t SAMPLE 1 / 10 OFFSET 1 / 2");
+    match &select.from[0].relation {
+        Table {
+            alias,
+            sample: Some(TableSampleKind::AfterTableAlias(sample)),
+            ..
+        } => {
+            assert_eq!(alias.as_ref().unwrap().name, Ident::new("t"));
+            assert_eq!(sample.modifier, TableSampleModifier::Sample);
+            assert_eq!(
+                sample.quantity.as_ref().unwrap().value.to_string(),
+                "1 / 10"
+            );
+            assert_eq!(sample.offset.as_ref().unwrap().to_string(), "1 / 2");
+        }
+        _ => unreachable!(),
+    }
+
+    let select = clickhouse().verified_only_select("SELECT * FROM tbl SAMPLE 10000000 WHERE x = 1");
+    match &select.from[0].relation {
+        Table {
+            sample: Some(TableSampleKind::AfterTableAlias(sample)),
+            ..
+        } => {
+            assert_eq!(
+                sample.quantity.as_ref().unwrap().value,
+                Expr::value(number("10000000"))
+            );
+            assert_eq!(sample.offset, None);
+        }
+        _ => unreachable!(),
+    }
+    assert!(select.selection.is_some());
+}
+
+#[test]
+fn parse_array_join_round_trip_forms() {
+    let select = clickhouse().verified_only_select("SELECT * FROM t ARRAY JOIN a, b AS y");
+    assert_eq!(1, select.from.len());
+    let join = &select.from[0].joins[0];
+    assert_eq!(join.join_operator, JoinOperator::ArrayJoin);
+    match &join.relation {
+        TableFactor::ArrayJoinList(arrays) => match arrays.as_slice() {
+            [TableFactor::Table { name: a, .. }, TableFactor::Table { name: b, alias, .. }] => {
+                assert_eq!(a.to_string(), "a");
+                assert_eq!(b.to_string(), "b");
+                assert_eq!(alias.as_ref().unwrap().name, Ident::new("y"));
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    clickhouse().verified_stmt("SELECT s, x FROM t ARRAY JOIN splitByChar(',', s) AS x");
+    clickhouse().verified_stmt("SELECT * FROM t INNER ARRAY JOIN a AS x, b, c AS z");
+
+    let select = clickhouse().verified_only_select("SELECT * FROM t LEFT ARRAY JOIN a AS x");
+    let join = &select.from[0].joins[0];
+    assert_eq!(join.join_operator, JoinOperator::LeftArrayJoin);
+    match &join.relation {
+        TableFactor::Table { name, alias, .. } => {
+            assert_eq!(name.to_string(), "a");
+            assert_eq!(alias.as_ref().unwrap().name, Ident::new("x"));
+        }
+        _ => unreachable!(),
+    }
+}
+
 fn clickhouse() -> TestedDialects {
     TestedDialects::new(vec![Box::new(ClickHouseDialect {})])
 }
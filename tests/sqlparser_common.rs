@@ -192,7 +192,10 @@ fn parse_replace_into() {
     let sql = "REPLACE INTO public.customer (id, name, active) VALUES (1, 2, 3)";
 
     assert_eq!(
-        ParserError::ParserError("Unsupported statement REPLACE at Line: 1, Column: 9".to_string()),
+        ParserError::ParserErrorWithLocation {
+            message: "Unsupported statement REPLACE".to_string(),
+            location: Location::new(1, 9),
+        },
         Parser::parse_sql(&dialect, sql,).unwrap_err(),
     )
 }
@@ -1470,7 +1473,7 @@ fn parse_null_in_select() {
 
 #[test]
 fn parse_exponent_in_select() -> Result<(), ParserError> {
-    // all except Hive, as it allows numbers to start an identifier
+    // all except Hive and MySQL, as they allow numbers to start an identifier
     let dialects = TestedDialects::new(vec![
         Box::new(AnsiDialect {}),
         Box::new(BigQueryDialect {}),
@@ -1479,7 +1482,7 @@ fn parse_exponent_in_select() -> Result<(), ParserError> {
         Box::new(GenericDialect {}),
         // Box::new(HiveDialect {}),
         Box::new(MsSqlDialect {}),
-        Box::new(MySqlDialect {}),
+        // Box::new(MySqlDialect {}),
         Box::new(PostgreSqlDialect {}),
         Box::new(RedshiftSqlDialect {}),
         Box::new(SnowflakeDialect {}),
@@ -2744,53 +2747,19 @@ fn parse_select_order_by_all() {
 
 #[test]
 fn parse_select_order_by_not_support_all() {
-    fn chk(sql: &str, except_order_by: OrderByKind) {
-        let dialects = all_dialects_where(|d| !d.supports_order_by_all());
-        let select = dialects.verified_query(sql);
+    let dialects = all_dialects_where(|d| !d.supports_order_by_all());
+    for sql in [
+        "SELECT id, ALL FROM customer WHERE id < 5 ORDER BY ALL",
+        "SELECT id, ALL FROM customer ORDER BY ALL ASC NULLS FIRST",
+        "SELECT id, ALL FROM customer ORDER BY ALL DESC NULLS LAST",
+    ] {
         assert_eq!(
-            except_order_by,
-            select.order_by.expect("ORDER BY expected").kind
+            ParserError::ParserError(
+                "Expected: an expression after ORDER BY, found: ALL".to_string()
+            ),
+            dialects.parse_sql_statements(sql).unwrap_err()
         );
     }
-    let test_cases = [
-        (
-            "SELECT id, ALL FROM customer WHERE id < 5 ORDER BY ALL",
-            OrderByKind::Expressions(vec![OrderByExpr {
-                expr: Expr::Identifier(Ident::new("ALL")),
-                options: OrderByOptions {
-                    sort: None,
-                    nulls_first: None,
-                },
-                with_fill: None,
-            }]),
-        ),
-        (
-            "SELECT id, ALL FROM customer ORDER BY ALL ASC NULLS FIRST",
-            OrderByKind::Expressions(vec![OrderByExpr {
-                expr: Expr::Identifier(Ident::new("ALL")),
-                options: OrderByOptions {
-                    sort: Some(OrderBySort::Asc),
-                    nulls_first: Some(true),
-                },
-                with_fill: None,
-            }]),
-        ),
-        (
-            "SELECT id, ALL FROM customer ORDER BY ALL DESC NULLS LAST",
-            OrderByKind::Expressions(vec![OrderByExpr {
-                expr: Expr::Identifier(Ident::new("ALL")),
-                options: OrderByOptions {
-                    sort: Some(OrderBySort::Desc),
-                    nulls_first: Some(false),
-                },
-                with_fill: None,
-            }]),
-        ),
-    ];
-
-    for (sql, expected_order_by) in test_cases {
-        chk(sql, expected_order_by);
-    }
 }
 
 #[test]
@@ -2912,14 +2881,22 @@ fn parse_select_group_by() {
 
 #[test]
 fn parse_select_group_by_all() {
+    let dialects = all_dialects_where(|d| d.supports_group_by_all());
     let sql = "SELECT id, fname, lname, SUM(order) FROM customer GROUP BY ALL";
-    let select = verified_only_select(sql);
+    let select = dialects.verified_only_select(sql);
     assert_eq!(GroupByExpr::All(vec![]), select.group_by);
 
-    one_statement_parses_to(
+    dialects.one_statement_parses_to(
         "SELECT id, fname, lname, SUM(order) FROM customer GROUP BY ALL",
         "SELECT id, fname, lname, SUM(order) FROM customer GROUP BY ALL",
     );
+
+    assert_eq!(
+        ParserError::ParserError("Expected: an expression after GROUP BY, found: ALL".to_string()),
+        all_dialects_where(|d| !d.supports_group_by_all())
+            .parse_sql_statements(sql)
+            .unwrap_err()
+    );
 }
 
 #[test]
@@ -2938,8 +2915,15 @@ fn parse_group_by_with_modifier() {
         vec![GroupByWithModifier::Rollup, GroupByWithModifier::Cube],
     ];
     let dialects = all_dialects_where(|d| d.supports_group_by_with_modifier());
+    let group_by_all_dialects =
+        all_dialects_where(|d| d.supports_group_by_with_modifier() && d.supports_group_by_all());
 
     for clause in &clauses {
+        let dialects = if clause == &"ALL" {
+            &group_by_all_dialects
+        } else {
+            &dialects
+        };
         for (modifier, expected_modifier) in modifiers.iter().zip(expected_modifiers.iter()) {
             let sql = format!("SELECT * FROM t GROUP BY {clause} {modifier}");
             match dialects.verified_stmt(&sql) {
@@ -3073,8 +3057,9 @@ fn parse_select_having() {
 
 #[test]
 fn parse_select_qualify() {
+    let dialects = all_dialects_where(|d| d.supports_qualify_clause());
     let sql = "SELECT i, p, o FROM qt QUALIFY ROW_NUMBER() OVER (PARTITION BY p ORDER BY o) = 1";
-    let select = verified_only_select(sql);
+    let select = dialects.verified_only_select(sql);
     assert_eq!(
         Some(Expr::BinaryOp {
             left: Box::new(Expr::Function(Function {
@@ -3110,7 +3095,7 @@ fn parse_select_qualify() {
     );
 
     let sql = "SELECT i, p, o, ROW_NUMBER() OVER (PARTITION BY p ORDER BY o) AS row_num FROM qt QUALIFY row_num = 1";
-    let select = verified_only_select(sql);
+    let select = dialects.verified_only_select(sql);
     assert_eq!(
         Some(Expr::BinaryOp {
             left: Box::new(Expr::Identifier(Ident::new("row_num"))),
@@ -5436,7 +5421,7 @@ fn run_explain_analyze(
     expected_verbose: bool,
     expected_analyze: bool,
     expected_format: Option<AnalyzeFormatKind>,
-    expected_options: Option<Vec<UtilityOption>>,
+    expected_options: Option<Vec<ExplainOption>>,
 ) {
     match dialect.verified_stmt(query) {
         Statement::Explain {
@@ -6077,9 +6062,10 @@ fn test_parse_named_window() {
 
 #[test]
 fn parse_window_and_qualify_clause() {
-    let dialects = all_dialects_except(|d| {
-        d.is_table_alias(&Keyword::WINDOW, &mut Parser::new(d))
-            || d.is_table_alias(&Keyword::QUALIFY, &mut Parser::new(d))
+    let dialects = all_dialects_where(|d| {
+        d.supports_qualify_clause()
+            && !d.is_table_alias(&Keyword::WINDOW, &mut Parser::new(d))
+            && !d.is_table_alias(&Keyword::QUALIFY, &mut Parser::new(d))
     });
     let sql = "SELECT \
     MIN(c12) OVER window1 AS min1 \
@@ -6298,7 +6284,7 @@ fn parse_interval_all() {
         expr_from_projection(only(&select.projection)),
     );
 
-    let sql = "SELECT INTERVAL '01:01.01' MINUTE (5) TO SECOND (5)";
+    let sql = "SELECT INTERVAL '01:01.01' MINUTE(5) TO SECOND(5)";
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::Interval(Interval {
@@ -6313,7 +6299,7 @@ fn parse_interval_all() {
         expr_from_projection(only(&select.projection)),
     );
 
-    let sql = "SELECT INTERVAL '1' SECOND (5, 4)";
+    let sql = "SELECT INTERVAL '1' SECOND(5, 4)";
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::Interval(Interval {
@@ -6369,7 +6355,7 @@ fn parse_interval_all() {
         expr_from_projection(only(&select.projection)),
     );
 
-    let sql = "SELECT INTERVAL '10' HOUR (1)";
+    let sql = "SELECT INTERVAL '10' HOUR(1)";
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::Interval(Interval {
@@ -8222,6 +8208,7 @@ fn parse_trim() {
             trim_characters: Some(vec![Expr::Value(
                 Value::SingleQuotedString(" ".to_owned()).with_empty_span()
             )]),
+            bare_from: false,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -8238,6 +8225,7 @@ fn parse_trim() {
             trim_characters: Some(vec![Expr::Value(
                 Value::SingleQuotedString("a".to_owned()).with_empty_span()
             )]),
+            bare_from: false,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -10327,7 +10315,7 @@ VALUES (1, 'abc')";
 
     verified_stmt("MERGE INTO target USING source ON target.id = source.id WHEN MATCHED AND source.active = 1 THEN UPDATE SET *");
 
-    verified_stmt("MERGE INTO target USING source ON target.id = source.id WHEN NOT MATCHED BY TARGET THEN INSERT *");
+    all_dialects_where(|d| d.supports_merge_not_matched_by_source_or_target()).verified_stmt("MERGE INTO target USING source ON target.id = source.id WHEN NOT MATCHED BY TARGET THEN INSERT *");
 
     verified_stmt("MERGE INTO target USING source ON target.id = source.id WHEN MATCHED THEN UPDATE SET * WHEN NOT MATCHED THEN INSERT (a, b) VALUES (source.a, source.b)");
 
@@ -10444,7 +10432,7 @@ fn test_lock() {
     assert_eq!(ast.locks.len(), 1);
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Update);
-    assert!(lock.of.is_none());
+    assert!(lock.of.is_empty());
     assert!(lock.nonblock.is_none());
 
     let sql = "SELECT * FROM student WHERE id = '1' FOR SHARE";
@@ -10452,7 +10440,7 @@ fn test_lock() {
     assert_eq!(ast.locks.len(), 1);
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Share);
-    assert!(lock.of.is_none());
+    assert!(lock.of.is_empty());
     assert!(lock.nonblock.is_none());
 }
 
@@ -10464,12 +10452,12 @@ fn test_lock_table() {
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Update);
     assert_eq!(
-        lock.of.unwrap(),
-        ObjectName::from(vec![Ident {
+        lock.of,
+        vec![ObjectName::from(vec![Ident {
             value: "school".to_string(),
             quote_style: None,
             span: Span::empty(),
-        }])
+        }])]
     );
     assert!(lock.nonblock.is_none());
 
@@ -10479,12 +10467,12 @@ fn test_lock_table() {
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Share);
     assert_eq!(
-        lock.of.unwrap(),
-        ObjectName::from(vec![Ident {
+        lock.of,
+        vec![ObjectName::from(vec![Ident {
             value: "school".to_string(),
             quote_style: None,
             span: Span::empty(),
-        }])
+        }])]
     );
     assert!(lock.nonblock.is_none());
 
@@ -10494,23 +10482,23 @@ fn test_lock_table() {
     let lock = ast.locks.remove(0);
     assert_eq!(lock.lock_type, LockType::Share);
     assert_eq!(
-        lock.of.unwrap(),
-        ObjectName::from(vec![Ident {
+        lock.of,
+        vec![ObjectName::from(vec![Ident {
             value: "school".to_string(),
             quote_style: None,
             span: Span::empty(),
-        }])
+        }])]
     );
     assert!(lock.nonblock.is_none());
     let lock = ast.locks.remove(0);
     assert_eq!(lock.lock_type, LockType::Update);
     assert_eq!(
-        lock.of.unwrap(),
-        ObjectName::from(vec![Ident {
+        lock.of,
+        vec![ObjectName::from(vec![Ident {
             value: "student".to_string(),
             quote_style: None,
             span: Span::empty(),
-        }])
+        }])]
     );
     assert!(lock.nonblock.is_none());
 }
@@ -10523,12 +10511,12 @@ fn test_lock_nonblock() {
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Update);
     assert_eq!(
-        lock.of.unwrap(),
-        ObjectName::from(vec![Ident {
+        lock.of,
+        vec![ObjectName::from(vec![Ident {
             value: "school".to_string(),
             quote_style: None,
             span: Span::empty(),
-        }])
+        }])]
     );
     assert_eq!(lock.nonblock.unwrap(), NonBlock::SkipLocked);
 
@@ -10538,12 +10526,12 @@ fn test_lock_nonblock() {
     let lock = ast.locks.pop().unwrap();
     assert_eq!(lock.lock_type, LockType::Share);
     assert_eq!(
-        lock.of.unwrap(),
-        ObjectName::from(vec![Ident {
+        lock.of,
+        vec![ObjectName::from(vec![Ident {
             value: "school".to_string(),
             quote_style: None,
             span: Span::empty(),
-        }])
+        }])]
     );
     assert_eq!(lock.nonblock.unwrap(), NonBlock::Nowait);
 }
@@ -10903,7 +10891,7 @@ fn parse_is_boolean() {
     let res = parse_sql_statements(sql);
     assert_eq!(
         ParserError::ParserError(
-            "Expected: [NOT] NULL | TRUE | FALSE | DISTINCT | [form] NORMALIZED FROM after IS, found: 0"
+            "Expected: [NOT] NULL | TRUE | FALSE | UNKNOWN | DISTINCT | [form] NORMALIZED FROM after IS, found: 0"
                 .to_string()
         ),
         res.unwrap_err()
@@ -10913,7 +10901,7 @@ fn parse_is_boolean() {
     let res = parse_sql_statements(sql);
     assert_eq!(
         ParserError::ParserError(
-            "Expected: [NOT] NULL | TRUE | FALSE | DISTINCT | [form] NORMALIZED FROM after IS, found: XYZ"
+            "Expected: [NOT] NULL | TRUE | FALSE | UNKNOWN | DISTINCT | [form] NORMALIZED FROM after IS, found: XYZ"
                 .to_string()
         ),
         res.unwrap_err()
@@ -10923,7 +10911,7 @@ fn parse_is_boolean() {
     let res = parse_sql_statements(sql);
     assert_eq!(
         ParserError::ParserError(
-            "Expected: [NOT] NULL | TRUE | FALSE | DISTINCT | [form] NORMALIZED FROM after IS, found: FROM"
+            "Expected: [NOT] NULL | TRUE | FALSE | UNKNOWN | DISTINCT | [form] NORMALIZED FROM after IS, found: FROM"
                 .to_string()
         ),
         res.unwrap_err()
@@ -10933,7 +10921,7 @@ fn parse_is_boolean() {
     let res = parse_sql_statements(sql);
     assert_eq!(
         ParserError::ParserError(
-            "Expected: [NOT] NULL | TRUE | FALSE | DISTINCT | [form] NORMALIZED FROM after IS, found: TRIM"
+            "Expected: [NOT] NULL | TRUE | FALSE | UNKNOWN | DISTINCT | [form] NORMALIZED FROM after IS, found: TRIM"
                 .to_string()
         ),
         res.unwrap_err()
@@ -14232,14 +14220,8 @@ fn parse_explain_with_option_list() {
         false,
         None,
         Some(vec![
-            UtilityOption {
-                name: Ident::new("ANALYZE"),
-                arg: Some(Expr::Value((Value::Boolean(false)).with_empty_span())),
-            },
-            UtilityOption {
-                name: Ident::new("VERBOSE"),
-                arg: Some(Expr::Value((Value::Boolean(true)).with_empty_span())),
-            },
+            ExplainOption::Analyze(Some(false)),
+            ExplainOption::Verbose(Some(true)),
         ]),
     );
 
@@ -14250,14 +14232,14 @@ fn parse_explain_with_option_list() {
         false,
         None,
         Some(vec![
-            UtilityOption {
+            ExplainOption::Other(UtilityOption {
                 name: Ident::new("ANALYZE"),
                 arg: Some(Expr::Identifier(Ident::new("ON"))),
-            },
-            UtilityOption {
+            }),
+            ExplainOption::Other(UtilityOption {
                 name: Ident::new("VERBOSE"),
                 arg: Some(Expr::Identifier(Ident::new("OFF"))),
-            },
+            }),
         ]),
     );
 
@@ -14268,24 +14250,24 @@ fn parse_explain_with_option_list() {
         false,
         None,
         Some(vec![
-            UtilityOption {
+            ExplainOption::Other(UtilityOption {
                 name: Ident::new("FORMAT1"),
                 arg: Some(Expr::Identifier(Ident::new("TEXT"))),
-            },
-            UtilityOption {
+            }),
+            ExplainOption::Other(UtilityOption {
                 name: Ident::new("FORMAT2"),
                 arg: Some(Expr::Value(
                     (Value::SingleQuotedString("JSON".to_string())).with_empty_span(),
                 )),
-            },
-            UtilityOption {
+            }),
+            ExplainOption::Other(UtilityOption {
                 name: Ident::new("FORMAT3"),
                 arg: Some(Expr::Identifier(Ident::with_quote('"', "XML"))),
-            },
-            UtilityOption {
+            }),
+            ExplainOption::Other(UtilityOption {
                 name: Ident::new("FORMAT4"),
                 arg: Some(Expr::Identifier(Ident::new("YAML"))),
-            },
+            }),
         ]),
     );
 
@@ -14296,13 +14278,13 @@ fn parse_explain_with_option_list() {
         false,
         None,
         Some(vec![
-            UtilityOption {
+            ExplainOption::Other(UtilityOption {
                 name: Ident::new("NUM1"),
                 arg: Some(Expr::Value(
                     (Value::Number("10".parse().unwrap(), false)).with_empty_span(),
                 )),
-            },
-            UtilityOption {
+            }),
+            ExplainOption::Other(UtilityOption {
                 name: Ident::new("NUM2"),
                 arg: Some(Expr::UnaryOp {
                     op: UnaryOperator::Plus,
@@ -14310,8 +14292,8 @@ fn parse_explain_with_option_list() {
                         (Value::Number("10.1".parse().unwrap(), false)).with_empty_span(),
                     )),
                 }),
-            },
-            UtilityOption {
+            }),
+            ExplainOption::Other(UtilityOption {
                 name: Ident::new("NUM3"),
                 arg: Some(Expr::UnaryOp {
                     op: UnaryOperator::Minus,
@@ -14319,28 +14301,19 @@ fn parse_explain_with_option_list() {
                         (Value::Number("10.2".parse().unwrap(), false)).with_empty_span(),
                     )),
                 }),
-            },
+            }),
         ]),
     );
 
-    let utility_options = vec![
-        UtilityOption {
-            name: Ident::new("ANALYZE"),
-            arg: None,
-        },
-        UtilityOption {
-            name: Ident::new("VERBOSE"),
-            arg: Some(Expr::Value((Value::Boolean(true)).with_empty_span())),
-        },
-        UtilityOption {
+    let explain_options = vec![
+        ExplainOption::Analyze(None),
+        ExplainOption::Verbose(Some(true)),
+        ExplainOption::Other(UtilityOption {
             name: Ident::new("WAL"),
             arg: Some(Expr::Identifier(Ident::new("OFF"))),
-        },
-        UtilityOption {
-            name: Ident::new("FORMAT"),
-            arg: Some(Expr::Identifier(Ident::new("YAML"))),
-        },
-        UtilityOption {
+        }),
+        ExplainOption::Format(ExplainFormat::Yaml),
+        ExplainOption::Other(UtilityOption {
             name: Ident::new("USER_DEF_NUM"),
             arg: Some(Expr::UnaryOp {
                 op: UnaryOperator::Minus,
@@ -14348,7 +14321,7 @@ fn parse_explain_with_option_list() {
                     (Value::Number("100.1".parse().unwrap(), false)).with_empty_span(),
                 )),
             }),
-        },
+        }),
     ];
     run_explain_analyze(
         all_dialects_where(|d| d.supports_explain_with_utility_options()),
@@ -14356,7 +14329,7 @@ fn parse_explain_with_option_list() {
         false,
         false,
         None,
-        Some(utility_options),
+        Some(explain_options),
     );
 }
 
@@ -17747,6 +17720,8 @@ SELECT * FROM tbl2
         trailing_commas: false,
         unescape: true,
         require_semicolon_stmt_delimiter: false,
+        unreserved_keywords: vec![],
+        max_input_length: None,
     });
     let stmts = dialects.parse_sql_statements(sql).unwrap();
     assert_eq!(stmts.len(), 2);
@@ -18951,3 +18926,603 @@ fn parse_non_pg_dialects_keep_xml_names_as_regular_identifiers() {
     let dialects = all_dialects_except(|d| d.supports_xml_expressions());
     dialects.verified_only_select("SELECT xml FROM t");
 }
+
+#[test]
+fn parse_substring_keyword_and_comma_forms() {
+    let x = || Box::new(Expr::Identifier(Ident::new("x")));
+    let num = |n: &str| Some(Box::new(Expr::value(number(n))));
+
+    assert_eq!(
+        verified_expr("SUBSTRING(x FROM 2)"),
+        Expr::Substring {
+            expr: x(),
+            substring_from: num("2"),
+            substring_for: None,
+            special: false,
+            shorthand: false,
+        }
+    );
+    assert_eq!(
+        verified_expr("SUBSTRING(x FROM 2 FOR 3)"),
+        Expr::Substring {
+            expr: x(),
+            substring_from: num("2"),
+            substring_for: num("3"),
+            special: false,
+            shorthand: false,
+        }
+    );
+    assert_eq!(
+        verified_expr("SUBSTRING(x, 2, 3)"),
+        Expr::Substring {
+            expr: x(),
+            substring_from: num("2"),
+            substring_for: num("3"),
+            special: true,
+            shorthand: false,
+        }
+    );
+}
+
+#[test]
+fn parse_trim_keyword_forms() {
+    let col = || Box::new(Expr::Identifier(Ident::new("col")));
+    let x = || {
+        Some(Box::new(Expr::Value(
+            Value::SingleQuotedString("x".to_owned()).with_empty_span(),
+        )))
+    };
+
+    for (keyword, trim_where) in [
+        ("LEADING", TrimWhereField::Leading),
+        ("TRAILING", TrimWhereField::Trailing),
+        ("BOTH", TrimWhereField::Both),
+    ] {
+        assert_eq!(
+            verified_expr(&format!("TRIM({keyword} 'x' FROM col)")),
+            Expr::Trim {
+                expr: col(),
+                trim_where: Some(trim_where),
+                trim_what: x(),
+                trim_characters: None,
+                bare_from: false,
+            }
+        );
+        assert_eq!(
+            verified_expr(&format!("TRIM({keyword} FROM col)")),
+            Expr::Trim {
+                expr: col(),
+                trim_where: Some(trim_where),
+                trim_what: None,
+                trim_characters: None,
+                bare_from: true,
+            }
+        );
+    }
+
+    assert_eq!(
+        verified_expr("TRIM(FROM col)"),
+        Expr::Trim {
+            expr: col(),
+            trim_where: None,
+            trim_what: None,
+            trim_characters: None,
+            bare_from: true,
+        }
+    );
+    verified_expr("TRIM('x' FROM col)");
+    verified_expr("TRIM(BOTH col)");
+    all_dialects_where(|d| d.supports_comma_separated_trim()).verified_expr("TRIM(col, 'x')");
+}
+
+#[test]
+fn parse_position_in_keyword_form() {
+    assert_eq!(
+        verified_expr("POSITION('b' IN 'abc')"),
+        Expr::Position {
+            expr: Box::new(Expr::Value(
+                Value::SingleQuotedString("b".to_owned()).with_empty_span()
+            )),
+            r#in: Box::new(Expr::Value(
+                Value::SingleQuotedString("abc".to_owned()).with_empty_span()
+            )),
+        }
+    );
+
+    // the searched expression binds tighter than `IN`
+    match verified_expr("POSITION(a || b IN UPPER(c))") {
+        Expr::Position { expr, .. } => assert!(matches!(
+            *expr,
+            Expr::BinaryOp {
+                op: BinaryOperator::StringConcat,
+                ..
+            }
+        )),
+        expr => panic!("Expected Position, got: {expr:?}"),
+    }
+    verified_stmt("SELECT POSITION(LOWER(x) IN y) > 0 FROM t");
+}
+
+#[test]
+fn parse_is_boolean_precedence() {
+    use self::Expr::*;
+
+    let a = || Box::new(Identifier(Ident::new("a")));
+    let b = || Box::new(Identifier(Ident::new("b")));
+
+    assert_eq!(
+        UnaryOp {
+            op: UnaryOperator::Not,
+            expr: Box::new(IsTrue(a())),
+        },
+        verified_expr("NOT a IS TRUE")
+    );
+    assert_eq!(
+        IsNotUnknown(Box::new(BinaryOp {
+            left: a(),
+            op: BinaryOperator::Eq,
+            right: b(),
+        })),
+        verified_expr("a = b IS NOT UNKNOWN")
+    );
+    assert_eq!(
+        BinaryOp {
+            left: Box::new(IsFalse(a())),
+            op: BinaryOperator::And,
+            right: Box::new(IsNotFalse(b())),
+        },
+        verified_expr("a IS FALSE AND b IS NOT FALSE")
+    );
+    assert_eq!(
+        BinaryOp {
+            left: Box::new(IsUnknown(a())),
+            op: BinaryOperator::Or,
+            right: Box::new(IsNotTrue(b())),
+        },
+        verified_expr("a IS UNKNOWN OR b IS NOT TRUE")
+    );
+}
+
+#[test]
+fn parse_truncated_statements_do_not_panic() {
+    let statements = [
+        "WITH cte AS (SELECT a, COUNT(*) AS c FROM t GROUP BY a) SELECT * FROM cte WHERE c > 1 ORDER BY a DESC LIMIT 10",
+        "SELECT CASE WHEN a IS NOT NULL THEN CAST(a AS DECIMAL(10, 2)) ELSE -1 END, b[1], f(x => 1) FROM t",
+        "INSERT INTO t (a, b) VALUES (1, 'x'), (2, 'y') ON CONFLICT (a) DO UPDATE SET b = EXCLUDED.b RETURNING *",
+        "CREATE TABLE t (id INT PRIMARY KEY, name VARCHAR(20) NOT NULL DEFAULT 'x', CONSTRAINT c CHECK (id > 0))",
+        "MERGE INTO t USING s ON t.id = s.id WHEN MATCHED THEN UPDATE SET t.a = s.a WHEN NOT MATCHED THEN INSERT (id) VALUES (s.id)",
+        "ALTER TABLE t ADD COLUMN IF NOT EXISTS c INT, DROP CONSTRAINT k, RENAME TO u",
+        "SELECT a FROM t1 JOIN t2 USING (id) LEFT JOIN LATERAL (SELECT 1) AS s ON TRUE WINDOW w AS (PARTITION BY a)",
+        "GRANT SELECT, INSERT ON TABLE t TO ROLE r WITH GRANT OPTION",
+    ];
+    for sql in statements {
+        for (end, _) in sql.char_indices() {
+            for dialect in &all_dialects().dialects {
+                // Only the absence of panics matters here
+                let _ = Parser::parse_sql(&**dialect, &sql[..end]);
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_max_input_length() {
+    let options = ParserOptions::new().with_max_input_length(32);
+    let parse = |sql: &str| {
+        Parser::new(&GenericDialect {})
+            .with_options(options.clone())
+            .try_with_sql(sql)?
+            .parse_statements()
+    };
+
+    assert_eq!(1, parse("SELECT * FROM t WHERE a = 1").unwrap().len());
+
+    let sql = format!("SELECT {}", vec!["a"; 100_000].join(", "));
+    assert_eq!(
+        ParserError::ParserError(format!(
+            "SQL input of {} bytes exceeds the maximum length of 32 bytes",
+            sql.len()
+        )),
+        parse(&sql).unwrap_err()
+    );
+
+    // Unbalanced input is rejected before it is tokenized
+    let sql = "'".repeat(1_000_000);
+    assert!(parse(&sql)
+        .unwrap_err()
+        .to_string()
+        .contains("maximum length"));
+}
+
+#[test]
+fn parse_cte_materialization_hint() {
+    let dialects = all_dialects_where(|d| d.supports_cte_materialization_hint());
+    for (sql, expected) in [
+        (
+            "WITH cte AS MATERIALIZED (SELECT 1) SELECT * FROM cte",
+            Some(CteAsMaterialized::Materialized),
+        ),
+        (
+            "WITH cte AS NOT MATERIALIZED (SELECT 1) SELECT * FROM cte",
+            Some(CteAsMaterialized::NotMaterialized),
+        ),
+        ("WITH cte AS (SELECT 1) SELECT * FROM cte", None),
+    ] {
+        let query = dialects.verified_query(sql);
+        assert_eq!(expected, query.with.unwrap().cte_tables[0].materialized);
+    }
+
+    let dialects = all_dialects_where(|d| !d.supports_cte_materialization_hint());
+    assert_eq!(
+        ParserError::ParserError("Expected: (, found: MATERIALIZED".to_string()),
+        dialects
+            .parse_sql_statements("WITH cte AS MATERIALIZED (SELECT 1) SELECT * FROM cte")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn ident_normalize() {
+    let unquoted = Ident::new("MyTable");
+    let quoted = Ident::with_quote('"', "MyTable");
+
+    let cases: Vec<(&dyn Dialect, &str, &str)> = vec![
+        (&GenericDialect {}, "MyTable", "MyTable"),
+        (&PostgreSqlDialect {}, "mytable", "MyTable"),
+        (&SnowflakeDialect {}, "MYTABLE", "MyTable"),
+        (&MySqlDialect {}, "mytable", "mytable"),
+    ];
+    for (dialect, expected_unquoted, expected_quoted) in cases {
+        assert_eq!(
+            expected_unquoted,
+            unquoted.normalize(dialect),
+            "{dialect:?}"
+        );
+        assert_eq!(expected_quoted, quoted.normalize(dialect), "{dialect:?}");
+    }
+}
+
+#[test]
+fn ident_to_string_with_dialect() {
+    let mysql = MySqlDialect {};
+    let pg = PostgreSqlDialect {};
+
+    let reserved = Ident::new("select");
+    assert_eq!("`select`", reserved.to_string_with_dialect(&mysql));
+    assert_eq!(r#""select""#, reserved.to_string_with_dialect(&pg));
+
+    let special = Ident::new("my col");
+    assert_eq!("`my col`", special.to_string_with_dialect(&mysql));
+    assert_eq!(r#""my col""#, special.to_string_with_dialect(&pg));
+
+    let plain = Ident::new("my_col");
+    assert_eq!("my_col", plain.to_string_with_dialect(&mysql));
+    assert_eq!("my_col", plain.to_string_with_dialect(&pg));
+
+    let quoted = Ident::with_quote('"', "a`b");
+    assert_eq!("`a``b`", quoted.to_string_with_dialect(&mysql));
+    assert_eq!(r#""a`b""#, quoted.to_string_with_dialect(&pg));
+    assert_eq!(
+        r#""order""#,
+        Ident::new("order").to_string_with_dialect(&GenericDialect {})
+    );
+}
+
+#[test]
+fn parse_qualify_clause_support() {
+    let sql = "SELECT i, p FROM qt QUALIFY ROW_NUMBER() OVER (PARTITION BY p ORDER BY o) = 1";
+    let supported = all_dialects_where(|d| d.supports_qualify_clause());
+    let select = supported.verified_only_select(sql);
+    assert!(select.qualify.is_some());
+
+    // QUALIFY follows HAVING and precedes ORDER BY
+    supported.verified_stmt(
+        "SELECT p, COUNT(*) FROM qt GROUP BY p HAVING COUNT(*) > 1 QUALIFY ROW_NUMBER() OVER (ORDER BY p) = 1 ORDER BY p",
+    );
+    supported.verified_stmt(
+        "SELECT * FROM (SELECT i, p FROM qt QUALIFY ROW_NUMBER() OVER (PARTITION BY p ORDER BY i DESC) = 1) AS t",
+    );
+
+    let unsupported = all_dialects_where(|d| !d.supports_qualify_clause());
+    assert_eq!(
+        unsupported.parse_sql_statements(sql).unwrap_err(),
+        ParserError::ParserError("Expected: end of statement, found: QUALIFY".to_string())
+    );
+}
+
+#[test]
+fn parse_interval_to_qualifier_matrix() {
+    for (qualifier, leading_field, last_field) in [
+        ("YEAR TO MONTH", DateTimeField::Year, DateTimeField::Month),
+        ("DAY TO HOUR", DateTimeField::Day, DateTimeField::Hour),
+        ("DAY TO MINUTE", DateTimeField::Day, DateTimeField::Minute),
+        ("DAY TO SECOND", DateTimeField::Day, DateTimeField::Second),
+        ("HOUR TO MINUTE", DateTimeField::Hour, DateTimeField::Minute),
+        ("HOUR TO SECOND", DateTimeField::Hour, DateTimeField::Second),
+        (
+            "MINUTE TO SECOND",
+            DateTimeField::Minute,
+            DateTimeField::Second,
+        ),
+    ] {
+        let sql = format!("SELECT INTERVAL '1' {qualifier}");
+        let select = verified_only_select(&sql);
+        assert_eq!(
+            &Expr::Interval(Interval {
+                value: Box::new(Expr::Value(
+                    (Value::SingleQuotedString(String::from("1"))).with_empty_span()
+                )),
+                leading_field: Some(leading_field),
+                leading_precision: None,
+                last_field: Some(last_field),
+                fractional_seconds_precision: None,
+            }),
+            expr_from_projection(only(&select.projection)),
+        );
+    }
+
+    let select = verified_only_select("SELECT INTERVAL '3 4:05:06.789' DAY(2) TO SECOND(3)");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Interval(interval) => {
+            assert_eq!(interval.leading_field, Some(DateTimeField::Day));
+            assert_eq!(interval.leading_precision, Some(2));
+            assert_eq!(interval.last_field, Some(DateTimeField::Second));
+            assert_eq!(interval.fractional_seconds_precision, Some(3));
+        }
+        expr => panic!("Expected interval, got: {expr:?}"),
+    }
+    one_statement_parses_to(
+        "SELECT INTERVAL '3 4:05:06.789' DAY (2) TO SECOND (3)",
+        "SELECT INTERVAL '3 4:05:06.789' DAY(2) TO SECOND(3)",
+    );
+
+    verified_only_select("SELECT INTERVAL '1-2' YEAR(3) TO MONTH");
+    verified_only_select("SELECT INTERVAL '4:05' HOUR(2) TO MINUTE");
+    verified_only_select("SELECT INTERVAL '05:06.7' MINUTE TO SECOND(1)");
+}
+
+#[test]
+fn parse_listagg_on_overflow_variants() {
+    for (sql, expected) in [
+        (
+            "SELECT LISTAGG(x, ',' ON OVERFLOW ERROR) WITHIN GROUP (ORDER BY y) FROM t",
+            ListAggOnOverflow::Error,
+        ),
+        (
+            "SELECT LISTAGG(x, ',' ON OVERFLOW TRUNCATE '...' WITH COUNT) WITHIN GROUP (ORDER BY y) FROM t",
+            ListAggOnOverflow::Truncate {
+                filler: Some(Box::new(Expr::Value(
+                    (Value::SingleQuotedString("...".to_string())).with_empty_span(),
+                ))),
+                with_count: true,
+            },
+        ),
+        (
+            "SELECT LISTAGG(x, ',' ON OVERFLOW TRUNCATE WITHOUT COUNT) WITHIN GROUP (ORDER BY y) FROM t",
+            ListAggOnOverflow::Truncate {
+                filler: None,
+                with_count: false,
+            },
+        ),
+    ] {
+        let select = verified_only_select(sql);
+        let Expr::Function(Function {
+            args: FunctionArguments::List(FunctionArgumentList { clauses, .. }),
+            within_group,
+            ..
+        }) = expr_from_projection(only(&select.projection))
+        else {
+            panic!("Expected LISTAGG function in {sql}");
+        };
+        assert_eq!(clauses, &vec![FunctionArgumentClause::OnOverflow(expected)]);
+        assert_eq!(within_group.len(), 1);
+    }
+}
+
+#[test]
+fn parse_alter_column_operations_round_trip() {
+    for op in [
+        "SET NOT NULL",
+        "DROP NOT NULL",
+        "SET DEFAULT 'pending'",
+        "SET DEFAULT CURRENT_TIMESTAMP",
+        "SET DEFAULT (1 + 2)",
+        "DROP DEFAULT",
+        "SET DATA TYPE VARCHAR(20)",
+        "TYPE BIGINT",
+    ] {
+        verified_stmt(&format!("ALTER TABLE tab ALTER COLUMN c {op}"));
+    }
+
+    match alter_table_op(verified_stmt(
+        "ALTER TABLE tab ALTER COLUMN c SET DEFAULT lower('X')",
+    )) {
+        AlterTableOperation::AlterColumn { column_name, op } => {
+            assert_eq!("c", column_name.to_string());
+            assert!(matches!(
+                op,
+                AlterColumnOperation::SetDefault {
+                    value: Expr::Function(_)
+                }
+            ));
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_named_window_reference_and_extension() {
+    let dialects = all_dialects_except(|d| d.is_table_alias(&Keyword::WINDOW, &mut Parser::new(d)));
+    let select = dialects.verified_only_select(
+        "SELECT SUM(x) OVER w, SUM(x) OVER (w), SUM(x) OVER (w ORDER BY c) \
+         FROM t WINDOW w AS (PARTITION BY a ORDER BY b)",
+    );
+
+    let over = |i: usize| match expr_from_projection(&select.projection[i]) {
+        Expr::Function(Function { over, .. }) => over.clone().unwrap(),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(WindowType::NamedWindow(Ident::new("w")), over(0));
+    assert_eq!(
+        WindowType::WindowSpec(WindowSpec {
+            window_name: Some(Ident::new("w")),
+            partition_by: vec![],
+            order_by: vec![],
+            window_frame: None,
+        }),
+        over(1)
+    );
+    match over(2) {
+        WindowType::WindowSpec(WindowSpec {
+            window_name,
+            partition_by,
+            order_by,
+            window_frame: None,
+        }) => {
+            assert_eq!(Some(Ident::new("w")), window_name);
+            assert!(partition_by.is_empty());
+            assert_eq!(1, order_by.len());
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_row_value_comparisons() {
+    let tuple = |a: &str, b: &str| {
+        Expr::Tuple(vec![
+            Expr::Identifier(Ident::new(a)),
+            Expr::Identifier(Ident::new(b)),
+        ])
+    };
+
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(tuple("a", "b")),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Tuple(vec![
+                Expr::value(number("1")),
+                Expr::value(number("2")),
+            ])),
+        },
+        verified_expr("(a, b) = (1, 2)")
+    );
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(tuple("a", "b")),
+            op: BinaryOperator::Lt,
+            right: Box::new(tuple("c", "d")),
+        },
+        verified_expr("(a, b) < (c, d)")
+    );
+
+    // a single parenthesized expression is not a row value
+    assert_eq!(
+        Expr::Nested(Box::new(Expr::Identifier(Ident::new("a")))),
+        match verified_expr("(a) = 1") {
+            Expr::BinaryOp { left, .. } => *left,
+            _ => unreachable!(),
+        }
+    );
+
+    verified_stmt("SELECT * FROM t WHERE (a, b) >= (1, 2) AND (a, b, c) <> (x, y, z)");
+}
+
+#[test]
+fn parse_exists_polarities_in_boolean_expressions() {
+    let sql = "SELECT * FROM t WHERE NOT EXISTS (SELECT 1 FROM u) AND EXISTS (SELECT 2 FROM v) OR NOT EXISTS (SELECT 3 FROM w)";
+    let select = verified_only_select(sql);
+    let (and_expr, last) = match select.selection.unwrap() {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::Or,
+            right,
+        } => (*left, *right),
+        _ => unreachable!(),
+    };
+    let (first, second) = match and_expr {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => (*left, *right),
+        _ => unreachable!(),
+    };
+    for (expr, expected_negated) in [(first, true), (second, false), (last, true)] {
+        match expr {
+            Expr::Exists { negated, .. } => assert_eq!(expected_negated, negated),
+            _ => unreachable!(),
+        }
+    }
+
+    // `NOT (EXISTS ...)` stays a unary NOT around a non-negated EXISTS
+    match verified_expr("NOT (EXISTS (SELECT 1))") {
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr,
+        } => match *expr {
+            Expr::Nested(inner) => {
+                assert!(matches!(*inner, Expr::Exists { negated: false, .. }))
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    // The span of an EXISTS expression is that of its subquery
+    let stmt = Parser::parse_sql(
+        &GenericDialect {},
+        "SELECT * FROM t WHERE NOT EXISTS (SELECT a FROM u)",
+    )
+    .unwrap()
+    .pop()
+    .unwrap();
+    let Statement::Query(query) = stmt else {
+        unreachable!()
+    };
+    let selection = query.body.as_select().unwrap().selection.clone().unwrap();
+    let Expr::Exists { subquery, .. } = &selection else {
+        unreachable!()
+    };
+    assert_eq!(
+        Span::new(Location::new(1, 35), Location::new(1, 50)),
+        selection.span()
+    );
+    assert_eq!(subquery.span(), selection.span());
+}
+
+#[test]
+fn parse_table_sample_method_quantity_and_seed() {
+    let dialects = TestedDialects::new(vec![Box::new(GenericDialect {}), Box::new(AnsiDialect {})]);
+    let select = dialects
+        .verified_only_select("SELECT * FROM t TABLESAMPLE SYSTEM (10 PERCENT) REPEATABLE (42)");
+    match &select.from[0].relation {
+        TableFactor::Table {
+            sample: Some(TableSampleKind::AfterTableAlias(sample)),
+            ..
+        } => {
+            assert_eq!(TableSampleModifier::TableSample, sample.modifier);
+            assert_eq!(Some(TableSampleMethod::System), sample.name);
+            assert_eq!(
+                Some(TableSampleQuantity {
+                    parenthesized: true,
+                    value: Expr::value(number("10")),
+                    unit: Some(TableSampleUnit::Percent),
+                }),
+                sample.quantity
+            );
+            assert_eq!(
+                Some(TableSampleSeed {
+                    modifier: TableSampleSeedModifier::Repeatable,
+                    value: number("42").with_empty_span(),
+                }),
+                sample.seed
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    dialects.verified_stmt("SELECT * FROM t TABLESAMPLE BERNOULLI (5 ROWS)");
+    dialects.verified_stmt("SELECT * FROM t AS x TABLESAMPLE BERNOULLI (50) REPEATABLE (1)");
+}
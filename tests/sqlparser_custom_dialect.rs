@@ -156,6 +156,37 @@ fn test_map_syntax_not_support_default() -> Result<(), ParserError> {
     Ok(())
 }
 
+#[test]
+fn custom_delimited_identifier() -> Result<(), ParserError> {
+    #[derive(Debug)]
+    struct MyDialect {}
+
+    impl Dialect for MyDialect {
+        fn is_identifier_start(&self, ch: char) -> bool {
+            is_identifier_start(ch)
+        }
+
+        fn is_identifier_part(&self, ch: char) -> bool {
+            is_identifier_part(ch)
+        }
+
+        fn is_delimited_identifier_start(&self, ch: char) -> bool {
+            ch == '|'
+        }
+    }
+
+    let dialect = MyDialect {};
+    let sql = "SELECT |my col| FROM t";
+    let ast = Parser::parse_sql(&dialect, sql)?;
+    assert_eq!(sql, ast[0].to_string());
+
+    let err = Parser::parse_sql(&dialect, "SELECT 1 + |my col|.").unwrap_err();
+    assert!(err.to_string().contains("found: EOF"), "{err}");
+    let err = Parser::parse_sql(&dialect, "SELECT * FROM |t| |x| |y|").unwrap_err();
+    assert!(err.to_string().contains("found: |y|"), "{err}");
+    Ok(())
+}
+
 fn is_identifier_start(ch: char) -> bool {
     ch.is_ascii_lowercase() || ch.is_ascii_uppercase() || ch == '_'
 }
@@ -490,7 +490,9 @@ fn parse_optimize_table() {
         .verified_stmt("OPTIMIZE my_table WHERE date = '2023-01-01' ZORDER BY (col1, col2)")
     {
         Statement::OptimizeTable {
+            location,
             name,
+            additional_names,
             has_table_keyword,
             on_cluster,
             partition,
@@ -498,8 +500,11 @@ fn parse_optimize_table() {
             deduplicate,
             predicate,
             zorder,
+            ..
         } => {
+            assert!(location.is_none());
             assert_eq!(name.to_string(), "my_table");
+            assert!(additional_names.is_empty());
             assert!(!has_table_keyword);
             assert!(on_cluster.is_none());
             assert!(partition.is_none());
@@ -19,12 +19,12 @@
 mod test_utils;
 
 use helpers::attached_token::AttachedToken;
-use sqlparser::tokenizer::Span;
+use sqlparser::tokenizer::{Location, Span};
 use test_utils::*;
 
 use sqlparser::ast::*;
 use sqlparser::dialect::{DuckDbDialect, GenericDialect};
-use sqlparser::parser::ParserError;
+use sqlparser::parser::{Parser, ParserError};
 
 fn duckdb() -> TestedDialects {
     TestedDialects::new(vec![Box::new(DuckDbDialect {})])
@@ -762,6 +762,8 @@ fn test_duckdb_union_datatype() {
             primary_key: Default::default(),
             order_by: Default::default(),
             partition_by: Default::default(),
+            partition_key: Default::default(),
+            partition_options: None,
             cluster_by: Default::default(),
             clustered_by: Default::default(),
             inherits: Default::default(),
@@ -798,6 +800,7 @@ fn test_duckdb_union_datatype() {
             multiset: Default::default(),
             fallback: Default::default(),
             with_data: Default::default(),
+            duplicate_handling: Default::default(),
         }),
         stmt
     );
@@ -874,6 +877,7 @@ fn test_duckdb_trim() {
             trim_characters: Some(vec![Expr::Value(
                 Value::SingleQuotedString("a".to_owned()).with_empty_span()
             )]),
+            bare_from: false,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -910,3 +914,263 @@ fn test_duckdb_lambda_function() {
     let sql_transform = "SELECT list_transform([1, 2, 3], lambda x : x * 2)";
     duckdb().verified_stmt(sql_transform);
 }
+
+#[test]
+fn test_duckdb_from_first_queries() {
+    let select = duckdb().verified_only_select("FROM t SELECT a");
+    assert_eq!(select.flavor, SelectFlavor::FromFirst);
+    assert_eq!(
+        select.projection,
+        vec![SelectItem::UnnamedExpr(Expr::Identifier(Ident::new("a")))]
+    );
+
+    let select = duckdb().verified_only_select("FROM t");
+    assert_eq!(select.flavor, SelectFlavor::FromFirstNoSelect);
+    assert!(select.projection.is_empty());
+
+    duckdb().verified_stmt("FROM t SELECT a WHERE a > 1 ORDER BY a");
+
+    match duckdb().verified_stmt("CREATE TABLE t2 AS FROM t") {
+        Statement::CreateTable(CreateTable { name, query, .. }) => {
+            assert_eq!(name.to_string(), "t2");
+            let query = query.expect("CTAS query");
+            assert_eq!(
+                query.body.as_select().unwrap().flavor,
+                SelectFlavor::FromFirstNoSelect
+            );
+        }
+        _ => unreachable!(),
+    }
+    duckdb().verified_stmt("CREATE TABLE t2 AS FROM t SELECT a, b");
+}
+
+#[test]
+fn test_duckdb_list_map_and_struct_literals() {
+    let select = duckdb().verified_only_select("SELECT [1, 2], MAP {'a': 1}, {x: 1}");
+    assert_eq!(
+        &Expr::Array(Array {
+            elem: vec![Expr::value(number("1")), Expr::value(number("2"))],
+            named: false,
+        }),
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::Map(Map {
+            entries: vec![MapEntry {
+                key: Box::new(Expr::Value(
+                    (Value::SingleQuotedString("a".to_string())).with_empty_span()
+                )),
+                value: Box::new(Expr::value(number("1"))),
+            }],
+        }),
+        expr_from_projection(&select.projection[1])
+    );
+    assert_eq!(
+        &Expr::Dictionary(vec![DictionaryField {
+            key: Ident::new("x"),
+            value: Box::new(Expr::value(number("1"))),
+        }]),
+        expr_from_projection(&select.projection[2])
+    );
+
+    // nested structures
+    duckdb().verified_stmt("SELECT [[1, 2], [3]]");
+    duckdb().verified_stmt("SELECT {x: 1, y: [1, 2]}");
+    duckdb().verified_stmt("SELECT {'a': {b: MAP {1: [2]}}}");
+}
+
+#[test]
+fn parse_group_by_all_and_order_by_all() {
+    let select =
+        duckdb().verified_query("SELECT city, COUNT(*) FROM addresses GROUP BY ALL ORDER BY ALL");
+    assert_eq!(
+        GroupByExpr::All(vec![]),
+        select.body.as_select().unwrap().group_by
+    );
+    assert_eq!(
+        OrderByKind::All(OrderByOptions {
+            sort: None,
+            nulls_first: None,
+        }),
+        select.order_by.unwrap().kind
+    );
+
+    // The generic dialect enables neither hook
+    let generic = TestedDialects::new(vec![Box::new(GenericDialect {})]);
+    for sql in [
+        "SELECT city, COUNT(*) FROM addresses GROUP BY ALL",
+        "SELECT city FROM addresses ORDER BY ALL",
+    ] {
+        assert!(generic.parse_sql_statements(sql).is_err());
+    }
+}
+
+#[test]
+fn parse_pivot_multiple_aggregates_with_aliases() {
+    let sql = "SELECT * FROM t PIVOT(SUM(x) AS s, AVG(y) AS a FOR k IN (1 AS one, 2 AS two)) AS p";
+    let select = duckdb().verified_only_select(sql);
+    match &select.from[0].relation {
+        TableFactor::Pivot {
+            aggregate_functions,
+            value_source: PivotValueSource::List(values),
+            ..
+        } => {
+            assert_eq!(
+                aggregate_functions
+                    .iter()
+                    .map(|f| (f.expr.to_string(), f.alias.clone()))
+                    .collect::<Vec<_>>(),
+                vec![
+                    ("SUM(x)".to_string(), Some(Ident::new("s"))),
+                    ("AVG(y)".to_string(), Some(Ident::new("a"))),
+                ]
+            );
+            assert_eq!(
+                values
+                    .iter()
+                    .map(|v| (v.expr.to_string(), v.alias.clone()))
+                    .collect::<Vec<_>>(),
+                vec![
+                    ("1".to_string(), Some(Ident::new("one"))),
+                    ("2".to_string(), Some(Ident::new("two"))),
+                ]
+            );
+        }
+        relation => panic!("Expected PIVOT, got: {relation:?}"),
+    }
+
+    duckdb()
+        .verified_stmt("SELECT * FROM t PIVOT(SUM(x), COUNT(*) AS c FOR k IN ('a', 'b' AS bee))");
+}
+
+#[test]
+fn test_duckdb_from_first_with_filter() {
+    let select = duckdb().verified_only_select("FROM t WHERE x > 1");
+    assert_eq!(select.flavor, SelectFlavor::FromFirstNoSelect);
+    assert!(select.projection.is_empty());
+    assert_eq!(
+        select.selection,
+        Some(Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("x"))),
+            op: BinaryOperator::Gt,
+            right: Box::new(Expr::value(number("1"))),
+        })
+    );
+
+    duckdb().verified_stmt("FROM t WHERE x > 1 ORDER BY x LIMIT 5");
+
+    // leading-FROM queries as set operation operands and subqueries
+    match duckdb().verified_stmt("FROM t WHERE a = 1 UNION ALL FROM u WHERE b = 2") {
+        Statement::Query(query) => match *query.body {
+            SetExpr::SetOperation { left, right, .. } => {
+                for side in [left, right] {
+                    assert_eq!(
+                        side.as_select().unwrap().flavor,
+                        SelectFlavor::FromFirstNoSelect
+                    );
+                }
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+    duckdb().verified_stmt("SELECT * FROM (FROM t WHERE x > 1) AS s");
+    duckdb().verified_stmt("SELECT * FROM t WHERE a IN (FROM u SELECT b)");
+}
+
+#[test]
+fn parse_merge_not_matched_by_source_or_target() {
+    match duckdb().verified_stmt(
+        "MERGE INTO t USING s ON t.id = s.id \
+         WHEN NOT MATCHED BY SOURCE THEN DELETE \
+         WHEN NOT MATCHED BY TARGET THEN INSERT (id) VALUES (s.id)",
+    ) {
+        Statement::Merge(Merge { clauses, .. }) => {
+            assert_eq!(
+                vec![
+                    MergeClauseKind::NotMatchedBySource,
+                    MergeClauseKind::NotMatchedByTarget
+                ],
+                clauses.iter().map(|c| c.clause_kind).collect::<Vec<_>>()
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_pivot_statement() {
+    let sql = "PIVOT t ON col USING SUM(x) AS s, AVG(y) AS a";
+    match duckdb_and_generic().verified_stmt(sql) {
+        Statement::Pivot(PivotStatement {
+            table,
+            on,
+            using,
+            group_by,
+            order_by,
+            limit,
+            ..
+        }) => {
+            assert_eq!(table.to_string(), "t");
+            assert_eq!(
+                on,
+                vec![PivotOnColumn {
+                    expr: Expr::Identifier(Ident::new("col")),
+                    values: None,
+                }]
+            );
+            assert_eq!(
+                using
+                    .iter()
+                    .map(|u| (u.expr.to_string(), u.alias.clone()))
+                    .collect::<Vec<_>>(),
+                vec![
+                    ("SUM(x)".to_string(), Some(Ident::new("s"))),
+                    ("AVG(y)".to_string(), Some(Ident::new("a"))),
+                ]
+            );
+            assert!(group_by.is_empty());
+            assert!(order_by.is_empty());
+            assert_eq!(limit, None);
+        }
+        _ => unreachable!(),
+    }
+
+    match duckdb().verified_stmt(
+        "PIVOT cities ON year IN (2000, 2010 AS y2010) USING sum(population) GROUP BY country",
+    ) {
+        Statement::Pivot(PivotStatement { on, group_by, .. }) => {
+            assert_eq!(
+                on[0].values,
+                Some(vec![
+                    ExprWithAlias {
+                        expr: Expr::value(number("2000")),
+                        alias: None,
+                    },
+                    ExprWithAlias {
+                        expr: Expr::value(number("2010")),
+                        alias: Some(Ident::new("y2010")),
+                    },
+                ])
+            );
+            assert_eq!(group_by, vec![Expr::Identifier(Ident::new("country"))]);
+        }
+        _ => unreachable!(),
+    }
+
+    duckdb().verified_stmt("PIVOT t ON col");
+    duckdb().verified_stmt("PIVOT t ON a, b || '_' || c USING max(v) ORDER BY a DESC LIMIT 10");
+    duckdb().verified_stmt("PIVOT (SELECT * FROM t) ON col USING count(*)");
+
+    let stmt = Parser::parse_sql(
+        &DuckDbDialect {},
+        "PIVOT t ON col USING SUM(x) AS s GROUP BY k",
+    )
+    .unwrap()
+    .pop()
+    .unwrap();
+    assert_eq!(
+        stmt.span(),
+        Span::new(Location::new(1, 1), Location::new(1, 44))
+    );
+}
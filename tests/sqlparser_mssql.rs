@@ -1467,7 +1467,8 @@ fn parse_mssql_declare() {
                     hold: None,
                     for_query: None
                 }
-            ]
+            ],
+            separator: DeclareSeparator::Comma,
         }],
         ast
     );
@@ -1487,7 +1488,8 @@ fn parse_mssql_declare() {
                     scroll: None,
                     hold: None,
                     for_query: None
-                }]
+                }],
+                separator: DeclareSeparator::Comma,
             },
             Statement::Set(Set::SingleAssignment {
                 scope: None,
@@ -1979,6 +1981,8 @@ fn parse_create_table_with_valid_options() {
                 primary_key: None,
                 order_by: None,
                 partition_by: None,
+                partition_key: None,
+                partition_options: None,
                 cluster_by: None,
                 clustered_by: None,
                 inherits: None,
@@ -2017,6 +2021,7 @@ fn parse_create_table_with_valid_options() {
                 multiset: None,
                 fallback: None,
                 with_data: None,
+                duplicate_handling: None,
             })
         );
     }
@@ -2159,6 +2164,8 @@ fn parse_create_table_with_identity_column() {
                 primary_key: None,
                 order_by: None,
                 partition_by: None,
+                partition_key: None,
+                partition_options: None,
                 cluster_by: None,
                 clustered_by: None,
                 inherits: None,
@@ -2195,6 +2202,7 @@ fn parse_create_table_with_identity_column() {
                 multiset: None,
                 fallback: None,
                 with_data: None,
+                duplicate_handling: None,
             }),
         );
     }
@@ -2441,6 +2449,8 @@ fn tsql() -> TestedDialects {
             trailing_commas: false,
             unescape: true,
             require_semicolon_stmt_delimiter: false,
+            unreserved_keywords: vec![],
+            max_input_length: None,
         },
     )
 }
@@ -2923,3 +2933,159 @@ fn parse_mssql_money_constants() {
         expr_from_projection(only(&select.projection)),
     );
 }
+
+#[test]
+fn parse_merge_when_not_matched_by_source() {
+    let sql = concat!(
+        "MERGE INTO t USING s ON t.id = s.id ",
+        "WHEN NOT MATCHED BY TARGET THEN INSERT (id) VALUES (s.id) ",
+        "WHEN NOT MATCHED BY SOURCE AND t.active = 0 THEN DELETE"
+    );
+    match ms().verified_stmt(sql) {
+        Statement::Merge(Merge { clauses, .. }) => {
+            assert_eq!(clauses.len(), 2);
+            assert_eq!(clauses[0].clause_kind, MergeClauseKind::NotMatchedByTarget);
+            assert_eq!(clauses[1].clause_kind, MergeClauseKind::NotMatchedBySource);
+            assert!(clauses[1].predicate.is_some());
+            assert!(matches!(clauses[1].action, MergeAction::Delete { .. }));
+        }
+        stmt => panic!("Expected Merge, got: {stmt:?}"),
+    }
+
+    ms().verified_stmt(
+        "MERGE INTO t USING s ON t.id = s.id WHEN NOT MATCHED BY SOURCE THEN UPDATE SET t.active = 0",
+    );
+
+    let unsupported = all_dialects_where(|d| !d.supports_merge_not_matched_by_source_or_target());
+    assert_eq!(
+        unsupported
+            .parse_sql_statements(
+                "MERGE INTO t USING s ON t.id = s.id WHEN NOT MATCHED BY SOURCE THEN DELETE"
+            )
+            .unwrap_err(),
+        ParserError::ParserError("Expected: THEN, found: BY".to_string())
+    );
+}
+
+#[test]
+fn parse_mssql_top_unparenthesized_combinations() {
+    for (sql, percent, with_ties) in [
+        ("SELECT TOP 10 a FROM t ORDER BY a", false, false),
+        ("SELECT TOP 10 PERCENT a FROM t ORDER BY a", true, false),
+        ("SELECT TOP 10 WITH TIES a FROM t ORDER BY a", false, true),
+        (
+            "SELECT TOP 10 PERCENT WITH TIES a FROM t ORDER BY a",
+            true,
+            true,
+        ),
+    ] {
+        let top = ms().verified_only_select(sql).top.unwrap();
+        assert_eq!(Some(TopQuantity::Constant(10)), top.quantity);
+        assert_eq!(percent, top.percent);
+        assert_eq!(with_ties, top.with_ties);
+    }
+
+    let select = ms().verified_only_select("SELECT TOP (@n) PERCENT WITH TIES a FROM t ORDER BY a");
+    let top = select.top.unwrap();
+    assert_eq!(
+        Some(TopQuantity::Expr(Expr::Identifier(Ident::new("@n")))),
+        top.quantity
+    );
+    assert!(top.percent);
+    assert!(top.with_ties);
+}
+
+#[test]
+fn parse_mssql_insert_output_clause_ast() {
+    let sql = "INSERT INTO t (a, b) OUTPUT inserted.a, deleted.b INTO @tbl VALUES (1, 2)";
+    match ms().verified_stmt(sql) {
+        Statement::Insert(Insert {
+            output:
+                Some(OutputClause::Output {
+                    select_items,
+                    into_table: Some(into_table),
+                    ..
+                }),
+            ..
+        }) => {
+            assert_eq!(
+                vec![
+                    SelectItem::UnnamedExpr(Expr::CompoundIdentifier(vec![
+                        Ident::new("inserted"),
+                        Ident::new("a")
+                    ])),
+                    SelectItem::UnnamedExpr(Expr::CompoundIdentifier(vec![
+                        Ident::new("deleted"),
+                        Ident::new("b")
+                    ])),
+                ],
+                select_items
+            );
+            assert_eq!(ObjectName::from(vec![Ident::new("@tbl")]), into_table.name);
+            assert!(!into_table.table);
+        }
+        stmt => panic!("Expected INSERT with OUTPUT, got: {stmt:?}"),
+    }
+
+    ms().verified_stmt("INSERT INTO t (a) OUTPUT inserted.* INTO audit SELECT a FROM s");
+}
+
+#[test]
+fn parse_mssql_bracket_quoted_identifiers() {
+    let select = ms().verified_only_select("SELECT [weird name], [a]]b] FROM [my table]");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::Identifier(Ident::with_quote('[', "weird name"))),
+            SelectItem::UnnamedExpr(Expr::Identifier(Ident::with_quote('[', "a]b"))),
+        ],
+        select.projection
+    );
+    assert_eq!(
+        table_from_name(ObjectName::from(vec![Ident::with_quote('[', "my table")])),
+        select.from[0].relation
+    );
+
+    assert_eq!(Ident::with_quote('[', "x]y").to_string(), "[x]]y]");
+    ms().verified_stmt("SELECT [dbo].[t]]1].[c] FROM [dbo].[t]]1]");
+
+    // `]` is escaped whatever the shape of the value
+    assert_eq!(Ident::with_quote('[', "\"a]\"").to_string(), "[\"a]]\"]");
+    assert_eq!(
+        vec![SelectItem::UnnamedExpr(Expr::Identifier(
+            Ident::with_quote('[', "\"a]\"")
+        ))],
+        ms().verified_only_select("SELECT [\"a]]\"]").projection
+    );
+}
+
+#[test]
+fn parse_mssql_declare_multiple_variables_round_trip() {
+    let sql = "DECLARE @a INT = 1, @b VARCHAR(10) = 'x', @c DATETIME";
+    match ms().verified_stmt(sql) {
+        Statement::Declare { stmts, separator } => {
+            assert_eq!(DeclareSeparator::Comma, separator);
+            assert_eq!(
+                vec!["@a", "@b", "@c"],
+                stmts
+                    .iter()
+                    .map(|stmt| stmt.names[0].value.as_str())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                Some(MsSqlAssignment(Box::new(Expr::value(number("1"))))),
+                stmts[0].assignment
+            );
+            assert_eq!(None, stmts[2].assignment);
+        }
+        _ => unreachable!(),
+    }
+
+    let stmts = ms()
+        .parse_sql_statements("DECLARE @a INT, @b INT; SET @a = @b + 1")
+        .unwrap();
+    assert_eq!(stmts[0].to_string(), "DECLARE @a INT, @b INT");
+    assert_eq!(stmts[1].to_string(), "SET @a = @b + 1");
+
+    // the separator does not depend on the declared names
+    ms().verified_stmt("DECLARE @a INT, c CURSOR FOR SELECT 1");
+}
@@ -25,9 +25,10 @@ use matches::assert_matches;
 use sqlparser::ast::MysqlInsertPriority::{Delayed, HighPriority, LowPriority};
 use sqlparser::ast::*;
 use sqlparser::dialect::{GenericDialect, MySqlDialect};
-use sqlparser::parser::{ParserError, ParserOptions};
-use sqlparser::tokenizer::Span;
+use sqlparser::keywords::Keyword;
+use sqlparser::parser::{Parser, ParserError, ParserOptions};
 use sqlparser::tokenizer::Token;
+use sqlparser::tokenizer::{Location, Span};
 use test_utils::*;
 
 #[macro_use]
@@ -387,16 +388,14 @@ fn parse_show_status() {
         mysql_and_generic().verified_stmt("SHOW SESSION STATUS LIKE 'ssl_cipher'"),
         Statement::ShowStatus {
             filter: Some(ShowStatementFilter::Like("ssl_cipher".into())),
-            session: true,
-            global: false
+            scope: Some(ContextModifier::Session),
         }
     );
     assert_eq!(
         mysql_and_generic().verified_stmt("SHOW GLOBAL STATUS LIKE 'ssl_cipher'"),
         Statement::ShowStatus {
             filter: Some(ShowStatementFilter::Like("ssl_cipher".into())),
-            session: false,
-            global: true
+            scope: Some(ContextModifier::Global),
         }
     );
     assert_eq!(
@@ -405,8 +404,7 @@ fn parse_show_status() {
             filter: Some(ShowStatementFilter::Where(
                 mysql_and_generic().verified_expr("value = 2")
             )),
-            session: false,
-            global: false
+            scope: None,
         }
     );
 }
@@ -1071,7 +1069,7 @@ fn parse_create_table_multiple_options_order_independent() {
 #[test]
 fn parse_create_table_with_all_table_options() {
     let sql =
-        "CREATE TABLE foo (bar INT NOT NULL AUTO_INCREMENT) ENGINE = InnoDB AUTO_INCREMENT = 123 DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_0900_ai_ci INSERT_METHOD = FIRST KEY_BLOCK_SIZE = 8 ROW_FORMAT = DYNAMIC DATA DIRECTORY = '/var/lib/mysql/data' INDEX DIRECTORY = '/var/lib/mysql/index' PACK_KEYS = 1 STATS_AUTO_RECALC = 1 STATS_PERSISTENT = 0 STATS_SAMPLE_PAGES = 128 DELAY_KEY_WRITE = 1 COMPRESSION = 'ZLIB' ENCRYPTION = 'Y' MAX_ROWS = 10000 MIN_ROWS = 10 AUTOEXTEND_SIZE = 64 AVG_ROW_LENGTH = 128 CHECKSUM = 1 CONNECTION = 'mysql://localhost' ENGINE_ATTRIBUTE = 'primary' PASSWORD = 'secure_password' SECONDARY_ENGINE_ATTRIBUTE = 'secondary_attr' START TRANSACTION TABLESPACE my_tablespace STORAGE DISK UNION = (table1, table2, table3)";
+        "CREATE TABLE foo (bar INT NOT NULL AUTO_INCREMENT) ENGINE = InnoDB AUTO_INCREMENT = 123 DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_0900_ai_ci INSERT_METHOD = FIRST KEY_BLOCK_SIZE = 8 ROW_FORMAT = DYNAMIC DATA DIRECTORY = '/var/lib/mysql/data' INDEX DIRECTORY = '/var/lib/mysql/index' PACK_KEYS = 1 STATS_AUTO_RECALC = 1 STATS_PERSISTENT = 0 STATS_SAMPLE_PAGES = 128 DELAY_KEY_WRITE = 1 COMPRESSION = 'ZLIB' ENCRYPTION = 'Y' MAX_ROWS = 10000 MIN_ROWS = 10 AUTOEXTEND_SIZE = 64 AVG_ROW_LENGTH = 128 CHECKSUM = 1 CONNECTION = 'mysql://localhost' ENGINE_ATTRIBUTE = 'primary' PASSWORD = 'secure_password' SECONDARY_ENGINE_ATTRIBUTE = 'secondary_attr' TABLESPACE my_tablespace STORAGE DISK UNION = (table1, table2, table3)";
 
     match mysql().verified_stmt(sql) {
         Statement::CreateTable(CreateTable {
@@ -1182,13 +1180,12 @@ fn parse_create_table_with_all_table_options() {
                 key: Ident::new("SECONDARY_ENGINE_ATTRIBUTE"),
                 value: Expr::value(Value::SingleQuotedString("secondary_attr".to_owned()))
             }));
-            assert!(plain_options.contains(&SqlOption::Ident(Ident::new(
-                "START TRANSACTION".to_owned()
-            ))));
             assert!(
                 plain_options.contains(&SqlOption::TableSpace(TablespaceOption {
-                    name: "my_tablespace".to_string(),
+                    name: Ident::new("my_tablespace"),
                     storage: Some(StorageType::Disk),
+                    file_block_size: None,
+                    file_block_size_eq: false,
                 }))
             );
 
@@ -1506,6 +1503,8 @@ fn parse_escaped_quote_identifiers_with_no_escape() {
                 trailing_commas: false,
                 unescape: false,
                 require_semicolon_stmt_delimiter: true,
+                unreserved_keywords: vec![],
+                max_input_length: None,
             }
         )
         .verified_stmt(sql),
@@ -3965,6 +3964,7 @@ fn parse_grant() {
         objects,
         grantees,
         with_grant_option,
+        with_admin_option: _,
         as_grantor: _,
         granted_by,
         current_grants: _,
@@ -3978,9 +3978,9 @@ fn parse_grant() {
         );
         assert_eq!(
             objects,
-            Some(GrantObjects::Tables(vec![ObjectName::from(vec![
-                "*".into(),
-                "*".into()
+            Some(GrantObjects::Tables(vec![ObjectName(vec![
+                ObjectNamePart::Wildcard(AttachedToken::empty()),
+                ObjectNamePart::Wildcard(AttachedToken::empty()),
             ])]))
         );
         assert!(!with_grant_option);
@@ -4022,9 +4022,9 @@ fn parse_revoke() {
         );
         assert_eq!(
             objects,
-            Some(GrantObjects::Tables(vec![ObjectName::from(vec![
-                "db1".into(),
-                "*".into()
+            Some(GrantObjects::Tables(vec![ObjectName(vec![
+                ObjectNamePart::Identifier("db1".into()),
+                ObjectNamePart::Wildcard(AttachedToken::empty()),
             ])]))
         );
         if let [Grantee {
@@ -4900,3 +4900,1411 @@ fn parse_adjacent_string_literal_concatenation() {
     let sql = r#"SELECT 'M' "y" 'S' "q" 'l'"#;
     mysql().one_statement_parses_to(sql, r"SELECT 'MySql'");
 }
+
+#[test]
+fn parse_extract_compound_units() {
+    for (sql_field, field) in [
+        ("SECOND_MICROSECOND", DateTimeField::SecondMicrosecond),
+        ("MINUTE_MICROSECOND", DateTimeField::MinuteMicrosecond),
+        ("MINUTE_SECOND", DateTimeField::MinuteSecond),
+        ("HOUR_MICROSECOND", DateTimeField::HourMicrosecond),
+        ("HOUR_SECOND", DateTimeField::HourSecond),
+        ("HOUR_MINUTE", DateTimeField::HourMinute),
+        ("DAY_MICROSECOND", DateTimeField::DayMicrosecond),
+        ("DAY_SECOND", DateTimeField::DaySecond),
+        ("DAY_MINUTE", DateTimeField::DayMinute),
+        ("DAY_HOUR", DateTimeField::DayHour),
+        ("YEAR_MONTH", DateTimeField::YearMonth),
+    ] {
+        assert_eq!(
+            mysql().verified_expr(&format!("EXTRACT({sql_field} FROM ts)")),
+            Expr::Extract {
+                field,
+                syntax: ExtractSyntax::From,
+                expr: Box::new(Expr::Identifier(Ident::new("ts"))),
+            }
+        );
+    }
+
+    mysql().verified_stmt("SELECT DATE_ADD(d, INTERVAL '1-2' YEAR_MONTH)");
+}
+
+#[test]
+fn parse_lock_tables_invalid_lock_type() {
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected: one of READ, WRITE, LOW_PRIORITY, found: SHARE".to_string()
+        ),
+        mysql()
+            .parse_sql_statements("LOCK TABLES trans AS t SHARE")
+            .unwrap_err()
+    );
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected: one of READ, WRITE, LOW_PRIORITY, found: EOF".to_string()
+        ),
+        mysql()
+            .parse_sql_statements("LOCK TABLES trans AS t")
+            .unwrap_err()
+    );
+    assert_eq!(
+        ParserError::ParserError("Expected: WRITE, found: READ".to_string()),
+        mysql()
+            .parse_sql_statements("LOCK TABLES trans LOW_PRIORITY READ")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_unreserved_keywords_option() {
+    let sql = "SELECT * FROM t use";
+    assert!(mysql().parse_sql_statements(sql).is_err());
+
+    let dialects = TestedDialects::new_with_options(
+        vec![Box::new(MySqlDialect {})],
+        ParserOptions::new().with_unreserved_keywords(vec![Keyword::USE]),
+    );
+    let select = dialects.verified_only_select(sql);
+    match only(select.from).relation {
+        TableFactor::Table {
+            alias: Some(alias), ..
+        } => assert_eq!(Ident::new("use"), alias.name),
+        other => panic!("Expected table with alias, got: {other:?}"),
+    }
+
+    // `USE INDEX` hints are still parsed without the option
+    mysql().verified_stmt("SELECT * FROM t USE INDEX (i)");
+}
+
+#[test]
+fn parse_mysql_reserved_column_alias() {
+    // `FOR` starts a locking clause rather than a column alias
+    mysql().verified_stmt("SELECT 1 FOR UPDATE");
+    mysql().verified_stmt("SELECT a, b FROM t WHERE a = 1 FOR SHARE");
+
+    // `WINDOW` is a bare column alias in other dialects, but reserved in MySQL
+    let sql = "SELECT 1 window";
+    let select = TestedDialects::new(vec![Box::new(GenericDialect {})])
+        .verified_only_select_with_canonical(sql, "SELECT 1 AS window");
+    match only(&select.projection) {
+        SelectItem::ExprWithAlias { alias, .. } => assert_eq!(&Ident::new("window"), alias),
+        other => panic!("Expected aliased expression, got: {other:?}"),
+    }
+    assert!(mysql().parse_sql_statements(sql).is_err());
+
+    // An explicit `AS` still allows it
+    mysql().verified_stmt("SELECT 1 AS window");
+}
+
+#[test]
+fn parse_collate_in_order_by_and_comparison() {
+    let query = mysql().verified_query("SELECT name FROM t ORDER BY name COLLATE utf8mb4_bin DESC");
+    let order_by = query.order_by.unwrap();
+    let OrderByKind::Expressions(exprs) = order_by.kind else {
+        panic!("Expected ORDER BY expressions");
+    };
+    assert_eq!(
+        Expr::Collate {
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            collation: ObjectName::from(vec![Ident::new("utf8mb4_bin")]),
+        },
+        only(exprs).expr
+    );
+
+    let select =
+        mysql().verified_only_select("SELECT * FROM t WHERE name COLLATE utf8mb4_bin = 'a'");
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Collate {
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                collation: ObjectName::from(vec![Ident::new("utf8mb4_bin")]),
+            }),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::value(Value::SingleQuotedString("a".to_string()))),
+        },
+        select.selection.unwrap()
+    );
+
+    let select =
+        mysql().verified_only_select("SELECT * FROM t WHERE name = 'a' COLLATE utf8mb4_bin");
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("name"))),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Collate {
+                expr: Box::new(Expr::value(Value::SingleQuotedString("a".to_string()))),
+                collation: ObjectName::from(vec![Ident::new("utf8mb4_bin")]),
+            }),
+        },
+        select.selection.unwrap()
+    );
+}
+
+#[test]
+fn parse_null_safe_equal() {
+    let select = mysql().verified_only_select("SELECT * FROM t WHERE a <=> NULL");
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            op: BinaryOperator::Spaceship,
+            right: Box::new(Expr::value(Value::Null)),
+        },
+        select.selection.unwrap()
+    );
+
+    // Same precedence as `=`, binding tighter than `AND`
+    let select = mysql().verified_only_select("SELECT a <=> b AND NOT c <=> NULL");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Spaceship,
+                right: Box::new(Expr::Identifier(Ident::new("b"))),
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("c"))),
+                    op: BinaryOperator::Spaceship,
+                    right: Box::new(Expr::value(Value::Null)),
+                }),
+            }),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_bitwise_operators_precedence() {
+    fn ident(name: &str) -> Box<Expr> {
+        Box::new(Expr::Identifier(Ident::new(name)))
+    }
+    fn binary(left: Box<Expr>, op: BinaryOperator, right: Box<Expr>) -> Box<Expr> {
+        Box::new(Expr::BinaryOp { left, op, right })
+    }
+    fn unary(op: UnaryOperator, expr: Box<Expr>) -> Box<Expr> {
+        Box::new(Expr::UnaryOp { op, expr })
+    }
+    let two = Box::new(Expr::value(number("2")));
+
+    // `^` binds tightest, then `<<`/`>>`, then `&`, then `|`
+    let select = mysql().verified_only_select("SELECT a & b | c ^ d << 2");
+    assert_eq!(
+        &*binary(
+            binary(ident("a"), BinaryOperator::BitwiseAnd, ident("b")),
+            BinaryOperator::BitwiseOr,
+            binary(
+                binary(ident("c"), BinaryOperator::BitwiseXor, ident("d")),
+                BinaryOperator::PGBitwiseShiftLeft,
+                two.clone(),
+            ),
+        ),
+        expr_from_projection(only(&select.projection))
+    );
+
+    let select = mysql().verified_only_select("SELECT a | b & c >> 2");
+    assert_eq!(
+        &*binary(
+            ident("a"),
+            BinaryOperator::BitwiseOr,
+            binary(
+                ident("b"),
+                BinaryOperator::BitwiseAnd,
+                binary(ident("c"), BinaryOperator::PGBitwiseShiftRight, two),
+            ),
+        ),
+        expr_from_projection(only(&select.projection))
+    );
+
+    // Unlike most dialects, `^` binds tighter than `*`
+    let select = mysql().verified_only_select("SELECT a * b ^ c");
+    assert_eq!(
+        &*binary(
+            ident("a"),
+            BinaryOperator::Multiply,
+            binary(ident("b"), BinaryOperator::BitwiseXor, ident("c")),
+        ),
+        expr_from_projection(only(&select.projection))
+    );
+
+    // Unary `~` binds tighter than `^`
+    let select = mysql().verified_only_select("SELECT ~a ^ b");
+    assert_eq!(
+        &*binary(
+            unary(UnaryOperator::BitwiseNot, ident("a")),
+            BinaryOperator::BitwiseXor,
+            ident("b"),
+        ),
+        expr_from_projection(only(&select.projection))
+    );
+
+    // ... as does unary `-`
+    let select = mysql().verified_only_select("SELECT -a ^ b");
+    assert_eq!(
+        &*binary(
+            unary(UnaryOperator::Minus, ident("a")),
+            BinaryOperator::BitwiseXor,
+            ident("b"),
+        ),
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_mod_operator_and_function() {
+    let modulo = |op: BinaryOperator| Expr::BinaryOp {
+        left: Box::new(Expr::Identifier(Ident::new("a"))),
+        op,
+        right: Box::new(Expr::Identifier(Ident::new("b"))),
+    };
+
+    let select = mysql().verified_only_select("SELECT a % b");
+    assert_eq!(
+        &modulo(BinaryOperator::Modulo),
+        expr_from_projection(only(&select.projection))
+    );
+
+    let select = mysql().verified_only_select("SELECT a MOD b");
+    assert_eq!(
+        &modulo(BinaryOperator::MyModulo),
+        expr_from_projection(only(&select.projection))
+    );
+
+    let select = mysql().verified_only_select("SELECT MOD(a, b)");
+    assert_matches!(
+        expr_from_projection(only(&select.projection)),
+        Expr::Function(Function { name, .. }) if name.to_string() == "MOD"
+    );
+
+    // `MOD` has the same precedence as `*`
+    let select = mysql().verified_only_select("SELECT c + a MOD b");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("c"))),
+            op: BinaryOperator::Plus,
+            right: Box::new(modulo(BinaryOperator::MyModulo)),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_create_table_tablespace_options() {
+    mysql().verified_stmt("CREATE TABLE t (a INT) TABLESPACE ts STORAGE DISK");
+    mysql().verified_stmt("CREATE TABLE t (a INT) TABLESPACE ts STORAGE MEMORY ENGINE = NDB");
+    mysql().verified_stmt("CREATE TABLE t (a INT) TABLESPACE ts FILE_BLOCK_SIZE 4096");
+
+    let sql =
+        "CREATE TABLE t (a INT) TABLESPACE ts STORAGE DISK FILE_BLOCK_SIZE = 8192 ENGINE = InnoDB";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            table_options: CreateTableOptions::Plain(options),
+            ..
+        }) => {
+            assert_eq!(
+                SqlOption::TableSpace(TablespaceOption {
+                    name: Ident::new("ts"),
+                    storage: Some(StorageType::Disk),
+                    file_block_size: Some(Expr::value(number("8192"))),
+                    file_block_size_eq: true,
+                }),
+                options[0]
+            );
+            // ENGINE stays a table option of its own
+            assert_eq!(2, options.len());
+            assert_eq!(Some(TableEngine::InnoDB), options[1].table_engine());
+        }
+        other => panic!("Expected CREATE TABLE, got: {other:?}"),
+    }
+
+    mysql().one_statement_parses_to(
+        "CREATE TABLE t (a INT) TABLESPACE = ts FILE_BLOCK_SIZE 4096 ENGINE InnoDB",
+        "CREATE TABLE t (a INT) TABLESPACE ts FILE_BLOCK_SIZE 4096 ENGINE = InnoDB",
+    );
+}
+
+#[test]
+fn parse_create_table_engine_name_round_trip() {
+    for engine in ["InnoDB", "MyISAM", "ndbcluster", "`InnoDB`", "'MyISAM'"] {
+        let sql = format!("CREATE TABLE t (a INT) ENGINE = {engine}");
+        match mysql().verified_stmt(&sql) {
+            Statement::CreateTable(CreateTable {
+                table_options: CreateTableOptions::Plain(options),
+                ..
+            }) => match only(options) {
+                SqlOption::NamedParenthesizedList(NamedParenthesizedList {
+                    key,
+                    name: Some(name),
+                    values,
+                }) => {
+                    assert_eq!(Ident::new("ENGINE"), key);
+                    assert_eq!(engine, name.to_string());
+                    assert!(values.is_empty());
+                }
+                other => panic!("Expected ENGINE option, got: {other:?}"),
+            },
+            other => panic!("Expected CREATE TABLE, got: {other:?}"),
+        }
+    }
+
+    mysql().one_statement_parses_to(
+        "CREATE TABLE t (a INT) ENGINE MyISAM",
+        "CREATE TABLE t (a INT) ENGINE = MyISAM",
+    );
+}
+
+#[test]
+fn parse_create_table_table_engine() {
+    let table_engine = |sql: &str| match mysql().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            table_options: CreateTableOptions::Plain(options),
+            ..
+        }) => only(options).table_engine(),
+        other => panic!("Expected CREATE TABLE, got: {other:?}"),
+    };
+
+    for (engine, expected) in [
+        ("InnoDB", TableEngine::InnoDB),
+        ("innodb", TableEngine::InnoDB),
+        ("MyISAM", TableEngine::MyISAM),
+        ("MEMORY", TableEngine::Memory),
+        ("Archive", TableEngine::Archive),
+        ("CSV", TableEngine::CSV),
+        ("ndbcluster", TableEngine::NDB),
+        ("RocksDB", TableEngine::Other("RocksDB".to_string())),
+    ] {
+        let sql = format!("CREATE TABLE t (a INT) ENGINE = {engine}");
+        assert_eq!(Some(expected), table_engine(&sql));
+    }
+
+    assert_eq!("InnoDB", TableEngine::from_name("INNODB").to_string());
+    assert_eq!("MEMORY", TableEngine::from_name("heap").to_string());
+    assert_eq!("RocksDB", TableEngine::from_name("RocksDB").to_string());
+
+    assert_eq!(
+        None,
+        table_engine("CREATE TABLE t (a INT) AUTO_INCREMENT = 1")
+    );
+}
+
+#[test]
+fn parse_create_table_invalid_compression_and_encryption() {
+    mysql().verified_stmt("CREATE TABLE t (a INT) COMPRESSION = 'lz4' ENCRYPTION = 'N'");
+    mysql().verified_stmt("CREATE TABLE t (a INT) COMPRESSION = 'NONE'");
+
+    assert_eq!(
+        ParserError::ParserError(
+            "invalid COMPRESSION value 'XYZ', expected one of ZLIB, LZ4, NONE".to_string()
+        ),
+        mysql()
+            .parse_sql_statements("CREATE TABLE t (a INT) COMPRESSION = 'XYZ'")
+            .unwrap_err()
+    );
+    assert_eq!(
+        ParserError::ParserError(
+            "invalid COMPRESSION value 'GZIP', expected one of ZLIB, LZ4, NONE".to_string()
+        ),
+        mysql()
+            .parse_sql_statements("CREATE TABLE t (a INT) COMPRESSION GZIP")
+            .unwrap_err()
+    );
+    assert_eq!(
+        ParserError::ParserError(
+            "invalid ENCRYPTION value 'YES', expected one of Y, N".to_string()
+        ),
+        mysql()
+            .parse_sql_statements("CREATE TABLE t (a INT) ENCRYPTION = 'YES'")
+            .unwrap_err()
+    );
+    assert_eq!(
+        ParserError::ParserError("invalid ENCRYPTION value '1', expected one of Y, N".to_string()),
+        mysql()
+            .parse_sql_statements("CREATE TABLE t (a INT) ENCRYPTION = 1")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_table_plain_options_round_trip() {
+    let options = [
+        "COMMENT = 'it''s a table'",
+        "COMMENT 'no equals'",
+        "ENGINE = InnoDB",
+        "TABLESPACE 'my ts' STORAGE MEMORY FILE_BLOCK_SIZE = 8192",
+        "ENGINE = NDB",
+        "UNION = (t1, t2)",
+        "DEFAULT CHARSET = utf8mb4",
+        "CHARSET = utf8mb4",
+        "DEFAULT CHARACTER SET = utf8mb4",
+        "CHARACTER SET = utf8mb4",
+        "DEFAULT COLLATE = utf8mb4_bin",
+        "COLLATE = utf8mb4_bin",
+        "DATA DIRECTORY = '/var/lib/data'",
+        "INDEX DIRECTORY = '/var/lib/index'",
+        "KEY_BLOCK_SIZE = 8",
+        "ROW_FORMAT = DYNAMIC",
+        "PACK_KEYS = DEFAULT",
+        "STATS_AUTO_RECALC = 1",
+        "STATS_PERSISTENT = 0",
+        "STATS_SAMPLE_PAGES = 128",
+        "DELAY_KEY_WRITE = 1",
+        "COMPRESSION = 'LZ4'",
+        "ENCRYPTION = 'N'",
+        "MAX_ROWS = 10000",
+        "MIN_ROWS = 10",
+        "AUTOEXTEND_SIZE = 64",
+        "AVG_ROW_LENGTH = 128",
+        "CHECKSUM = 1",
+        "CONNECTION = 'mysql://localhost'",
+        "ENGINE_ATTRIBUTE = '{\"a\": 1}'",
+        "PASSWORD = 'it''s secret'",
+        "SECONDARY_ENGINE_ATTRIBUTE = 'secondary'",
+        "INSERT_METHOD = LAST",
+        "AUTO_INCREMENT = 5",
+    ];
+
+    let sql = format!("CREATE TABLE t (a INT) {}", options.join(" "));
+    let stmt = mysql().verified_stmt(&sql);
+    match &stmt {
+        Statement::CreateTable(CreateTable {
+            table_options: CreateTableOptions::Plain(plain_options),
+            ..
+        }) => assert_eq!(options.len(), plain_options.len()),
+        other => panic!("Expected CREATE TABLE, got: {other:?}"),
+    }
+    assert_eq!(
+        stmt,
+        mysql().verified_stmt(&stmt.to_string()),
+        "reprinted SQL should parse to an equal AST"
+    );
+}
+
+#[test]
+fn parse_handler_statements() {
+    assert_eq!(
+        Statement::Handler {
+            handler_token: AttachedToken::empty(),
+            table_name: ObjectName::from(vec![Ident::new("db"), Ident::new("t")]),
+            alias: Some(Ident::new("h")),
+            op: HandlerOp::Open,
+        },
+        mysql().verified_stmt("HANDLER db.t OPEN AS h")
+    );
+    mysql().verified_stmt("HANDLER t OPEN");
+    mysql().one_statement_parses_to("HANDLER t OPEN h", "HANDLER t OPEN AS h");
+
+    assert_eq!(
+        Statement::Handler {
+            handler_token: AttachedToken::empty(),
+            table_name: ObjectName::from(vec![Ident::new("h")]),
+            alias: None,
+            op: HandlerOp::Read {
+                index: Some(Ident::with_quote('`', "PRIMARY")),
+                read: HandlerRead::Compare {
+                    op: BinaryOperator::GtEq,
+                    values: vec![
+                        Expr::value(number("1")),
+                        Expr::value(Value::SingleQuotedString("a".to_string())),
+                    ],
+                },
+                selection: Some(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("b"))),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::value(number("2"))),
+                }),
+                limit: Some(LimitClause::LimitOffset {
+                    limit: Some(Expr::value(number("10"))),
+                    offset: None,
+                    limit_by: vec![],
+                }),
+            },
+        },
+        mysql().verified_stmt("HANDLER h READ `PRIMARY` >= (1, 'a') WHERE b = 2 LIMIT 10")
+    );
+    mysql().verified_stmt("HANDLER h READ idx = (1)");
+    mysql().verified_stmt("HANDLER h READ idx <= (1, 2)");
+    mysql().verified_stmt("HANDLER h READ idx < (1) LIMIT 1, 5");
+    mysql().verified_stmt("HANDLER h READ idx > (1)");
+
+    for read in ["FIRST", "NEXT", "PREV", "LAST"] {
+        mysql().verified_stmt(&format!("HANDLER h READ idx {read}"));
+    }
+    match mysql().verified_stmt("HANDLER h READ NEXT WHERE a > 1 LIMIT 5") {
+        Statement::Handler {
+            op:
+                HandlerOp::Read {
+                    index: None,
+                    read: HandlerRead::Next,
+                    ..
+                },
+            ..
+        } => {}
+        other => panic!("Expected HANDLER READ NEXT without index, got: {other:?}"),
+    }
+    mysql().verified_stmt("HANDLER h READ FIRST");
+    // An index that happens to be named `first`
+    mysql().verified_stmt("HANDLER h READ first NEXT");
+
+    assert_eq!(
+        Statement::Handler {
+            handler_token: AttachedToken::empty(),
+            table_name: ObjectName::from(vec![Ident::new("h")]),
+            alias: None,
+            op: HandlerOp::Close,
+        },
+        mysql().verified_stmt("HANDLER h CLOSE")
+    );
+
+    assert_eq!(
+        ParserError::ParserError("Expected: one of OPEN, READ, CLOSE, found: FOO".to_string()),
+        mysql().parse_sql_statements("HANDLER h FOO").unwrap_err()
+    );
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected: one of =, <=, >=, <, >, FIRST, NEXT, PREV, LAST, found: EOF".to_string()
+        ),
+        mysql()
+            .parse_sql_statements("HANDLER h READ idx")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_table_start_transaction_option() {
+    let sql = "CREATE TABLE t (a INT) START TRANSACTION AS SELECT 1";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            table_options: CreateTableOptions::Plain(options),
+            query: Some(_),
+            ..
+        }) => assert_eq!(
+            vec![SqlOption::Ident(Ident::new("START TRANSACTION"))],
+            options
+        ),
+        other => panic!("Expected CREATE TABLE ... SELECT, got: {other:?}"),
+    }
+    mysql().one_statement_parses_to(
+        "CREATE TABLE t (a INT) ENGINE = InnoDB START TRANSACTION SELECT 1",
+        "CREATE TABLE t (a INT) ENGINE = InnoDB START TRANSACTION AS SELECT 1",
+    );
+
+    assert_eq!(
+        ParserError::ParserError(
+            "START TRANSACTION is only allowed in CREATE TABLE ... SELECT".to_string()
+        ),
+        mysql()
+            .parse_sql_statements("CREATE TABLE t (a INT) START TRANSACTION")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_optimize_table() {
+    mysql().verified_stmt("OPTIMIZE TABLE t1");
+    mysql().verified_stmt("OPTIMIZE NO_WRITE_TO_BINLOG TABLE db.t1");
+
+    match mysql().verified_stmt("OPTIMIZE LOCAL TABLE t1, db.t2") {
+        Statement::OptimizeTable {
+            location,
+            name,
+            additional_names,
+            has_table_keyword,
+            ..
+        } => {
+            assert_eq!(Some(FlushLocation::Local), location);
+            assert_eq!(ObjectName::from(vec![Ident::new("t1")]), name);
+            assert_eq!(
+                vec![ObjectName::from(vec![Ident::new("db"), Ident::new("t2")])],
+                additional_names
+            );
+            assert!(has_table_keyword);
+        }
+        other => panic!("Expected OPTIMIZE TABLE, got: {other:?}"),
+    }
+
+    assert_eq!(
+        ParserError::ParserError("Expected: TABLE, found: t1".to_string()),
+        mysql().parse_sql_statements("OPTIMIZE t1").unwrap_err()
+    );
+}
+
+#[test]
+fn parse_rename_table_swap() {
+    match mysql().verified_stmt("RENAME TABLE a TO tmp, b TO a, tmp TO b") {
+        Statement::RenameTable(rename_tables) => {
+            let table = |name: &str| ObjectName::from(vec![Ident::new(name)]);
+            assert_eq!(
+                vec![
+                    RenameTable {
+                        old_name: table("a"),
+                        new_name: table("tmp"),
+                    },
+                    RenameTable {
+                        old_name: table("b"),
+                        new_name: table("a"),
+                    },
+                    RenameTable {
+                        old_name: table("tmp"),
+                        new_name: table("b"),
+                    },
+                ],
+                rename_tables
+            );
+        }
+        other => panic!("Expected RENAME TABLE, got: {other:?}"),
+    }
+}
+
+#[test]
+fn parse_create_table_select_duplicate_handling() {
+    match mysql().verified_stmt("CREATE TABLE t (extra INT) IGNORE AS SELECT a, b FROM s") {
+        Statement::CreateTable(CreateTable {
+            columns,
+            duplicate_handling,
+            query,
+            ..
+        }) => {
+            assert_eq!(
+                vec!["extra"],
+                columns
+                    .iter()
+                    .map(|c| c.name.to_string())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                Some(CreateTableDuplicateHandling::Ignore),
+                duplicate_handling
+            );
+            assert_eq!("SELECT a, b FROM s", query.unwrap().to_string());
+        }
+        other => panic!("Expected CREATE TABLE ... SELECT, got: {other:?}"),
+    }
+    match mysql().verified_stmt("CREATE TABLE t (id INT PRIMARY KEY) REPLACE AS SELECT id FROM s") {
+        Statement::CreateTable(CreateTable {
+            duplicate_handling, ..
+        }) => assert_eq!(
+            Some(CreateTableDuplicateHandling::Replace),
+            duplicate_handling
+        ),
+        other => panic!("Expected CREATE TABLE ... SELECT, got: {other:?}"),
+    }
+    mysql().one_statement_parses_to(
+        "CREATE TABLE t ENGINE = InnoDB REPLACE SELECT 1",
+        "CREATE TABLE t ENGINE = InnoDB REPLACE AS SELECT 1",
+    );
+
+    assert_eq!(
+        ParserError::ParserError("Expected: AS or SELECT, found: EOF".to_string()),
+        mysql()
+            .parse_sql_statements("CREATE TABLE t (a INT) IGNORE")
+            .unwrap_err()
+    );
+    assert!(TestedDialects::new(vec![Box::new(GenericDialect {})])
+        .parse_sql_statements("CREATE TABLE t (a INT) IGNORE AS SELECT 1")
+        .is_err());
+}
+
+#[test]
+fn parse_insert_set_on_duplicate_key_update() {
+    let sql = "INSERT INTO t SET a = 1, b = 2 ON DUPLICATE KEY UPDATE b = VALUES(b) + 1";
+    match mysql().verified_stmt(sql) {
+        Statement::Insert(Insert {
+            source,
+            assignments,
+            on: Some(OnInsert::DuplicateKeyUpdate(updates)),
+            ..
+        }) => {
+            assert!(source.is_none());
+            assert_eq!(
+                vec![
+                    Assignment {
+                        target: AssignmentTarget::ColumnName(ObjectName::from(vec![Ident::new(
+                            "a"
+                        )])),
+                        value: Expr::value(number("1")),
+                    },
+                    Assignment {
+                        target: AssignmentTarget::ColumnName(ObjectName::from(vec![Ident::new(
+                            "b"
+                        )])),
+                        value: Expr::value(number("2")),
+                    },
+                ],
+                assignments
+            );
+            assert_eq!(1, updates.len());
+        }
+        other => panic!("Expected INSERT ... SET, got: {other:?}"),
+    }
+    mysql()
+        .verified_stmt("INSERT INTO t SET a = 1, b = 2 AS new ON DUPLICATE KEY UPDATE b = new.b");
+
+    assert!(TestedDialects::new(vec![Box::new(GenericDialect {})])
+        .parse_sql_statements("INSERT INTO t SET a = 1")
+        .is_err());
+}
+
+#[test]
+fn parse_check_table() {
+    mysql().verified_stmt("CHECK TABLE t");
+    mysql().verified_stmt("CHECK TABLE t1, db.t2 QUICK");
+    mysql().verified_stmt("CHECK TABLE t MEDIUM CHANGED");
+
+    match mysql().verified_stmt("CHECK TABLE t FOR UPGRADE") {
+        Statement::CheckTable { names, options, .. } => {
+            assert_eq!(vec![ObjectName::from(vec![Ident::new("t")])], names);
+            assert_eq!(vec![CheckTableOption::ForUpgrade], options);
+        }
+        other => panic!("Expected CHECK TABLE, got: {other:?}"),
+    }
+    match mysql().verified_stmt("CHECK TABLE t FAST EXTENDED") {
+        Statement::CheckTable { options, .. } => assert_eq!(
+            vec![CheckTableOption::Fast, CheckTableOption::Extended],
+            options
+        ),
+        other => panic!("Expected CHECK TABLE, got: {other:?}"),
+    }
+}
+
+#[test]
+fn parse_repair_table() {
+    mysql().verified_stmt("REPAIR TABLE t");
+    mysql().verified_stmt("REPAIR NO_WRITE_TO_BINLOG TABLE t1, t2 USE_FRM");
+
+    match mysql().verified_stmt("REPAIR TABLE t QUICK EXTENDED") {
+        Statement::RepairTable {
+            location,
+            names,
+            options,
+            ..
+        } => {
+            assert_eq!(None, location);
+            assert_eq!(vec![ObjectName::from(vec![Ident::new("t")])], names);
+            assert_eq!(
+                vec![RepairTableOption::Quick, RepairTableOption::Extended],
+                options
+            );
+        }
+        other => panic!("Expected REPAIR TABLE, got: {other:?}"),
+    }
+    match mysql().verified_stmt("REPAIR LOCAL TABLE t QUICK EXTENDED USE_FRM") {
+        Statement::RepairTable {
+            location, options, ..
+        } => {
+            assert_eq!(Some(FlushLocation::Local), location);
+            assert_eq!(
+                vec![
+                    RepairTableOption::Quick,
+                    RepairTableOption::Extended,
+                    RepairTableOption::UseFrm
+                ],
+                options
+            );
+        }
+        other => panic!("Expected REPAIR TABLE, got: {other:?}"),
+    }
+
+    assert_eq!(
+        ParserError::ParserError("Expected: end of statement, found: QUICK".to_string()),
+        mysql()
+            .parse_sql_statements("REPAIR TABLE t EXTENDED QUICK")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_set_mixed_variable_assignments() {
+    let sql = "SET @a = 1, @@b = 2, @@SESSION.c = 3, GLOBAL d = 4, e = 5";
+    match mysql().verified_stmt(sql) {
+        Statement::Set(Set::MultipleAssignments { assignments }) => {
+            let assignment = |scope, name: Vec<&str>, value| SetAssignment {
+                scope,
+                name: ObjectName::from(name.into_iter().map(Ident::new).collect::<Vec<_>>()),
+                value: Expr::value(number(value)),
+            };
+            assert_eq!(
+                vec![
+                    assignment(None, vec!["@a"], "1"),
+                    assignment(None, vec!["@@b"], "2"),
+                    assignment(None, vec!["@@SESSION", "c"], "3"),
+                    assignment(Some(ContextModifier::Global), vec!["d"], "4"),
+                    assignment(None, vec!["e"], "5"),
+                ],
+                assignments
+            );
+        }
+        other => panic!("Expected SET with multiple assignments, got: {other:?}"),
+    }
+    mysql().verified_stmt("SET SESSION a = 1, LOCAL @@b = 'x', @c = (SELECT 1)");
+
+    assert!(mysql().parse_sql_statements("SET @a = 1, b = 2,").is_err());
+}
+
+#[test]
+fn parse_numeric_prefix_identifiers_and_numbers() {
+    let projection = |sql: &str| mysql().verified_only_select(sql).projection;
+    // Exponent literals don't round-trip textually with the `bigdecimal`
+    // feature, so only the parsed AST is compared for those.
+    let parsed_projection = |sql: &str| match mysql().parse_sql_statements(sql).unwrap().pop() {
+        Some(Statement::Query(query)) => query.body.as_select().unwrap().projection.clone(),
+        _ => unreachable!(),
+    };
+    let ident = |name: &str| SelectItem::UnnamedExpr(Expr::Identifier(Ident::new(name)));
+    let value = |value: Value| SelectItem::UnnamedExpr(Expr::value(value));
+
+    assert_eq!(
+        vec![
+            value(number("1e5")),
+            value(number("1.5e3")),
+            value(number("12e-3")),
+            value(number("1e+3")),
+        ],
+        parsed_projection("SELECT 1e5, 1.5e3, 12e-3, 1e+3")
+    );
+    assert_eq!(
+        vec![
+            ident("1st_place"),
+            ident("123abc"),
+            ident("1e"),
+            ident("1ea")
+        ],
+        projection("SELECT 1st_place, 123abc, 1e, 1ea")
+    );
+    assert_eq!(
+        vec![
+            value(Value::HexStringLiteral("1F".to_string())),
+            ident("0x1G"),
+            ident("0x"),
+        ],
+        mysql()
+            .verified_only_select_with_canonical("SELECT 0x1F, 0x1G, 0x", "SELECT X'1F', 0x1G, 0x")
+            .projection
+    );
+    // An exponent ends the number, so what follows is an alias
+    assert_eq!(
+        vec![SelectItem::ExprWithAlias {
+            expr: Expr::value(number("1e5")),
+            alias: Ident::new("a"),
+        }],
+        parsed_projection("SELECT 1e5a")
+    );
+}
+
+#[test]
+fn parse_floating_point_precision_and_scale() {
+    let sql = "CREATE TABLE t (a FLOAT(10,2), b DOUBLE(16,4), c REAL, d DOUBLE PRECISION, e FLOAT(7), f DOUBLE(16,4) UNSIGNED)";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            let types: Vec<DataType> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                types,
+                vec![
+                    DataType::Float(ExactNumberInfo::PrecisionAndScale(10, 2)),
+                    DataType::Double(ExactNumberInfo::PrecisionAndScale(16, 4)),
+                    DataType::Real,
+                    DataType::DoublePrecision,
+                    DataType::Float(ExactNumberInfo::Precision(7)),
+                    DataType::DoubleUnsigned(ExactNumberInfo::PrecisionAndScale(16, 4)),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_bool_and_boolean_spellings() {
+    // MySQL treats BOOL and BOOLEAN as TINYINT(1), but the spelling is preserved
+    let sql = "CREATE TABLE t (a BOOL, b BOOLEAN, c TINYINT(1))";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            let types: Vec<DataType> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                types,
+                vec![
+                    DataType::Bool,
+                    DataType::Boolean,
+                    DataType::TinyInt(Some(1))
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+    mysql().verified_stmt("ALTER TABLE t MODIFY COLUMN a BOOL DEFAULT false");
+}
+
+#[test]
+fn parse_fixed_point_unsigned_and_zerofill() {
+    let sql = "CREATE TABLE t (a DECIMAL UNSIGNED, b DECIMAL(10), c NUMERIC(10,2) UNSIGNED ZEROFILL, d NUMERIC UNSIGNED, e DEC(5,1) UNSIGNED, f DECIMAL(10,2) ZEROFILL, g INT(5) ZEROFILL)";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            let types: Vec<DataType> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                types,
+                vec![
+                    DataType::DecimalUnsigned(ExactNumberInfo::None),
+                    DataType::Decimal(ExactNumberInfo::Precision(10)),
+                    DataType::Zerofill(Box::new(DataType::NumericUnsigned(
+                        ExactNumberInfo::PrecisionAndScale(10, 2)
+                    ))),
+                    DataType::NumericUnsigned(ExactNumberInfo::None),
+                    DataType::DecUnsigned(ExactNumberInfo::PrecisionAndScale(5, 1)),
+                    DataType::Zerofill(Box::new(DataType::Decimal(
+                        ExactNumberInfo::PrecisionAndScale(10, 2)
+                    ))),
+                    DataType::Zerofill(Box::new(DataType::Int(Some(5)))),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql().verified_stmt("CREATE TABLE t (a DECIMAL(10,2) UNSIGNED ZEROFILL NOT NULL DEFAULT 0)");
+}
+
+#[test]
+fn parse_temporal_fractional_seconds_precision() {
+    let sql = "CREATE TABLE t (a DATETIME(6), b TIME(3), c TIMESTAMP(6) DEFAULT CURRENT_TIMESTAMP(6) ON UPDATE CURRENT_TIMESTAMP(6), d DATETIME, e TIME, f TIMESTAMP)";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            let types: Vec<DataType> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                types,
+                vec![
+                    DataType::Datetime(Some(6)),
+                    DataType::Time(Some(3), TimezoneInfo::None),
+                    DataType::Timestamp(Some(6), TimezoneInfo::None),
+                    DataType::Datetime(None),
+                    DataType::Time(None, TimezoneInfo::None),
+                    DataType::Timestamp(None, TimezoneInfo::None),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql().verified_expr("CAST(x AS DATETIME(6))");
+    mysql().verified_expr("CAST(x AS TIME(3))");
+}
+
+#[test]
+fn parse_column_default_and_on_update_with_precision() {
+    let sql = "CREATE TABLE t (ts TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6) ON UPDATE CURRENT_TIMESTAMP(6))";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            assert_eq!(
+                vec![ColumnDef {
+                    name: Ident::new("ts"),
+                    data_type: DataType::Timestamp(Some(6), TimezoneInfo::None),
+                    options: vec![
+                        ColumnOptionDef {
+                            name: None,
+                            option: ColumnOption::NotNull,
+                        },
+                        ColumnOptionDef {
+                            name: None,
+                            option: ColumnOption::Default(call(
+                                "CURRENT_TIMESTAMP",
+                                [Expr::value(number("6"))]
+                            )),
+                        },
+                        ColumnOptionDef {
+                            name: None,
+                            option: ColumnOption::OnUpdate(call(
+                                "CURRENT_TIMESTAMP",
+                                [Expr::value(number("6"))]
+                            )),
+                        },
+                    ],
+                }],
+                columns
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql().verified_stmt(
+        "CREATE TABLE t (dt DATETIME(3) DEFAULT NOW(3) ON UPDATE LOCALTIMESTAMP(3))",
+    );
+}
+
+#[test]
+fn parse_unlock_tables_round_trip_and_span() {
+    let stmt = mysql().one_statement_parses_to("unlock   tables", "UNLOCK TABLES");
+    assert!(matches!(stmt, Statement::UnlockTables { .. }));
+
+    let stmts = Parser::parse_sql(&MySqlDialect {}, "unlock   tables").unwrap();
+    assert_eq!(
+        stmts[0].span(),
+        Span::new(Location::new(1, 1), Location::new(1, 16))
+    );
+
+    let stmts = Parser::parse_sql(&MySqlDialect {}, "SELECT 1; UNLOCK TABLES").unwrap();
+    assert_eq!(
+        stmts[1].span(),
+        Span::new(Location::new(1, 11), Location::new(1, 24))
+    );
+}
+
+#[test]
+fn parse_show_grants_and_show_create_user() {
+    let user_host = GranteeName::UserHost {
+        user: Ident::with_quote('\'', "u"),
+        host: Ident::with_quote('\'', "h"),
+    };
+
+    assert_eq!(
+        mysql().verified_stmt("SHOW GRANTS"),
+        Statement::ShowGrants {
+            show_token: AttachedToken::empty(),
+            for_user: None,
+            using: vec![],
+        }
+    );
+    assert_eq!(
+        mysql().verified_stmt("SHOW GRANTS FOR 'u'@'h'"),
+        Statement::ShowGrants {
+            show_token: AttachedToken::empty(),
+            for_user: Some(user_host.clone()),
+            using: vec![],
+        }
+    );
+    assert_eq!(
+        mysql().verified_stmt("SHOW GRANTS FOR 'u'@'h' USING r1, 'r2'@'%'"),
+        Statement::ShowGrants {
+            show_token: AttachedToken::empty(),
+            for_user: Some(user_host.clone()),
+            using: vec![
+                GranteeName::ObjectName(ObjectName::from(vec![Ident::new("r1")])),
+                GranteeName::UserHost {
+                    user: Ident::with_quote('\'', "r2"),
+                    host: Ident::with_quote('\'', "%"),
+                },
+            ],
+        }
+    );
+    mysql().verified_stmt("SHOW GRANTS FOR CURRENT_USER");
+
+    assert_eq!(
+        mysql().verified_stmt("SHOW CREATE USER 'u'@'h'"),
+        Statement::ShowCreateUser {
+            show_token: AttachedToken::empty(),
+            user: user_host,
+        }
+    );
+    mysql().verified_stmt("SHOW CREATE USER admin");
+    mysql().verified_stmt("SHOW CREATE TABLE t");
+}
+
+#[test]
+fn parse_show_table_status_and_open_tables() {
+    mysql().verified_stmt("SHOW TABLE STATUS");
+    mysql().verified_stmt("SHOW TABLE STATUS IN db");
+    mysql().verified_stmt("SHOW TABLE STATUS WHERE Engine = 'InnoDB'");
+    mysql().verified_stmt("SHOW OPEN TABLES");
+    mysql().verified_stmt("SHOW OPEN TABLES FROM db LIKE 't%'");
+
+    match mysql().verified_stmt("SHOW TABLE STATUS FROM db LIKE 't%'") {
+        Statement::ShowTableStatus {
+            show_in, filter, ..
+        } => {
+            assert_eq!(
+                Some(ShowStatementIn {
+                    clause: ShowStatementInClause::FROM,
+                    parent_type: None,
+                    parent_name: Some(ObjectName::from(vec![Ident::new("db")])),
+                }),
+                show_in
+            );
+            assert_eq!(Some(ShowStatementFilter::Like("t%".to_string())), filter);
+        }
+        _ => unreachable!(),
+    }
+
+    match mysql().verified_stmt("SHOW OPEN TABLES IN db WHERE In_use > 0") {
+        Statement::ShowOpenTables {
+            show_in, filter, ..
+        } => {
+            assert_eq!(
+                Some(ShowStatementInClause::IN),
+                show_in.map(|show_in| show_in.clause)
+            );
+            assert!(matches!(filter, Some(ShowStatementFilter::Where(_))));
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_show_variables_scope_matches_set_scope() {
+    let set_scope = match mysql_and_generic().verified_stmt("SET GLOBAL sql_mode = ''") {
+        Statement::Set(Set::SingleAssignment { scope, .. }) => scope,
+        _ => unreachable!(),
+    };
+    assert_eq!(Some(ContextModifier::Global), set_scope);
+
+    match mysql_and_generic().verified_stmt("SHOW GLOBAL VARIABLES LIKE 'sql_mode'") {
+        Statement::ShowVariables { filter, scope, .. } => {
+            assert_eq!(set_scope, scope);
+            assert_eq!(Some(ShowStatementFilter::Like("sql_mode".into())), filter);
+        }
+        _ => unreachable!(),
+    }
+
+    match mysql_and_generic().verified_stmt("SHOW SESSION VARIABLES") {
+        Statement::ShowVariables { scope, .. } => {
+            assert_eq!(Some(ContextModifier::Session), scope)
+        }
+        _ => unreachable!(),
+    }
+
+    match mysql_and_generic().verified_stmt("SHOW VARIABLES") {
+        Statement::ShowVariables { scope, .. } => assert_eq!(None, scope),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_row_constructor_in_list() {
+    let select = mysql().verified_only_select("SELECT * FROM t WHERE (a, b) IN ((1, 2), (3, 4))");
+    match select.selection {
+        Some(Expr::InList {
+            expr,
+            list,
+            negated: false,
+        }) => {
+            assert_eq!(
+                Expr::Tuple(vec![
+                    Expr::Identifier(Ident::new("a")),
+                    Expr::Identifier(Ident::new("b")),
+                ]),
+                *expr
+            );
+            assert_eq!(
+                vec![
+                    Expr::Tuple(vec![Expr::value(number("1")), Expr::value(number("2")),]),
+                    Expr::Tuple(vec![Expr::value(number("3")), Expr::value(number("4")),]),
+                ],
+                list
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql().verified_stmt("SELECT * FROM t WHERE (a, b) NOT IN ((1, 2))");
+    mysql().verified_stmt("SELECT * FROM t WHERE (a, b) IN (SELECT x, y FROM u)");
+}
+
+#[test]
+fn parse_quantified_subquery_comparisons() {
+    for (op, compare_op) in [
+        (">", BinaryOperator::Gt),
+        ("=", BinaryOperator::Eq),
+        ("<>", BinaryOperator::NotEq),
+    ] {
+        for quantifier in ["ANY", "SOME", "ALL"] {
+            let sql = format!("SELECT * FROM t WHERE x {op} {quantifier}(SELECT y FROM u)");
+            let select = mysql().verified_only_select(&sql);
+            match (quantifier, select.selection.unwrap()) {
+                (
+                    "ANY" | "SOME",
+                    Expr::AnyOp {
+                        compare_op: actual,
+                        right,
+                        is_some,
+                        ..
+                    },
+                ) => {
+                    assert_eq!(compare_op, actual);
+                    assert_eq!(quantifier == "SOME", is_some);
+                    assert!(matches!(*right, Expr::Subquery(_)));
+                }
+                (
+                    "ALL",
+                    Expr::AllOp {
+                        compare_op: actual,
+                        right,
+                        ..
+                    },
+                ) => {
+                    assert_eq!(compare_op, actual);
+                    assert!(matches!(*right, Expr::Subquery(_)));
+                }
+                _ => unreachable!(),
+            }
+
+            mysql().one_statement_parses_to(
+                &format!("SELECT * FROM t WHERE x {op} {quantifier} (SELECT y FROM u)"),
+                &sql,
+            );
+        }
+    }
+}
+
+#[test]
+fn parse_cast_and_convert_to_binary() {
+    let select = mysql().verified_only_select(
+        "SELECT CAST(x AS BINARY), CAST(x AS BINARY(16)), CONVERT(x, BINARY), CONVERT(x, BINARY(4)), CAST(x AS CHAR(4))",
+    );
+    let data_types: Vec<Option<DataType>> = select
+        .projection
+        .iter()
+        .map(|item| match item {
+            SelectItem::UnnamedExpr(Expr::Cast { data_type, .. }) => Some(data_type.clone()),
+            SelectItem::UnnamedExpr(Expr::Convert { data_type, .. }) => data_type.clone(),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            Some(DataType::Binary(None)),
+            Some(DataType::Binary(Some(16))),
+            Some(DataType::Binary(None)),
+            Some(DataType::Binary(Some(4))),
+            Some(DataType::Char(Some(CharacterLength::IntegerLength {
+                length: 4,
+                unit: None
+            }))),
+        ],
+        data_types
+    );
+}
+
+#[test]
+fn parse_grant_object_name_wildcards() {
+    let objects = |sql: &str| match mysql_and_generic().verified_stmt(sql) {
+        Statement::Grant(Grant {
+            objects: Some(GrantObjects::Tables(objects)),
+            ..
+        }) => objects,
+        _ => unreachable!(),
+    };
+
+    assert_eq!(
+        vec![ObjectName(vec![
+            ObjectNamePart::Identifier(Ident::new("db")),
+            ObjectNamePart::Wildcard(AttachedToken::empty()),
+        ])],
+        objects("GRANT SELECT ON db.* TO u")
+    );
+    assert_eq!(
+        vec![ObjectName(vec![
+            ObjectNamePart::Wildcard(AttachedToken::empty()),
+            ObjectNamePart::Wildcard(AttachedToken::empty()),
+        ])],
+        objects("GRANT SELECT ON *.* TO u")
+    );
+    assert_eq!(
+        vec![ObjectName::from(vec![Ident::new("db"), Ident::new("tbl")])],
+        objects("GRANT SELECT ON db.tbl TO u")
+    );
+
+    // wildcards are only accepted where explicitly requested
+    assert!(mysql_and_generic()
+        .parse_sql_statements("SELECT * FROM db.*")
+        .is_err());
+}
+
+#[test]
+fn parse_object_name_mixed_quoting_round_trip() {
+    let select = mysql().verified_only_select("SELECT * FROM `db`.tbl JOIN db2.`t 2` ON 1 = 1");
+    match &select.from[0].relation {
+        TableFactor::Table { name, .. } => assert_eq!(
+            &ObjectName::from(vec![Ident::with_quote('`', "db"), Ident::new("tbl")]),
+            name
+        ),
+        _ => unreachable!(),
+    }
+    match &select.from[0].joins[0].relation {
+        TableFactor::Table { name, .. } => assert_eq!(
+            &ObjectName::from(vec![Ident::new("db2"), Ident::with_quote('`', "t 2")]),
+            name
+        ),
+        _ => unreachable!(),
+    }
+
+    mysql().verified_stmt("INSERT INTO db.`tbl` (`a`, b) VALUES (1, 2)");
+    mysql().verified_stmt("CREATE TABLE `db`.tbl (a INT)");
+}
+
+#[test]
+fn parse_create_table_partition_options() {
+    let sql = "CREATE TABLE t (a INT) ENGINE = InnoDB PARTITION BY RANGE (a) (\
+        PARTITION p0 VALUES LESS THAN (10) DATA DIRECTORY = '/data/p0' INDEX DIRECTORY = '/index/p0', \
+        PARTITION p1 VALUES LESS THAN MAXVALUE)";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            partition_options: Some(partition_options),
+            ..
+        }) => {
+            assert_eq!(
+                PartitionFunction::Range(Box::new(Expr::Identifier(Ident::new("a")))),
+                partition_options.function
+            );
+            assert_eq!(None, partition_options.partitions);
+            assert_eq!(
+                vec![
+                    PartitionDefinition {
+                        name: Ident::new("p0"),
+                        values: Some(PartitionValues::LessThan(vec![Expr::value(number("10"))])),
+                        options: vec![
+                            SqlOption::KeyValue {
+                                key: Ident::new("DATA DIRECTORY"),
+                                value: Expr::value(Value::SingleQuotedString(
+                                    "/data/p0".to_string()
+                                )),
+                            },
+                            SqlOption::KeyValue {
+                                key: Ident::new("INDEX DIRECTORY"),
+                                value: Expr::value(Value::SingleQuotedString(
+                                    "/index/p0".to_string()
+                                )),
+                            },
+                        ],
+                    },
+                    PartitionDefinition {
+                        name: Ident::new("p1"),
+                        values: Some(PartitionValues::LessThanMaxValue),
+                        options: vec![],
+                    },
+                ],
+                partition_options.definitions
+            );
+        }
+        other => panic!("Expected CREATE TABLE with partitions, got: {other:?}"),
+    }
+
+    mysql().verified_stmt(
+        "CREATE TABLE t (a INT, b INT) PARTITION BY LIST COLUMNS (a, b) (\
+        PARTITION p0 VALUES IN ((1, 2), (3, 4)) COMMENT = 'first' DATA DIRECTORY = '/d' MAX_ROWS = 100 TABLESPACE ts)",
+    );
+    mysql().verified_stmt("CREATE TABLE t (a INT) PARTITION BY LIST (a) (PARTITION p0 VALUES IN (1, 2) ENGINE = InnoDB)");
+    mysql().verified_stmt(
+        "CREATE TABLE t (a INT) PARTITION BY RANGE COLUMNS (a) (PARTITION p0 VALUES LESS THAN (5) INDEX DIRECTORY = '/i')",
+    );
+    mysql().verified_stmt("CREATE TABLE t (a INT) PARTITION BY HASH (a) PARTITIONS 4");
+    mysql().verified_stmt("CREATE TABLE t (a INT) PARTITION BY LINEAR HASH (YEAR(a)) PARTITIONS 4");
+    mysql().verified_stmt("CREATE TABLE t (a INT PRIMARY KEY) PARTITION BY KEY () PARTITIONS 2");
+    mysql().verified_stmt(
+        "CREATE TABLE t (a INT) PARTITION BY LINEAR KEY (a) (PARTITION p0, PARTITION p1)",
+    );
+
+    assert_eq!(
+        ParserError::ParserError("Expected: one of HASH or KEY, found: RANGE".to_string()),
+        mysql()
+            .parse_sql_statements("CREATE TABLE t (a INT) PARTITION BY LINEAR RANGE (a)")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_zerofill_requires_numeric_type() {
+    for sql in [
+        "CREATE TABLE t (a VARCHAR(10) ZEROFILL)",
+        "CREATE TABLE t (a DATE ZEROFILL)",
+    ] {
+        assert!(mysql().parse_sql_statements(sql).is_err(), "{sql}");
+    }
+    mysql().verified_stmt("CREATE TABLE t (a DOUBLE(16,4) UNSIGNED ZEROFILL, b BIGINT ZEROFILL)");
+}
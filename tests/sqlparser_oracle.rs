@@ -27,7 +27,7 @@ use sqlparser::{
     },
     dialect::OracleDialect,
     parser::ParserError,
-    tokenizer::Span,
+    tokenizer::{Location, Span},
 };
 use test_utils::{all_dialects_where, expr_from_projection, number, TestedDialects};
 
@@ -215,29 +215,33 @@ fn parse_invalid_quote_delimited_strings() {
     // invalid quote delimiter
     for q in [' ', '\t', '\r', '\n'] {
         assert_eq!(
-            dialect.parse_sql_statements(&format!("SELECT Q'{q}abc{q}' FROM dual")),
-            Err(ParserError::TokenizerError(
-                "Invalid space, tab, newline, or EOF after 'Q'' at Line: 1, Column: 10".into()
-            )),
+            dialect
+                .parse_sql_statements(&format!("SELECT Q'{q}abc{q}' FROM dual"))
+                .unwrap_err()
+                .to_string(),
+            "sql parser error: Invalid space, tab, newline, or EOF after 'Q'' at Line: 1, Column: 10",
             "with quote char {q:?}"
         );
     }
     // invalid eof after quote
     assert_eq!(
-        dialect.parse_sql_statements("SELECT Q'"),
-        Err(ParserError::TokenizerError(
-            "Invalid space, tab, newline, or EOF after 'Q'' at Line: 1, Column: 10".into()
-        )),
+        dialect
+            .parse_sql_statements("SELECT Q'")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Invalid space, tab, newline, or EOF after 'Q'' at Line: 1, Column: 10",
         "with EOF quote char"
     );
     // unterminated string
+    let err = dialect
+        .parse_sql_statements("SELECT Q'|asdfa....")
+        .unwrap_err();
     assert_eq!(
-        dialect.parse_sql_statements("SELECT Q'|asdfa...."),
-        Err(ParserError::TokenizerError(
-            "Unterminated string literal at Line: 1, Column: 9".into()
-        )),
+        err.to_string(),
+        "sql parser error: Unterminated string literal at Line: 1, Column: 9",
         "with EOF quote char"
     );
+    assert_eq!(err.location(), Some(Location::new(1, 9)));
 }
 
 #[test]
@@ -25,8 +25,8 @@ mod test_utils;
 use helpers::attached_token::AttachedToken;
 use sqlparser::ast::*;
 use sqlparser::dialect::{GenericDialect, PostgreSqlDialect};
-use sqlparser::parser::ParserError;
-use sqlparser::tokenizer::Span;
+use sqlparser::parser::{Parser, ParserError};
+use sqlparser::tokenizer::{Location, Span};
 use test_utils::*;
 
 #[test]
@@ -5652,7 +5652,7 @@ fn parse_create_table_with_alias() {
 
 #[test]
 fn parse_create_table_with_partition_by() {
-    let sql = "CREATE TABLE t1 (a INT, b TEXT) PARTITION BY RANGE(a)";
+    let sql = "CREATE TABLE t1 (a INT, b TEXT) PARTITION BY RANGE (a)";
     match pg_and_generic().verified_stmt(sql) {
         Statement::CreateTable(create_table) => {
             assert_eq!("t1", create_table.name.to_string());
@@ -5671,22 +5671,14 @@ fn parse_create_table_with_partition_by() {
                 ],
                 create_table.columns
             );
-            match *create_table.partition_by.unwrap() {
-                Expr::Function(f) => {
-                    assert_eq!("RANGE", f.name.to_string());
-                    assert_eq!(
-                        FunctionArguments::List(FunctionArgumentList {
-                            duplicate_treatment: None,
-                            clauses: vec![],
-                            args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
-                                Expr::Identifier(Ident::new("a"))
-                            ))],
-                        }),
-                        f.args
-                    );
-                }
-                _ => unreachable!(),
-            }
+            assert_eq!(None, create_table.partition_by);
+            assert_eq!(
+                Some(PartitionKey {
+                    strategy: PartitionStrategy::Range,
+                    columns: vec![Expr::Identifier(Ident::new("a"))],
+                }),
+                create_table.partition_key
+            );
         }
         _ => unreachable!(),
     }
@@ -6197,6 +6189,7 @@ fn parse_create_domain() {
     let sql1 = "CREATE DOMAIN my_domain AS INTEGER CHECK (VALUE > 0)";
     let expected = Statement::CreateDomain(CreateDomain {
         name: ObjectName::from(vec![Ident::new("my_domain")]),
+        as_keyword: true,
         data_type: DataType::Integer(None),
         collation: None,
         default: None,
@@ -6217,6 +6210,7 @@ fn parse_create_domain() {
     let sql2 = "CREATE DOMAIN my_domain AS INTEGER COLLATE \"en_US\" CHECK (VALUE > 0)";
     let expected = Statement::CreateDomain(CreateDomain {
         name: ObjectName::from(vec![Ident::new("my_domain")]),
+        as_keyword: true,
         data_type: DataType::Integer(None),
         collation: Some(Ident::with_quote('"', "en_US")),
         default: None,
@@ -6237,6 +6231,7 @@ fn parse_create_domain() {
     let sql3 = "CREATE DOMAIN my_domain AS INTEGER DEFAULT 1 CHECK (VALUE > 0)";
     let expected = Statement::CreateDomain(CreateDomain {
         name: ObjectName::from(vec![Ident::new("my_domain")]),
+        as_keyword: true,
         data_type: DataType::Integer(None),
         collation: None,
         default: Some(Expr::Value(test_utils::number("1").into())),
@@ -6257,6 +6252,7 @@ fn parse_create_domain() {
     let sql4 = "CREATE DOMAIN my_domain AS INTEGER COLLATE \"en_US\" DEFAULT 1 CHECK (VALUE > 0)";
     let expected = Statement::CreateDomain(CreateDomain {
         name: ObjectName::from(vec![Ident::new("my_domain")]),
+        as_keyword: true,
         data_type: DataType::Integer(None),
         collation: Some(Ident::with_quote('"', "en_US")),
         default: Some(Expr::Value(test_utils::number("1").into())),
@@ -6277,6 +6273,7 @@ fn parse_create_domain() {
     let sql5 = "CREATE DOMAIN my_domain AS INTEGER CONSTRAINT my_constraint CHECK (VALUE > 0)";
     let expected = Statement::CreateDomain(CreateDomain {
         name: ObjectName::from(vec![Ident::new("my_domain")]),
+        as_keyword: true,
         data_type: DataType::Integer(None),
         collation: None,
         default: None,
@@ -6684,6 +6681,8 @@ fn parse_trigger_related_functions() {
             primary_key: None,
             order_by: None,
             partition_by: None,
+            partition_key: None,
+            partition_options: None,
             cluster_by: None,
             clustered_by: None,
             inherits: None,
@@ -6720,6 +6719,7 @@ fn parse_trigger_related_functions() {
             multiset: None,
             fallback: None,
             with_data: None,
+            duplicate_handling: None,
         }
     );
 
@@ -9225,3 +9225,912 @@ fn parse_lock_table() {
         }
     }
 }
+
+#[test]
+fn parse_array_constructor_and_typed_array_literals() {
+    assert_eq!(
+        pg().verified_expr("ARRAY[1, 2, 3]"),
+        Expr::Array(Array {
+            elem: vec![
+                Expr::value(number("1")),
+                Expr::value(number("2")),
+                Expr::value(number("3")),
+            ],
+            named: true,
+        })
+    );
+
+    match pg().verified_expr("'{1,2}'::INT[]") {
+        Expr::Cast { data_type, .. } => assert_eq!(
+            data_type,
+            DataType::Array(ArrayElemTypeDef::SquareBracket(
+                Box::new(DataType::Int(None)),
+                None
+            ))
+        ),
+        expr => panic!("Expected Cast, got: {expr:?}"),
+    }
+
+    match pg().verified_expr("'{{1,2},{3,4}}'::INT[][]") {
+        Expr::Cast { data_type, .. } => assert_eq!(
+            data_type,
+            DataType::Array(ArrayElemTypeDef::SquareBracket(
+                Box::new(DataType::Array(ArrayElemTypeDef::SquareBracket(
+                    Box::new(DataType::Int(None)),
+                    None
+                ))),
+                None
+            ))
+        ),
+        expr => panic!("Expected Cast, got: {expr:?}"),
+    }
+
+    match pg().verified_expr("'{1,2,3}'::INT[3]") {
+        Expr::Cast { data_type, .. } => assert_eq!(
+            data_type,
+            DataType::Array(ArrayElemTypeDef::SquareBracket(
+                Box::new(DataType::Int(None)),
+                Some(3)
+            ))
+        ),
+        expr => panic!("Expected Cast, got: {expr:?}"),
+    }
+
+    pg().verified_stmt("CREATE TABLE t (a INT[], b TEXT[][], c INT[3])");
+    pg().verified_stmt("SELECT CAST('{1,2}' AS INT[])");
+}
+
+#[test]
+fn parse_at_time_zone_with_cast() {
+    let expr = pg_and_generic().verified_expr("(ts AT TIME ZONE 'UTC')::DATE");
+    assert_eq!(
+        expr,
+        Expr::Cast {
+            kind: CastKind::DoubleColon,
+            expr: Box::new(Expr::Nested(Box::new(Expr::AtTimeZone {
+                timestamp: Box::new(Expr::Identifier(Ident::new("ts"))),
+                time_zone: Box::new(Expr::Value(
+                    Value::SingleQuotedString("UTC".to_owned()).with_empty_span(),
+                )),
+            }))),
+            data_type: DataType::Date,
+            array: false,
+            format: None,
+        }
+    );
+
+    pg_and_generic().verified_expr("ts AT TIME ZONE 'UTC' AT TIME ZONE 'America/New_York'");
+    pg_and_generic().verified_stmt("SELECT ts::TIMESTAMPTZ AT TIME ZONE 'UTC' FROM t");
+}
+
+#[test]
+fn parse_overlay_placing() {
+    assert_eq!(
+        pg().verified_expr("OVERLAY('abc' PLACING 'x' FROM 2 FOR 1)"),
+        Expr::Overlay {
+            expr: Box::new(Expr::Value(
+                Value::SingleQuotedString("abc".to_owned()).with_empty_span()
+            )),
+            overlay_what: Box::new(Expr::Value(
+                Value::SingleQuotedString("x".to_owned()).with_empty_span()
+            )),
+            overlay_from: Box::new(Expr::value(number("2"))),
+            overlay_for: Some(Box::new(Expr::value(number("1")))),
+        }
+    );
+
+    match pg().verified_expr("OVERLAY('abc' PLACING 'x' FROM 2)") {
+        Expr::Overlay { overlay_for, .. } => assert_eq!(overlay_for, None),
+        expr => panic!("Expected Overlay, got: {expr:?}"),
+    }
+}
+
+#[test]
+fn parse_extract_postgres_fields() {
+    for (sql_field, field) in [
+        ("EPOCH", DateTimeField::Epoch),
+        ("DOW", DateTimeField::Dow),
+        ("ISOYEAR", DateTimeField::Isoyear),
+        (
+            "'custom'",
+            DateTimeField::Custom(Ident::with_quote('\'', "custom")),
+        ),
+    ] {
+        assert_eq!(
+            pg().verified_expr(&format!("EXTRACT({sql_field} FROM ts)")),
+            Expr::Extract {
+                field,
+                syntax: ExtractSyntax::From,
+                expr: Box::new(Expr::Identifier(Ident::new("ts"))),
+            }
+        );
+    }
+}
+
+#[test]
+fn parse_jsonb_operators_with_placeholders() {
+    let sql = r#"SELECT json_build_object('a', data #> '{b,c}') FROM t WHERE data @> '{"a":1}' AND data ?| ARRAY['x', 'y'] AND id = $1"#;
+    let select = pg().verified_only_select(sql);
+
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(func) => assert_eq!(func.name.to_string(), "json_build_object"),
+        expr => panic!("Expected Function, got: {expr:?}"),
+    }
+
+    // `?` is the key-existence operator, not a placeholder
+    match pg().verified_expr("data ? 'a'") {
+        Expr::BinaryOp { op, right, .. } => {
+            assert_eq!(op, BinaryOperator::Question);
+            assert_eq!(
+                *right,
+                Expr::Value(Value::SingleQuotedString("a".to_owned()).with_empty_span())
+            );
+        }
+        expr => panic!("Expected BinaryOp, got: {expr:?}"),
+    }
+
+    match select.selection {
+        Some(Expr::BinaryOp { right, .. }) => assert_eq!(
+            *right,
+            Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("id"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(
+                    Value::Placeholder("$1".to_owned()).with_empty_span()
+                )),
+            }
+        ),
+        selection => panic!("Expected BinaryOp, got: {selection:?}"),
+    }
+}
+
+#[test]
+fn parse_create_type_enum_and_composite() {
+    match pg().verified_stmt("CREATE TYPE mood AS ENUM ('sad', 'happy')") {
+        Statement::CreateType {
+            name,
+            representation: Some(UserDefinedTypeRepresentation::Enum { labels }),
+        } => {
+            assert_eq!(name.to_string(), "mood");
+            assert_eq!(
+                labels,
+                vec![
+                    Ident::with_quote('\'', "sad"),
+                    Ident::with_quote('\'', "happy")
+                ]
+            );
+        }
+        stmt => panic!("Expected CreateType, got: {stmt:?}"),
+    }
+
+    match pg().verified_stmt("CREATE TYPE pt AS (x INT, y INT)") {
+        Statement::CreateType {
+            name,
+            representation: Some(UserDefinedTypeRepresentation::Composite { attributes }),
+        } => {
+            assert_eq!(name.to_string(), "pt");
+            assert_eq!(
+                attributes,
+                vec![
+                    UserDefinedTypeCompositeAttributeDef {
+                        name: Ident::new("x"),
+                        data_type: DataType::Int(None),
+                        collation: None,
+                    },
+                    UserDefinedTypeCompositeAttributeDef {
+                        name: Ident::new("y"),
+                        data_type: DataType::Int(None),
+                        collation: None,
+                    },
+                ]
+            );
+        }
+        stmt => panic!("Expected CreateType, got: {stmt:?}"),
+    }
+
+    pg().verified_stmt("CREATE TYPE public.mood AS ENUM ()");
+    pg().verified_stmt("CREATE TYPE floatrange AS RANGE (SUBTYPE = FLOAT8)");
+}
+
+#[test]
+fn parse_create_domain_not_null() {
+    let sql = "CREATE DOMAIN posint AS INTEGER DEFAULT 1 NOT NULL CHECK (VALUE > 0)";
+    match pg().verified_stmt(sql) {
+        Statement::CreateDomain(CreateDomain {
+            default,
+            constraints,
+            ..
+        }) => {
+            assert_eq!(default, Some(Expr::value(number("1"))));
+            assert_eq!(constraints.len(), 2);
+            assert_eq!(constraints[0], DomainConstraint::NotNull { name: None });
+            assert!(matches!(constraints[1], DomainConstraint::Check(_)));
+        }
+        stmt => panic!("Expected CreateDomain, got: {stmt:?}"),
+    }
+
+    match pg().verified_stmt("CREATE DOMAIN d AS TEXT CONSTRAINT nn NOT NULL NULL") {
+        Statement::CreateDomain(CreateDomain { constraints, .. }) => assert_eq!(
+            constraints,
+            vec![
+                DomainConstraint::NotNull {
+                    name: Some(Ident::new("nn"))
+                },
+                DomainConstraint::Null { name: None },
+            ]
+        ),
+        stmt => panic!("Expected CreateDomain, got: {stmt:?}"),
+    }
+
+    pg().verified_stmt("CREATE DOMAIN d AS INTEGER NOT NULL");
+    pg().verified_stmt("CREATE DOMAIN d AS INTEGER CHECK (VALUE > 0) NOT NULL");
+
+    assert_eq!(
+        pg().parse_sql_statements("CREATE DOMAIN d AS INTEGER UNIQUE (VALUE)")
+            .unwrap_err(),
+        ParserError::ParserError("Unsupported domain constraint: UNIQUE (VALUE)".to_string())
+    );
+}
+
+#[test]
+fn parse_copy_with_parenthesized_options() {
+    let stmt = pg().one_statement_parses_to(
+        "COPY t (a, b) FROM STDIN WITH (FORMAT csv, HEADER true)",
+        "COPY t (a, b) FROM STDIN (FORMAT csv, HEADER)",
+    );
+    assert_eq!(
+        stmt,
+        Statement::Copy {
+            source: CopySource::Table {
+                table_name: ObjectName::from(vec!["t".into()]),
+                columns: vec!["a".into(), "b".into()],
+            },
+            to: false,
+            target: CopyTarget::Stdin,
+            options: vec![CopyOption::Format("csv".into()), CopyOption::Header(true),],
+            legacy_options: vec![],
+            values: vec![],
+        }
+    );
+
+    let stmt = pg().one_statement_parses_to(
+        "COPY (SELECT a FROM t) TO STDOUT WITH (FORMAT csv, HEADER false)",
+        "COPY (SELECT a FROM t) TO STDOUT (FORMAT csv, HEADER FALSE)",
+    );
+    match stmt {
+        Statement::Copy {
+            source: CopySource::Query(_),
+            to: true,
+            target: CopyTarget::Stdout,
+            options,
+            ..
+        } => assert_eq!(
+            options,
+            vec![CopyOption::Format("csv".into()), CopyOption::Header(false),]
+        ),
+        stmt => panic!("Expected Copy, got: {stmt:?}"),
+    }
+
+    pg().verified_stmt("COPY t TO '/tmp/t.csv' (FORMAT csv, DELIMITER ';')");
+}
+
+#[test]
+fn parse_listen_notify_unlisten() {
+    match pg().verified_stmt(r#"LISTEN "Chan""#) {
+        Statement::LISTEN { channel } => assert_eq!(channel, Ident::with_quote('"', "Chan")),
+        stmt => panic!("Expected LISTEN, got: {stmt:?}"),
+    }
+
+    match pg().verified_stmt(r#"NOTIFY "Chan", 'it''s done'"#) {
+        Statement::NOTIFY { channel, payload } => {
+            assert_eq!(channel, Ident::with_quote('"', "Chan"));
+            assert_eq!(payload, Some("it's done".to_string()));
+        }
+        stmt => panic!("Expected NOTIFY, got: {stmt:?}"),
+    }
+
+    pg().verified_stmt("NOTIFY chan");
+    pg().verified_stmt("UNLISTEN chan");
+    pg().verified_stmt("UNLISTEN *");
+}
+
+#[test]
+fn parse_vacuum_with_options() {
+    match pg().verified_stmt("VACUUM (FULL) t") {
+        Statement::Vacuum(v) => {
+            assert_eq!(
+                v.options,
+                Some(vec![UtilityOption {
+                    name: Ident::new("FULL"),
+                    arg: None,
+                }])
+            );
+            assert!(!v.full);
+            assert_eq!(v.table_name, Some(ObjectName::from(vec![Ident::new("t")])));
+            assert!(v.columns.is_empty());
+        }
+        stmt => panic!("Expected Vacuum, got: {stmt:?}"),
+    }
+
+    match pg().verified_stmt("VACUUM (FULL, ANALYZE) t (a, b)") {
+        Statement::Vacuum(v) => {
+            assert_eq!(v.options.map(|o| o.len()), Some(2));
+            assert_eq!(v.columns, vec![Ident::new("a"), Ident::new("b")]);
+        }
+        stmt => panic!("Expected Vacuum, got: {stmt:?}"),
+    }
+
+    pg().verified_stmt("VACUUM (VERBOSE, PARALLEL 4, INDEX_CLEANUP OFF)");
+    pg().verified_stmt("VACUUM FULL t");
+    pg().verified_stmt("ANALYZE t (col)");
+}
+
+#[test]
+fn parse_reindex() {
+    for (sql_kind, kind) in [
+        ("INDEX", ReindexKind::Index),
+        ("TABLE", ReindexKind::Table),
+        ("SCHEMA", ReindexKind::Schema),
+        ("DATABASE", ReindexKind::Database),
+        ("SYSTEM", ReindexKind::System),
+    ] {
+        assert_eq!(
+            pg().verified_stmt(&format!("REINDEX {sql_kind} my_obj")),
+            Statement::Reindex(Reindex {
+                options: None,
+                kind,
+                concurrently: false,
+                name: Some(ObjectName::from(vec![Ident::new("my_obj")])),
+            })
+        );
+        pg().verified_stmt(&format!("REINDEX {sql_kind} CONCURRENTLY my_obj"));
+    }
+
+    match pg().verified_stmt("REINDEX (VERBOSE) TABLE CONCURRENTLY public.t") {
+        Statement::Reindex(reindex) => {
+            assert_eq!(
+                reindex.options,
+                Some(vec![UtilityOption {
+                    name: Ident::new("VERBOSE"),
+                    arg: None,
+                }])
+            );
+            assert!(reindex.concurrently);
+            assert_eq!(reindex.name.unwrap().to_string(), "public.t");
+        }
+        stmt => panic!("Expected Reindex, got: {stmt:?}"),
+    }
+
+    pg().verified_stmt("REINDEX DATABASE");
+    pg().verified_stmt("REINDEX SYSTEM");
+
+    assert_eq!(
+        pg().parse_sql_statements("REINDEX INDEX").unwrap_err(),
+        ParserError::ParserError("Expected: identifier, found: EOF".to_string())
+    );
+    assert_eq!(
+        pg().parse_sql_statements("REINDEX VIEW v").unwrap_err(),
+        ParserError::ParserError(
+            "Expected: one of INDEX or TABLE or SCHEMA or DATABASE or SYSTEM, found: VIEW"
+                .to_string()
+        )
+    );
+    assert!(all_dialects_where(|d| !d.supports_reindex())
+        .parse_sql_statements("REINDEX TABLE t")
+        .is_err());
+}
+
+#[test]
+fn parse_set_local_default_and_reset() {
+    for (sql_scope, scope) in [
+        ("LOCAL", ContextModifier::Local),
+        ("SESSION", ContextModifier::Session),
+    ] {
+        assert_eq!(
+            pg().verified_stmt(&format!("SET {sql_scope} search_path = public")),
+            Statement::Set(Set::SingleAssignment {
+                scope: Some(scope),
+                hivevar: false,
+                variable: ObjectName::from(vec![Ident::new("search_path")]),
+                values: vec![Expr::Identifier(Ident::new("public"))],
+            })
+        );
+    }
+
+    // `TO` is normalized to `=`, `DEFAULT` is kept as an unquoted identifier
+    assert_eq!(
+        pg().one_statement_parses_to(
+            "SET LOCAL work_mem TO DEFAULT",
+            "SET LOCAL work_mem = DEFAULT"
+        ),
+        Statement::Set(Set::SingleAssignment {
+            scope: Some(ContextModifier::Local),
+            hivevar: false,
+            variable: ObjectName::from(vec![Ident::new("work_mem")]),
+            values: vec![Expr::Identifier(Ident::new("DEFAULT"))],
+        })
+    );
+    pg().verified_stmt("SET work_mem = DEFAULT");
+
+    assert_eq!(
+        pg().verified_stmt("RESET work_mem"),
+        Statement::Reset(ResetStatement {
+            reset: Reset::ConfigurationParameter(ObjectName::from(vec![Ident::new("work_mem")])),
+        })
+    );
+    assert_eq!(
+        pg().verified_stmt("RESET ALL"),
+        Statement::Reset(ResetStatement { reset: Reset::ALL })
+    );
+}
+
+#[test]
+fn parse_explain_parenthesized_options() {
+    match pg().verified_stmt("EXPLAIN (FORMAT JSON, COSTS false) SELECT 1") {
+        Statement::Explain { options, .. } => {
+            assert_eq!(
+                Some(vec![
+                    ExplainOption::Format(ExplainFormat::Json),
+                    ExplainOption::Costs(Some(false)),
+                ]),
+                options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("EXPLAIN (ANALYZE, BUFFERS, FORMAT JSON) SELECT * FROM t WHERE a = 1");
+}
+
+#[test]
+fn parse_explain_option_list() {
+    let sql =
+        "EXPLAIN (ANALYZE true, VERBOSE, FORMAT JSON, BUFFERS off, SETTINGS, WAL false) SELECT 1";
+    match pg().verified_stmt(sql) {
+        Statement::Explain {
+            options: Some(options),
+            ..
+        } => {
+            assert_eq!(
+                vec![
+                    ExplainOption::Analyze(Some(true)),
+                    ExplainOption::Verbose(None),
+                    ExplainOption::Format(ExplainFormat::Json),
+                    ExplainOption::Other(UtilityOption {
+                        name: Ident::new("BUFFERS"),
+                        arg: Some(Expr::Identifier(Ident::new("off"))),
+                    }),
+                    ExplainOption::Settings(None),
+                    ExplainOption::Wal(Some(false)),
+                ],
+                options
+            );
+        }
+        other => panic!("Expected EXPLAIN with options, got: {other:?}"),
+    }
+
+    for format in ["TEXT", "XML", "JSON", "YAML"] {
+        pg().verified_stmt(&format!("EXPLAIN (FORMAT {format}) SELECT 1"));
+    }
+    pg().verified_stmt(
+        "EXPLAIN (ANALYZE false, COSTS true, GENERIC_PLAN, TIMING, SUMMARY, MEMORY) SELECT 1",
+    );
+
+    assert_eq!(
+        ParserError::ParserError("Expected: TEXT, XML, JSON or YAML, found: HTML".to_string()),
+        pg().parse_sql_statements("EXPLAIN (FORMAT HTML) SELECT 1")
+            .unwrap_err()
+    );
+    assert!(pg()
+        .parse_sql_statements("EXPLAIN (FORMAT 'JSON') SELECT 1")
+        .is_err());
+}
+
+#[test]
+fn parse_create_statistics() {
+    let sql = "CREATE STATISTICS IF NOT EXISTS s1 (ndistinct, dependencies, mcv) ON a, b FROM t1";
+    match pg().verified_stmt(sql) {
+        Statement::CreateStatistics(CreateStatistics {
+            if_not_exists,
+            name,
+            kinds,
+            on,
+            from,
+            ..
+        }) => {
+            assert!(if_not_exists);
+            assert_eq!(name, Some(ObjectName::from(vec![Ident::new("s1")])));
+            assert_eq!(
+                kinds,
+                vec![
+                    StatisticsKind::NDistinct,
+                    StatisticsKind::Dependencies,
+                    StatisticsKind::Mcv
+                ]
+            );
+            assert_eq!(
+                on,
+                vec![
+                    Expr::Identifier(Ident::new("a")),
+                    Expr::Identifier(Ident::new("b"))
+                ]
+            );
+            assert_eq!(from, ObjectName::from(vec![Ident::new("t1")]));
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt("CREATE STATISTICS s2 ON a, b FROM public.t1") {
+        Statement::CreateStatistics(CreateStatistics {
+            if_not_exists,
+            kinds,
+            ..
+        }) => {
+            assert!(!if_not_exists);
+            assert!(kinds.is_empty());
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("CREATE STATISTICS myschema.s3 (mcv) ON (lower(a)), b FROM t1");
+    pg().verified_stmt("CREATE STATISTICS ON (a + b) FROM t1");
+
+    assert_eq!(
+        pg().parse_sql_statements("CREATE STATISTICS s1 (histogram) ON a, b FROM t1")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: one of NDISTINCT or DEPENDENCIES or MCV, found: histogram".to_string()
+        )
+    );
+    assert!(all_dialects_where(|d| !d.supports_create_statistics())
+        .parse_sql_statements("CREATE STATISTICS s ON a, b FROM t")
+        .is_err());
+}
+
+#[test]
+fn parse_string_agg_with_inline_order_by() {
+    let select = pg().verified_only_select("SELECT STRING_AGG(x, ',' ORDER BY y DESC, z) FROM t");
+    let Expr::Function(Function {
+        args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
+        within_group,
+        ..
+    }) = expr_from_projection(only(&select.projection))
+    else {
+        panic!("Expected STRING_AGG function");
+    };
+    assert_eq!(args.len(), 2);
+    assert!(within_group.is_empty());
+    assert_eq!(
+        clauses,
+        &vec![FunctionArgumentClause::OrderBy(vec![
+            OrderByExpr {
+                expr: Expr::Identifier(Ident::new("y")),
+                options: OrderByOptions {
+                    sort: Some(OrderBySort::Desc),
+                    nulls_first: None,
+                },
+                with_fill: None,
+            },
+            OrderByExpr {
+                expr: Expr::Identifier(Ident::new("z")),
+                options: OrderByOptions {
+                    sort: None,
+                    nulls_first: None,
+                },
+                with_fill: None,
+            },
+        ])]
+    );
+
+    pg().verified_stmt(
+        "SELECT STRING_AGG(DISTINCT x, ',' ORDER BY x) FILTER (WHERE x IS NOT NULL) FROM t",
+    );
+}
+
+#[test]
+fn parse_create_table_partition_key_strategies() {
+    let sql = "CREATE TABLE measurement (city_id INT, logdate DATE) PARTITION BY LIST (city_id, (lower(region)))";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            partition_key: Some(PartitionKey { strategy, columns }),
+            partition_by: None,
+            ..
+        }) => {
+            assert_eq!(PartitionStrategy::List, strategy);
+            assert_eq!(2, columns.len());
+            assert_eq!(Expr::Identifier(Ident::new("city_id")), columns[0]);
+            assert!(matches!(columns[1], Expr::Nested(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("CREATE TABLE t (a INT, b INT) PARTITION BY RANGE (a, b)");
+    pg().verified_stmt("CREATE TABLE t (a INT) PARTITION BY HASH (a)");
+    pg().one_statement_parses_to(
+        "CREATE TABLE t (a INT) PARTITION BY hash(a)",
+        "CREATE TABLE t (a INT) PARTITION BY HASH (a)",
+    );
+
+    // Sub-partitioned partitions combine a bound spec with a partition key
+    match pg().verified_stmt(
+        "CREATE TABLE t_2020 PARTITION OF t FOR VALUES FROM (MINVALUE) TO ('2021-01-01') PARTITION BY HASH (a)",
+    ) {
+        Statement::CreateTable(create_table) => {
+            assert_eq!(
+                Some(ForValues::From {
+                    from: vec![PartitionBoundValue::MinValue],
+                    to: vec![PartitionBoundValue::Expr(Expr::value(
+                        Value::SingleQuotedString("2021-01-01".to_string())
+                    ))],
+                }),
+                create_table.for_values
+            );
+            assert_eq!(
+                Some(PartitionKey {
+                    strategy: PartitionStrategy::Hash,
+                    columns: vec![Expr::Identifier(Ident::new("a"))],
+                }),
+                create_table.partition_key
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_grant_with_options_and_granted_by() {
+    pg().verified_stmt("GRANT SELECT, UPDATE ON t TO alice WITH GRANT OPTION GRANTED BY bob");
+
+    match pg().verified_stmt("GRANT admin, ops TO alice, bob WITH ADMIN OPTION GRANTED BY postgres")
+    {
+        Statement::Grant(Grant {
+            privileges,
+            objects,
+            grantees,
+            with_grant_option,
+            with_admin_option,
+            granted_by,
+            ..
+        }) => {
+            assert_eq!(
+                Privileges::Roles(vec![Ident::new("admin"), Ident::new("ops")]),
+                privileges
+            );
+            assert_eq!(None, objects);
+            assert_eq!(2, grantees.len());
+            assert!(!with_grant_option);
+            assert!(with_admin_option);
+            assert_eq!(
+                Some(Grantee {
+                    grantee_type: GranteesType::None,
+                    name: Some(GranteeName::ObjectName(ObjectName::from(vec![Ident::new(
+                        "postgres"
+                    )]))),
+                }),
+                granted_by
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("GRANT admin TO alice");
+    pg().verified_stmt("REVOKE admin FROM alice");
+
+    assert_eq!(
+        pg().parse_sql_statements("GRANT admin ON t TO alice")
+            .unwrap_err(),
+        ParserError::ParserError("Expected: a privilege keyword, found: admin".to_string())
+    );
+    assert_eq!(
+        pg().parse_sql_statements("GRANT SELECT ON t TO a WITH GRANT OPTION WITH ADMIN OPTION")
+            .unwrap_err(),
+        ParserError::ParserError("Expected: end of statement, found: WITH".to_string())
+    );
+}
+
+#[test]
+fn parse_alter_column_postgres_operations() {
+    for op in [
+        "SET NOT NULL",
+        "DROP NOT NULL",
+        "SET DEFAULT nextval('tab_c_seq')",
+        "DROP DEFAULT",
+        "SET DATA TYPE INT USING c::INT",
+        "SET STATISTICS 500",
+        "SET STATISTICS -1",
+        "SET (n_distinct = 100, n_distinct_inherited = -1)",
+        "RESET (n_distinct, n_distinct_inherited)",
+        "SET STORAGE PLAIN",
+        "SET STORAGE EXTERNAL",
+        "SET STORAGE EXTENDED",
+        "SET STORAGE MAIN",
+        "SET STORAGE DEFAULT",
+    ] {
+        pg().verified_stmt(&format!("ALTER TABLE tab ALTER COLUMN c {op}"));
+    }
+
+    match alter_table_op(pg().verified_stmt("ALTER TABLE tab ALTER COLUMN c SET STATISTICS 500")) {
+        AlterTableOperation::AlterColumn { op, .. } => assert_eq!(
+            AlterColumnOperation::SetStatistics {
+                target: Expr::value(number("500")),
+            },
+            op
+        ),
+        _ => unreachable!(),
+    }
+
+    match alter_table_op(pg().verified_stmt("ALTER TABLE tab ALTER COLUMN c SET STORAGE EXTERNAL"))
+    {
+        AlterTableOperation::AlterColumn { op, .. } => assert_eq!(
+            AlterColumnOperation::SetStorage {
+                storage: ColumnStorage::External,
+                storage_token: AttachedToken::empty(),
+            },
+            op
+        ),
+        _ => unreachable!(),
+    }
+
+    match alter_table_op(pg().verified_stmt("ALTER TABLE tab ALTER COLUMN c RESET (n_distinct)")) {
+        AlterTableOperation::AlterColumn { op, .. } => assert_eq!(
+            AlterColumnOperation::ResetOptions {
+                options: vec![Ident::new("n_distinct")],
+            },
+            op
+        ),
+        _ => unreachable!(),
+    }
+
+    match alter_table_op(
+        pg().verified_stmt("ALTER TABLE tab ALTER COLUMN c SET (n_distinct = 100)"),
+    ) {
+        AlterTableOperation::AlterColumn { op, .. } => assert_eq!(
+            AlterColumnOperation::SetOptions {
+                options: vec![SqlOption::KeyValue {
+                    key: Ident::new("n_distinct"),
+                    value: Expr::value(number("100")),
+                }],
+            },
+            op
+        ),
+        _ => unreachable!(),
+    }
+
+    assert_eq!(
+        pg().parse_sql_statements("ALTER TABLE tab ALTER COLUMN c SET STORAGE COMPRESSED")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: one of PLAIN or EXTERNAL or EXTENDED or MAIN or DEFAULT, found: COMPRESSED"
+                .to_string()
+        )
+    );
+    let stmt = Parser::parse_sql(
+        &PostgreSqlDialect {},
+        "ALTER TABLE tab ALTER COLUMN c SET STORAGE EXTERNAL",
+    )
+    .unwrap()
+    .pop()
+    .unwrap();
+    match alter_table_op(stmt) {
+        AlterTableOperation::AlterColumn { op, .. } => assert_eq!(
+            op.span(),
+            Span::new(Location::new(1, 44), Location::new(1, 52))
+        ),
+        _ => unreachable!(),
+    }
+
+    for op in [
+        "SET STATISTICS 500",
+        "SET STORAGE PLAIN",
+        "SET (n_distinct = 100)",
+        "RESET (n_distinct)",
+    ] {
+        let sql = format!("ALTER TABLE tab ALTER COLUMN c {op}");
+        assert!(TestedDialects::new(vec![Box::new(GenericDialect {})])
+            .parse_sql_statements(&sql)
+            .is_err());
+    }
+}
+
+#[test]
+fn parse_select_locking_clauses() {
+    for lock_type in ["UPDATE", "NO KEY UPDATE", "SHARE", "KEY SHARE"] {
+        for of in ["", " OF a", " OF a, s.b"] {
+            for nonblock in ["", " NOWAIT", " SKIP LOCKED"] {
+                pg().verified_stmt(&format!(
+                    "SELECT * FROM a JOIN s.b ON a.id = b.id FOR {lock_type}{of}{nonblock}"
+                ));
+            }
+        }
+    }
+
+    let query = pg().verified_query(
+        "SELECT * FROM a JOIN b ON a.id = b.id FOR NO KEY UPDATE OF a, b SKIP LOCKED FOR KEY SHARE OF c NOWAIT",
+    );
+    assert_eq!(
+        vec![
+            LockClause {
+                lock_type: LockType::NoKeyUpdate,
+                of: vec![
+                    ObjectName::from(vec![Ident::new("a")]),
+                    ObjectName::from(vec![Ident::new("b")]),
+                ],
+                nonblock: Some(NonBlock::SkipLocked),
+            },
+            LockClause {
+                lock_type: LockType::KeyShare,
+                of: vec![ObjectName::from(vec![Ident::new("c")])],
+                nonblock: Some(NonBlock::Nowait),
+            },
+        ],
+        query.locks
+    );
+}
+
+#[test]
+fn parse_create_domain_optional_as_and_named_constraints() {
+    match pg().verified_stmt("CREATE DOMAIN d INT") {
+        Statement::CreateDomain(CreateDomain { as_keyword, .. }) => assert!(!as_keyword),
+        stmt => panic!("Expected CreateDomain, got: {stmt:?}"),
+    }
+    pg().verified_stmt("CREATE DOMAIN d INT NULL CHECK (VALUE <> 3)");
+
+    match pg().verified_stmt(
+        "CREATE DOMAIN pos AS INTEGER DEFAULT 1 CONSTRAINT c CHECK (VALUE > 0) CONSTRAINT nn NOT NULL",
+    ) {
+        Statement::CreateDomain(CreateDomain {
+            name,
+            data_type,
+            default,
+            constraints,
+            ..
+        }) => {
+            assert_eq!("pos", name.to_string());
+            assert_eq!(DataType::Integer(None), data_type);
+            assert_eq!(Some(Expr::value(number("1"))), default);
+            assert_eq!(2, constraints.len());
+            match &constraints[0] {
+                DomainConstraint::Check(check) => {
+                    assert_eq!(Some(Ident::new("c")), check.name);
+                    assert_eq!("VALUE > 0", check.expr.to_string());
+                }
+                _ => unreachable!(),
+            }
+            assert_eq!(
+                DomainConstraint::NotNull {
+                    name: Some(Ident::new("nn"))
+                },
+                constraints[1]
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_type_rename_value() {
+    match pg_and_generic().verified_stmt("ALTER TYPE mood RENAME VALUE 'sad' TO 'blue'") {
+        Statement::AlterType(AlterType { name, operation }) => {
+            assert_eq!("mood", name.to_string());
+            assert_eq!(
+                AlterTypeOperation::RenameValue(AlterTypeRenameValue {
+                    from: Ident::with_quote('\'', "sad"),
+                    to: Ident::with_quote('\'', "blue"),
+                }),
+                operation
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_real_and_double_precision_without_precision() {
+    pg().verified_stmt("CREATE TABLE t (a REAL, b DOUBLE PRECISION)");
+    assert!(pg()
+        .parse_sql_statements("CREATE TABLE t (a REAL(8,3))")
+        .is_err());
+    assert!(pg()
+        .parse_sql_statements("CREATE TABLE t (a DOUBLE PRECISION(12,5))")
+        .is_err());
+}
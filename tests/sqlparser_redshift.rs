@@ -382,8 +382,15 @@ fn test_parse_select_numbered_columns() {
 fn test_parse_nested_quoted_identifier() {
     redshift().verified_stmt(r#"SELECT 1 AS ["1"] FROM a"#);
     redshift().verified_stmt(r#"SELECT 1 AS ["[="] FROM a"#);
-    redshift().verified_stmt(r#"SELECT 1 AS ["=]"] FROM a"#);
-    redshift().verified_stmt(r#"SELECT 1 AS ["a[b]"] FROM a"#);
+    redshift().one_statement_parses_to(
+        r#"SELECT 1 AS ["=]"] FROM a"#,
+        r#"SELECT 1 AS ["=]]"] FROM a"#,
+    );
+    redshift().verified_stmt(r#"SELECT 1 AS ["=]]"] FROM a"#);
+    redshift().one_statement_parses_to(
+        r#"SELECT 1 AS ["a[b]"] FROM a"#,
+        r#"SELECT 1 AS ["a[b]]"] FROM a"#,
+    );
     // trim spaces
     redshift().one_statement_parses_to(r#"SELECT 1 AS [ " 1 " ]"#, r#"SELECT 1 AS [" 1 "]"#);
     // invalid query
@@ -542,3 +549,35 @@ fn test_partiql_from_alias_with_at_index() {
         _ => panic!("expected table factor"),
     }
 }
+
+#[test]
+fn test_copy_credentials_redacted() {
+    let sql = "COPY t1 FROM 's3://bucket/file.csv' CREDENTIALS 'aws_access_key_id=AK;aws_secret_access_key=SK' CSV";
+    let mut stmt = redshift().verified_stmt(sql);
+    stmt.redact_credentials();
+    assert_eq!(
+        "COPY t1 FROM 's3://bucket/file.csv' CREDENTIALS '***' CSV",
+        stmt.to_string()
+    );
+    match stmt {
+        Statement::Copy { legacy_options, .. } => {
+            assert_eq!(
+                vec![
+                    CopyLegacyOption::Credentials("***".to_string()),
+                    CopyLegacyOption::Csv(vec![]),
+                ],
+                legacy_options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let mut stmt = redshift().verified_stmt(
+        "UNLOAD('SELECT 1') TO 's3://bucket/prefix' CREDENTIALS 'aws_access_key_id=AK;aws_secret_access_key=SK'",
+    );
+    stmt.redact_credentials();
+    assert_eq!(
+        "UNLOAD('SELECT 1') TO 's3://bucket/prefix' CREDENTIALS '***'",
+        stmt.to_string()
+    );
+}
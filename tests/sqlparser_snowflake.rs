@@ -1726,7 +1726,7 @@ fn parse_snowflake_declare_cursor() {
         ),
     ] {
         match snowflake().verified_stmt(sql) {
-            Statement::Declare { mut stmts } => {
+            Statement::Declare { mut stmts, .. } => {
                 assert_eq!(1, stmts.len());
                 let Declare {
                     names,
@@ -1794,7 +1794,7 @@ fn parse_snowflake_declare_result_set() {
         ("DECLARE res RESULTSET", "res", None),
     ] {
         match snowflake().verified_stmt(sql) {
-            Statement::Declare { mut stmts } => {
+            Statement::Declare { mut stmts, .. } => {
                 assert_eq!(1, stmts.len());
                 let Declare {
                     names,
@@ -1847,7 +1847,7 @@ fn parse_snowflake_declare_exception() {
         ("DECLARE ex EXCEPTION", "ex", None),
     ] {
         match snowflake().verified_stmt(sql) {
-            Statement::Declare { mut stmts } => {
+            Statement::Declare { mut stmts, .. } => {
                 assert_eq!(1, stmts.len());
                 let Declare {
                     names,
@@ -1887,7 +1887,7 @@ fn parse_snowflake_declare_variable() {
         ("DECLARE profit", "profit", None, None),
     ] {
         match snowflake().verified_stmt(sql) {
-            Statement::Declare { mut stmts } => {
+            Statement::Declare { mut stmts, .. } => {
                 assert_eq!(1, stmts.len());
                 let Declare {
                     names,
@@ -1937,7 +1937,8 @@ fn parse_snowflake_declare_multi_statements() {
         "ex EXCEPTION (-20003, 'ERROR: Could not create table.')"
     );
     match snowflake().verified_stmt(sql) {
-        Statement::Declare { stmts } => {
+        Statement::Declare { stmts, separator } => {
+            assert_eq!(DeclareSeparator::Semicolon, separator);
             let actual = stmts
                 .iter()
                 .map(|stmt| (stmt.names[0].value.as_str(), stmt.declare_type.clone()))
@@ -2732,6 +2733,7 @@ fn test_snowflake_trim() {
             trim_characters: Some(vec![Expr::Value(
                 (Value::SingleQuotedString("a".to_owned())).with_empty_span()
             )]),
+            bare_from: false,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -5054,3 +5056,344 @@ fn test_select_dollar_column_from_stage() {
     // With table function args, without alias
     snowflake().verified_stmt("SELECT $1, $2 FROM @mystage1(file_format => 'myformat')");
 }
+
+#[test]
+fn test_copy_into_from_stage_with_bare_copy_options() {
+    let sql = concat!(
+        "COPY INTO my_table FROM @my_stage/path/ ",
+        "FILE_FORMAT=(TYPE=CSV FIELD_DELIMITER='|' SKIP_HEADER=1) ",
+        "ON_ERROR=CONTINUE"
+    );
+    let canonical = concat!(
+        "COPY INTO my_table FROM @my_stage/path/ ",
+        "FILE_FORMAT=(TYPE=CSV FIELD_DELIMITER='|' SKIP_HEADER=1) ",
+        "COPY_OPTIONS=(ON_ERROR=CONTINUE)"
+    );
+
+    match snowflake().one_statement_parses_to(sql, canonical) {
+        Statement::CopyIntoSnowflake {
+            kind,
+            into,
+            from_obj,
+            file_format,
+            copy_options,
+            ..
+        } => {
+            assert_eq!(kind, CopyIntoSnowflakeKind::Table);
+            assert_eq!(into, ObjectName::from(vec![Ident::new("my_table")]));
+            assert_eq!(
+                from_obj,
+                Some(ObjectName::from(vec![Ident::new("@my_stage/path/")]))
+            );
+            assert_eq!(file_format.options.len(), 3);
+            assert!(file_format.options.contains(&KeyValueOption {
+                option_name: "TYPE".to_string(),
+                option_value: KeyValueOptionKind::Single(
+                    Value::Placeholder("CSV".to_string()).with_empty_span()
+                ),
+            }));
+            assert_eq!(
+                copy_options.options,
+                vec![KeyValueOption {
+                    option_name: "ON_ERROR".to_string(),
+                    option_value: KeyValueOptionKind::Single(
+                        Value::Placeholder("CONTINUE".to_string()).with_empty_span()
+                    ),
+                }]
+            );
+        }
+        stmt => panic!("Expected CopyIntoSnowflake, got: {stmt:?}"),
+    }
+    snowflake().verified_stmt(canonical);
+}
+
+#[test]
+fn test_create_file_format() {
+    match snowflake()
+        .verified_stmt("CREATE FILE FORMAT my_csv TYPE=CSV FIELD_DELIMITER='|' SKIP_HEADER=1")
+    {
+        Statement::CreateFileFormat {
+            or_replace,
+            temporary,
+            if_not_exists,
+            name,
+            options,
+        } => {
+            assert!(!or_replace);
+            assert!(!temporary);
+            assert!(!if_not_exists);
+            assert_eq!(name, ObjectName::from(vec![Ident::new("my_csv")]));
+            assert_eq!(
+                options.options,
+                vec![
+                    KeyValueOption {
+                        option_name: "TYPE".to_string(),
+                        option_value: KeyValueOptionKind::Single(
+                            Value::Placeholder("CSV".to_string()).with_empty_span()
+                        ),
+                    },
+                    KeyValueOption {
+                        option_name: "FIELD_DELIMITER".to_string(),
+                        option_value: KeyValueOptionKind::Single(
+                            Value::SingleQuotedString("|".to_string()).with_empty_span()
+                        ),
+                    },
+                    KeyValueOption {
+                        option_name: "SKIP_HEADER".to_string(),
+                        option_value: KeyValueOptionKind::Single(
+                            Value::Number("1".parse().unwrap(), false).with_empty_span()
+                        ),
+                    },
+                ]
+            );
+        }
+        stmt => panic!("Expected CreateFileFormat, got: {stmt:?}"),
+    }
+
+    snowflake().verified_stmt("CREATE OR REPLACE TEMPORARY FILE FORMAT IF NOT EXISTS db.sc.my_csv TYPE=CSV NULL_IF=('NULL', 'null') COMMENT='csv'");
+    snowflake().verified_stmt("CREATE FILE FORMAT my_json");
+    snowflake().one_statement_parses_to(
+        "CREATE FILE FORMAT my_json TYPE = JSON STRIP_OUTER_ARRAY = TRUE",
+        "CREATE FILE FORMAT my_json TYPE=JSON STRIP_OUTER_ARRAY=true",
+    );
+    assert_eq!(
+        snowflake()
+            .parse_sql_statements("CREATE FILE FORMAT f TYPE=CSV; SELECT 1")
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_create_internal_stage() {
+    match snowflake().verified_stmt(
+        "CREATE STAGE my_int_stage FILE_FORMAT=(TYPE=CSV SKIP_HEADER=1) COMMENT='internal'",
+    ) {
+        Statement::CreateStage {
+            name,
+            stage_params,
+            file_format,
+            comment,
+            ..
+        } => {
+            assert_eq!(name, ObjectName::from(vec![Ident::new("my_int_stage")]));
+            assert_eq!(stage_params.url, None);
+            assert_eq!(file_format.options.len(), 2);
+            assert_eq!(comment, Some("internal".to_string()));
+        }
+        stmt => panic!("Expected CreateStage, got: {stmt:?}"),
+    }
+}
+
+#[test]
+fn test_merge_matched_update_delete_and_not_matched_insert() {
+    let sql = concat!(
+        "MERGE INTO t USING s ON t.id = s.id ",
+        "WHEN MATCHED AND s.deleted THEN DELETE ",
+        "WHEN MATCHED THEN UPDATE SET t.a = s.a, t.b = s.b ",
+        "WHEN NOT MATCHED AND s.a > 0 THEN INSERT (id, a, b) VALUES (s.id, s.a, s.b)"
+    );
+
+    match snowflake().verified_stmt(sql) {
+        Statement::Merge(Merge { clauses, .. }) => {
+            assert_eq!(clauses.len(), 3);
+
+            assert_eq!(clauses[0].clause_kind, MergeClauseKind::Matched);
+            assert!(clauses[0].predicate.is_some());
+            assert!(matches!(clauses[0].action, MergeAction::Delete { .. }));
+
+            assert_eq!(clauses[1].clause_kind, MergeClauseKind::Matched);
+            assert!(clauses[1].predicate.is_none());
+            match &clauses[1].action {
+                MergeAction::Update(MergeUpdateExpr {
+                    kind: MergeUpdateKind::Set(assignments),
+                    ..
+                }) => assert_eq!(assignments.len(), 2),
+                action => panic!("Expected UPDATE SET, got: {action:?}"),
+            }
+
+            assert_eq!(clauses[2].clause_kind, MergeClauseKind::NotMatched);
+            assert!(clauses[2].predicate.is_some());
+            match &clauses[2].action {
+                MergeAction::Insert(insert) => assert_eq!(
+                    insert.columns,
+                    vec![
+                        ObjectName::from(vec![Ident::new("id")]),
+                        ObjectName::from(vec![Ident::new("a")]),
+                        ObjectName::from(vec![Ident::new("b")]),
+                    ]
+                ),
+                action => panic!("Expected INSERT, got: {action:?}"),
+            }
+        }
+        stmt => panic!("Expected Merge, got: {stmt:?}"),
+    }
+}
+
+#[test]
+fn test_stage_credentials_redacted() {
+    let sql = concat!(
+        "CREATE OR REPLACE STAGE my_ext_stage ",
+        "URL='s3://load/files/' ",
+        "CREDENTIALS=(AWS_KEY_ID='1a2b3c' AWS_SECRET_KEY='4x5y6z') ",
+        "ENCRYPTION=(MASTER_KEY='key' TYPE='AWS_SSE_KMS')"
+    );
+    let mut stmt = snowflake().verified_stmt(sql);
+    // parsing keeps the secrets, only the explicit pass masks them
+    assert_eq!(sql, stmt.to_string());
+    stmt.redact_credentials();
+    assert_eq!(
+        concat!(
+            "CREATE OR REPLACE STAGE my_ext_stage ",
+            "URL='s3://load/files/' ",
+            "CREDENTIALS=(AWS_KEY_ID='***' AWS_SECRET_KEY='***') ",
+            "ENCRYPTION=(MASTER_KEY='***' TYPE='AWS_SSE_KMS')"
+        ),
+        stmt.to_string()
+    );
+    match stmt {
+        Statement::CreateStage { stage_params, .. } => {
+            assert_eq!(
+                vec!["AWS_KEY_ID", "AWS_SECRET_KEY"],
+                stage_params
+                    .credentials
+                    .options
+                    .iter()
+                    .map(|o| o.option_name.as_str())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                "MASTER_KEY='***' TYPE='AWS_SSE_KMS'",
+                stage_params.encryption.to_string()
+            );
+        }
+        _ => unreachable!(),
+    };
+
+    let mut stmt = snowflake().verified_stmt(
+        "COPY INTO my_table FROM 's3://bucket/data/' CREDENTIALS=(AWS_KEY_ID='1a2b3c' AWS_SECRET_KEY='4x5y6z')",
+    );
+    stmt.redact_credentials();
+    assert_eq!(
+        "COPY INTO my_table FROM 's3://bucket/data/' CREDENTIALS=(AWS_KEY_ID='***' AWS_SECRET_KEY='***')",
+        stmt.to_string()
+    );
+}
+
+#[test]
+fn parse_group_by_all_and_order_by_all() {
+    let select = snowflake().verified_query(
+        "SELECT state, city, SUM(retail_price) FROM sales GROUP BY ALL ORDER BY ALL",
+    );
+    assert_eq!(
+        GroupByExpr::All(vec![]),
+        select.body.as_select().unwrap().group_by
+    );
+    assert_eq!(
+        OrderByKind::All(OrderByOptions {
+            sort: None,
+            nulls_first: None,
+        }),
+        select.order_by.unwrap().kind
+    );
+    snowflake().verified_stmt("SELECT a, b FROM t ORDER BY ALL DESC NULLS LAST");
+}
+
+#[test]
+fn test_copy_into_location_unload_options() {
+    let sql = concat!(
+        "COPY INTO @my_stage/result/data_ ",
+        "FROM (SELECT * FROM orders) ",
+        "FILE_FORMAT=(TYPE=CSV COMPRESSION=NONE) ",
+        "HEADER=TRUE SINGLE=TRUE MAX_FILE_SIZE=4900000000 OVERWRITE=TRUE"
+    );
+    let canonical = concat!(
+        "COPY INTO @my_stage/result/data_ ",
+        "FROM (SELECT * FROM orders) ",
+        "FILE_FORMAT=(TYPE=CSV COMPRESSION=NONE) ",
+        "HEADER=true SINGLE=true MAX_FILE_SIZE=4900000000 OVERWRITE=true"
+    );
+    match snowflake().one_statement_parses_to(sql, canonical) {
+        Statement::CopyIntoSnowflake {
+            kind,
+            into,
+            from_obj,
+            from_query,
+            file_format,
+            copy_options,
+            ..
+        } => {
+            assert_eq!(kind, CopyIntoSnowflakeKind::Location);
+            assert_eq!(into.to_string(), "@my_stage/result/data_");
+            assert!(from_obj.is_none());
+            assert_eq!(from_query.unwrap().to_string(), "SELECT * FROM orders");
+            assert_eq!(file_format.options.len(), 2);
+            assert_eq!(
+                copy_options
+                    .options
+                    .iter()
+                    .map(|o| o.option_name.as_str())
+                    .collect::<Vec<_>>(),
+                vec!["HEADER", "SINGLE", "MAX_FILE_SIZE", "OVERWRITE"]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "COPY INTO @my_stage/unload/ FROM mytable FILE_FORMAT=(FORMAT_NAME='my_csv') OVERWRITE=false";
+    match snowflake().verified_stmt(sql) {
+        Statement::CopyIntoSnowflake {
+            kind,
+            from_obj,
+            from_query,
+            ..
+        } => {
+            assert_eq!(kind, CopyIntoSnowflakeKind::Location);
+            assert_eq!(
+                from_obj,
+                Some(ObjectName::from(vec![Ident::new("mytable")]))
+            );
+            assert!(from_query.is_none());
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_pivot_multiple_aggregates_with_aliases() {
+    let sql = "SELECT * FROM t PIVOT(SUM(x) AS s, AVG(y) AS a FOR k IN (1 AS one, 2 AS two)) AS p";
+    let select = snowflake().verified_only_select(sql);
+    match &select.from[0].relation {
+        TableFactor::Pivot {
+            aggregate_functions,
+            value_source: PivotValueSource::List(values),
+            ..
+        } => {
+            assert_eq!(
+                aggregate_functions
+                    .iter()
+                    .map(|f| (f.expr.to_string(), f.alias.clone()))
+                    .collect::<Vec<_>>(),
+                vec![
+                    ("SUM(x)".to_string(), Some(Ident::new("s"))),
+                    ("AVG(y)".to_string(), Some(Ident::new("a"))),
+                ]
+            );
+            assert_eq!(
+                values
+                    .iter()
+                    .map(|v| (v.expr.to_string(), v.alias.clone()))
+                    .collect::<Vec<_>>(),
+                vec![
+                    ("1".to_string(), Some(Ident::new("one"))),
+                    ("2".to_string(), Some(Ident::new("two"))),
+                ]
+            );
+        }
+        relation => panic!("Expected PIVOT, got: {relation:?}"),
+    }
+
+    snowflake()
+        .verified_stmt("SELECT * FROM t PIVOT(SUM(x), COUNT(*) AS c FOR k IN ('a', 'b' AS bee))");
+}
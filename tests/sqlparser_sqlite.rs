@@ -906,6 +906,150 @@ fn test_drop_trigger() {
     }
 }
 
+#[test]
+fn parse_upsert_on_conflict() {
+    match sqlite().verified_stmt("INSERT INTO t (id, v) VALUES (1, 2) ON CONFLICT(id) DO NOTHING") {
+        Statement::Insert(Insert {
+            on:
+                Some(OnInsert::OnConflict(OnConflict {
+                    conflict_target: Some(ConflictTarget::Columns(cols)),
+                    action,
+                })),
+            ..
+        }) => {
+            assert_eq!(vec![Ident::new("id")], cols);
+            assert_eq!(OnConflictAction::DoNothing, action);
+        }
+        _ => unreachable!(),
+    }
+
+    match sqlite().verified_stmt(
+        "INSERT INTO t (id, v) VALUES (1, 2) ON CONFLICT(id) DO UPDATE SET v = excluded.v WHERE t.v < excluded.v",
+    ) {
+        Statement::Insert(Insert {
+            on:
+                Some(OnInsert::OnConflict(OnConflict {
+                    conflict_target: Some(ConflictTarget::Columns(cols)),
+                    action: OnConflictAction::DoUpdate(DoUpdate {
+                        assignments,
+                        selection,
+                    }),
+                })),
+            ..
+        }) => {
+            assert_eq!(vec![Ident::new("id")], cols);
+            assert_eq!(
+                vec![Assignment {
+                    target: AssignmentTarget::ColumnName(ObjectName::from(vec![Ident::new("v")])),
+                    value: Expr::CompoundIdentifier(vec![Ident::new("excluded"), Ident::new("v")]),
+                }],
+                assignments
+            );
+            assert_eq!(
+                Some(Expr::BinaryOp {
+                    left: Box::new(Expr::CompoundIdentifier(vec![
+                        Ident::new("t"),
+                        Ident::new("v")
+                    ])),
+                    op: BinaryOperator::Lt,
+                    right: Box::new(Expr::CompoundIdentifier(vec![
+                        Ident::new("excluded"),
+                        Ident::new("v")
+                    ])),
+                }),
+                selection
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // conflict target is optional for DO NOTHING
+    sqlite().verified_stmt("INSERT INTO t (id) VALUES (1) ON CONFLICT DO NOTHING");
+}
+
+#[test]
+fn pragma_name_value() {
+    match sqlite_and_generic().verified_stmt("PRAGMA table_info(t)") {
+        Statement::Pragma {
+            name,
+            value: Some(val),
+            is_eq: false,
+        } => {
+            assert_eq!("table_info", name.to_string());
+            assert_eq!(Expr::Identifier(Ident::new("t")), val);
+        }
+        _ => unreachable!(),
+    }
+
+    match sqlite_and_generic().verified_stmt("PRAGMA main.journal_mode = WAL") {
+        Statement::Pragma {
+            name,
+            value: Some(val),
+            is_eq: true,
+        } => {
+            assert_eq!("main.journal_mode", name.to_string());
+            assert_eq!(Expr::Identifier(Ident::new("WAL")), val);
+        }
+        _ => unreachable!(),
+    }
+
+    sqlite().verified_stmt("PRAGMA foreign_keys = ON");
+    sqlite().verified_stmt("PRAGMA journal_mode = DELETE");
+
+    match sqlite().verified_stmt("PRAGMA foreign_keys = true") {
+        Statement::Pragma {
+            value: Some(val), ..
+        } => assert_eq!(Expr::Value(Value::Boolean(true).into()), val),
+        _ => unreachable!(),
+    }
+
+    match sqlite().verified_stmt("PRAGMA cache_size = -2000") {
+        Statement::Pragma {
+            value: Some(val), ..
+        } => assert_eq!(
+            Expr::UnaryOp {
+                op: UnaryOperator::Minus,
+                expr: Box::new(Expr::value(number("2000"))),
+            },
+            val
+        ),
+        _ => unreachable!(),
+    }
+    sqlite().verified_stmt("PRAGMA cache_size(-2000)");
+
+    assert!(sqlite().parse_sql_statements("PRAGMA x = select").is_err());
+    assert!(sqlite().parse_sql_statements("PRAGMA x(from)").is_err());
+}
+
+#[test]
+fn parse_detach_database() {
+    match sqlite().verified_stmt("DETACH DATABASE test") {
+        Statement::DetachDatabase {
+            schema_name,
+            database,
+        } => {
+            assert!(database);
+            assert_eq!(schema_name, Ident::new("test"));
+        }
+        _ => unreachable!(),
+    }
+    sqlite().verified_stmt("DETACH test");
+    assert!(sqlite()
+        .parse_sql_statements("DETACH DATABASE IF EXISTS test")
+        .is_err());
+    assert!(sqlite()
+        .parse_sql_statements("DETACH IF EXISTS test")
+        .is_err());
+
+    // round trip with ATTACH in the same batch
+    let stmts = sqlite()
+        .parse_sql_statements("ATTACH DATABASE 'aux.db' AS aux; DETACH DATABASE aux")
+        .unwrap();
+    assert_eq!(stmts.len(), 2);
+    assert_eq!(stmts[0].to_string(), "ATTACH DATABASE 'aux.db' AS aux");
+    assert_eq!(stmts[1].to_string(), "DETACH DATABASE aux");
+}
+
 fn sqlite() -> TestedDialects {
     TestedDialects::new(vec![Box::new(SQLiteDialect {})])
 }